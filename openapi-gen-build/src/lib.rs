@@ -0,0 +1,277 @@
+//! Generate Rust code from an OpenAPI specification in a build script.
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     openapi_gen_build::generate_openapi("openapi/service.openapi.yaml")?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The generated code is written to `OUT_DIR`, from which `openapi_gen::include!` embeds it into a module of your
+//! choosing:
+//!
+//! ```ignore
+//! mod openapi {
+//!     openapi_gen::include!("service");
+//! }
+//! ```
+//!
+//! This crate has the same features as `openapi-gen`, which decide what is generated: the `axum` integration, for
+//! example, is only generated with feature `axum-support`. Enable the same features on both crates.
+//!
+//! Cargo is told to rerun the build script whenever the specification, or any file it references, changes. The
+//! code is only regenerated when the contents of those files or the options have actually changed, so that
+//! touching the specification does not force the embedding crate to recompile.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use md5::{Digest, Md5};
+use openapi_gen::{ApiModel, Options};
+use openapiv3::OpenAPI;
+
+/// Generate code from the specification at `spec`, with the default options.
+///
+/// The generated code is named after the file name of the specification, up to its first `.`: the code generated
+/// from `openapi/service.openapi.yaml` is embedded with `openapi_gen::include!("service")`.
+pub fn generate_openapi(spec: impl AsRef<Path>) -> Result<(), Error> {
+    Generator::new(spec).generate()
+}
+
+/// Generate code from a specification, with more control than [`generate_openapi`].
+pub struct Generator {
+    spec: PathBuf,
+    name: Option<String>,
+    configure: Option<Box<dyn Fn(&mut Options)>>,
+}
+
+impl Generator {
+    /// Generate code from the specification at `spec`.
+    pub fn new(spec: impl AsRef<Path>) -> Self {
+        Self {
+            spec: spec.as_ref().to_owned(),
+            name: None,
+            configure: None,
+        }
+    }
+
+    /// Set the name with which the generated code is embedded by `openapi_gen::include!`.
+    ///
+    /// This is necessary when two specifications share a file name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adjust the options of the generated code.
+    ///
+    /// `configure` is applied after the options from the `x-openapi-gen` extension of the specification.
+    pub fn configure(mut self, configure: impl 'static + Fn(&mut Options)) -> Self {
+        self.configure = Some(Box::new(configure));
+        self
+    }
+
+    /// Generate the code into `OUT_DIR`.
+    pub fn generate(self) -> Result<(), Error> {
+        let out_dir = PathBuf::from(std::env::var("OUT_DIR").map_err(Error::OutDir)?);
+        let name = match self.name {
+            Some(name) => name,
+            None => module_name(&self.spec)
+                .ok_or_else(|| Error::Name(self.spec.clone()))?
+                .to_owned(),
+        };
+
+        let value = read_yaml(&self.spec)?;
+        let mut hasher = Md5::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(read(&self.spec)?);
+
+        println!("cargo:rerun-if-changed={}", self.spec.display());
+        for reference in references_from(&self.spec, &value)? {
+            println!("cargo:rerun-if-changed={}", reference.display());
+            hasher.update(reference.to_string_lossy().as_bytes());
+            hasher.update(read(&reference)?);
+        }
+
+        let spec: OpenAPI = openapi_gen::deserialize_spec(value).map_err(|err| Error::Parse {
+            path: self.spec.clone(),
+            err,
+        })?;
+        let mut model = ApiModel::new(&spec, Some(&self.spec))?;
+        if let Some(configure) = &self.configure {
+            configure(model.options_mut());
+        }
+        hasher.update(format!("{:?}", model.options()));
+
+        let hash = hasher
+            .finalize()
+            .into_iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        let output = out_dir.join(format!("{name}.rs"));
+        let hash_file = out_dir.join(format!("{name}.rs.md5"));
+        if output.exists() && std::fs::read_to_string(&hash_file).is_ok_and(|old| old == hash) {
+            return Ok(());
+        }
+
+        let code = model.emit_includable_items()?;
+        write(&output, code)?;
+        write(&hash_file, hash)?;
+        Ok(())
+    }
+}
+
+/// The name of the generated code for the specification at `spec`: its file name, up to the first `.`.
+fn module_name(spec: &Path) -> Option<&str> {
+    let file_name = spec.file_name()?.to_str()?;
+    let name = file_name.split('.').next()?;
+    (!name.is_empty()).then_some(name)
+}
+
+/// Find every local file referenced, directly or transitively, by the specification at `spec`.
+///
+/// Besides the specification itself, these are the files from which code is generated.
+pub fn referenced_files(spec: impl AsRef<Path>) -> Result<BTreeSet<PathBuf>, Error> {
+    let spec = spec.as_ref();
+    references_from(spec, &read_yaml(spec)?)
+}
+
+/// Find every local file referenced, directly or transitively, by the document at `path`, whose contents are `value`.
+fn references_from(path: &Path, value: &serde_yaml::Value) -> Result<BTreeSet<PathBuf>, Error> {
+    let mut found = BTreeSet::new();
+    let mut queue = vec![(path.to_owned(), value.clone())];
+    while let Some((path, value)) = queue.pop() {
+        let mut references = Vec::new();
+        collect_references(&value, &mut references);
+        for reference in references {
+            let Some(reference) = local_reference(&reference) else {
+                continue;
+            };
+            let reference = path
+                .parent()
+                .map(|directory| directory.join(reference))
+                .unwrap_or_else(|| PathBuf::from(reference));
+            if reference != path && found.insert(reference.clone()) {
+                let value = read_yaml(&reference)?;
+                queue.push((reference, value));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Collect the target of every `$ref` within `value`.
+fn collect_references(value: &serde_yaml::Value, references: &mut Vec<String>) {
+    match value {
+        serde_yaml::Value::Mapping(mapping) => {
+            for (key, value) in mapping {
+                match (key.as_str(), value.as_str()) {
+                    (Some("$ref"), Some(reference)) => references.push(reference.to_owned()),
+                    _ => collect_references(value, references),
+                }
+            }
+        }
+        serde_yaml::Value::Sequence(sequence) => {
+            for value in sequence {
+                collect_references(value, references);
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => collect_references(&tagged.value, references),
+        _ => {}
+    }
+}
+
+/// The file part of a reference to a local file, or `None` for references within the document and to URLs.
+fn local_reference(reference: &str) -> Option<&str> {
+    let file = reference.split('#').next()?;
+    (!file.is_empty() && !file.contains("://")).then_some(file)
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, Error> {
+    std::fs::read(path).map_err(|err| Error::Io {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+fn read_yaml(path: &Path) -> Result<serde_yaml::Value, Error> {
+    serde_yaml::from_slice(&read(path)?).map_err(|err| Error::Parse {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+fn write(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), Error> {
+    std::fs::write(path, contents).map_err(|err| Error::Io {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("`OUT_DIR` is not set; `openapi-gen-build` must run in a build script")]
+    OutDir(#[source] std::env::VarError),
+    #[error("cannot derive a name for the generated code from {}; set one with `Generator::name`", .0.display())]
+    Name(PathBuf),
+    #[error("accessing {}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        err: std::io::Error,
+    },
+    #[error("parsing {}", .path.display())]
+    Parse {
+        path: PathBuf,
+        #[source]
+        err: serde_yaml::Error,
+    },
+    #[error(transparent)]
+    Model(#[from] openapi_gen::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_name_stops_at_the_first_dot() {
+        assert_eq!(
+            module_name(Path::new("openapi/service.openapi.yaml")),
+            Some("service")
+        );
+        assert_eq!(module_name(Path::new("petstore.yaml")), Some("petstore"));
+        assert_eq!(module_name(Path::new(".hidden.yaml")), None);
+    }
+
+    #[test]
+    fn only_local_files_are_referenced() {
+        let value: serde_yaml::Value = serde_yaml::from_str(
+            r##"
+            components:
+              schemas:
+                Pet:
+                  $ref: "#/components/schemas/Animal"
+                Animal:
+                  $ref: "schemas/animal.yaml#/Animal"
+                Remote:
+                  allOf:
+                    - $ref: "https://example.com/schemas.yaml#/Remote"
+                    - $ref: common.yaml
+            "##,
+        )
+        .unwrap();
+        let mut references = Vec::new();
+        collect_references(&value, &mut references);
+        let mut local = references
+            .iter()
+            .filter_map(|reference| local_reference(reference))
+            .collect::<Vec<_>>();
+        local.sort_unstable();
+        assert_eq!(local, ["common.yaml", "schemas/animal.yaml"]);
+    }
+}
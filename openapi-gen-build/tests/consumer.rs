@@ -0,0 +1,23 @@
+//! Build a crate which generates its code in a build script, as users of this crate do.
+//!
+//! The generated code only compiles when the features of this crate reach the generator, so this catches code
+//! which the generator emits under features which the consumer has no way to enable.
+
+use std::{path::Path, process::Command};
+
+#[test]
+fn consumer_crate_builds() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/consumer/Cargo.toml");
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(manifest)
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("consumer"),
+        )
+        .status()
+        .expect("cargo should run");
+    assert!(status.success(), "the consumer crate should build");
+}
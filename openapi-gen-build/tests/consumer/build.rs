@@ -0,0 +1,4 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    openapi_gen_build::generate_openapi("openapi/pets.openapi.yaml")?;
+    Ok(())
+}
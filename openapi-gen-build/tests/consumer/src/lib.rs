@@ -0,0 +1,8 @@
+//! A crate which generates its API in a build script.
+
+mod openapi {
+    openapi_gen::include!("pets");
+}
+
+// `build_router` only exists when the build script generated the `axum` integration
+pub use openapi::{build_router, Api, ListPetsResponse, Pet};
@@ -0,0 +1,170 @@
+//! Generate Rust code from an OpenAPI specification at compile time.
+//!
+//! This crate implements `openapi_gen::generate!`, which should be invoked through that path; see its documentation.
+
+use std::path::PathBuf;
+
+use openapi_gen::{ApiModel, Options};
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::Value;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Ident, LitStr, Token,
+};
+
+/// Expand to the items generated from a specification.
+///
+/// ```ignore
+/// mod openapi {
+///     openapi_gen::generate!("openapi/service.openapi.yaml", client, mock);
+/// }
+/// ```
+///
+/// The path is relative to the directory containing the manifest of the invoking crate. It may be followed by the
+/// names of options to enable, as they are spelled in [`Options`]. These are added to the `x-openapi-gen`
+/// extension of the specification.
+///
+/// What is generated depends on the features of this crate, which are those of `openapi-gen`: the `axum`
+/// integration, for example, is only generated with feature `axum-support`. Enable the same features on both
+/// crates.
+#[proc_macro]
+pub fn generate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as Input);
+    match input.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+struct Input {
+    path: LitStr,
+    options: Vec<Ident>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let mut options = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            options.extend(Punctuated::<Ident, Token![,]>::parse_terminated(input)?);
+        }
+        // report misspelled options even when the specification cannot be read
+        for option in &options {
+            let mut extension = Value::Object(Default::default());
+            enable(&mut extension, option)?;
+            serde_json::from_value::<Options>(extension).map_err(|_| {
+                syn::Error::new(option.span(), format!("unknown option `{option}`"))
+            })?;
+        }
+        Ok(Self { path, options })
+    }
+}
+
+impl Input {
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+            .map_err(|_| self.error("`CARGO_MANIFEST_DIR` is not set"))?;
+        let path = PathBuf::from(manifest_dir).join(self.path.value());
+        let display = self.path.value();
+
+        let file = std::fs::File::open(&path)
+            .map_err(|err| self.error(format!("reading {display}: {err}")))?;
+        let deserializer = serde_yaml::Deserializer::from_reader(std::io::BufReader::new(file));
+        let mut spec: OpenAPI =
+            openapi_gen::deserialize_spec(deserializer).map_err(|err: serde_yaml::Error| {
+                let location = err
+                    .location()
+                    .map(|location| format!(":{}:{}", location.line(), location.column()))
+                    .unwrap_or_default();
+                self.error(format!("parsing {display}{location}: {err}"))
+            })?;
+
+        let extension = spec
+            .extensions
+            .entry(OPTIONS_EXTENSION.to_owned())
+            .or_insert_with(|| Value::Object(Default::default()));
+        for option in &self.options {
+            enable(extension, option)?;
+        }
+
+        let model = ApiModel::new(&spec, Some(&path))
+            .map_err(|err| self.error(format!("{display}: {}", error_chain(&err))))?;
+        let items = model
+            .emit_includable_items_to_token_stream()
+            .map_err(|err| self.error(format!("{display}: {}", error_chain(&err))))?;
+
+        // recompile when the specification, or any file it references, changes
+        let references = openapi_gen_build::referenced_files(&path)
+            .map_err(|err| self.error(error_chain(&err)))?;
+        let files = std::iter::once(path)
+            .chain(references)
+            .map(|file| file.to_string_lossy().into_owned());
+        Ok(quote! {
+            #( const _: &[u8] = ::core::include_bytes!(#files); )*
+            #items
+        })
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> syn::Error {
+        syn::Error::new(self.path.span(), message)
+    }
+}
+
+/// Code generation is configured by this extension at the top level of the specification.
+const OPTIONS_EXTENSION: &str = "x-openapi-gen";
+
+/// Enable the option named by `option` in the `x-openapi-gen` extension of a specification.
+///
+/// Options are named in `snake_case` in the macro, and in `kebab-case` in the extension.
+fn enable(extension: &mut Value, option: &Ident) -> syn::Result<()> {
+    let Value::Object(options) = extension else {
+        return Err(syn::Error::new(
+            option.span(),
+            format!("`{OPTIONS_EXTENSION}` is not an object"),
+        ));
+    };
+    options.insert(option.to_string().replace('_', "-"), Value::Bool(true));
+    Ok(())
+}
+
+/// Format an error along with each of its sources.
+fn error_chain(err: &dyn std::error::Error) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(err) = source {
+        message.push_str(": ");
+        message.push_str(&err.to_string());
+        source = err.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+
+    #[test]
+    fn parse_errors_are_located_within_the_specification() {
+        let input = Input {
+            path: LitStr::new("tests/specs/invalid.openapi.yaml", Span::call_site()),
+            options: Vec::new(),
+        };
+        let message = input
+            .expand()
+            .expect_err("the specification should be rejected")
+            .to_string();
+        let location = message
+            .strip_prefix("parsing tests/specs/invalid.openapi.yaml:")
+            .unwrap_or_else(|| panic!("unexpected message: {message}"));
+        assert!(
+            location.starts_with(|c: char| c.is_ascii_digit()),
+            "{message}"
+        );
+    }
+}
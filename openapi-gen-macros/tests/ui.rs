@@ -0,0 +1,7 @@
+//! Errors from `generate!` are reported as compile errors which point at their cause.
+
+#[test]
+fn ui() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,5 @@
+mod openapi {
+    openapi_gen_macros::generate!("tests/specs/missing.openapi.yaml");
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+mod openapi {
+    openapi_gen_macros::generate!("tests/specs/pets.openapi.yaml", client, frobnicate);
+}
+
+fn main() {}
@@ -15,7 +15,7 @@ repo_path="$(realpath "$PWD")"
 tmpdir="$(mktemp -d --tmpdir "compile-tests.XXXXXX")"
 cd "$tmpdir"
 cargo init --name "compile-tests" --lib >/dev/null 2>&1
-cargo add openapi-gen --path "$repo_path" --features api-problem,axum-support,bytes,integer-restrictions,string-pattern,uuid >/dev/null 2>&1
+cargo add openapi-gen --path "$repo_path" --features actix-support,api-problem,axum-support,bytes,integer-restrictions,reqwest-support,string-pattern,tower-support,uuid >/dev/null 2>&1
 
 exit_code=0
 
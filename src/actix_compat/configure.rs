@@ -0,0 +1,405 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::request_body::{body_object, resolve_concrete},
+        make_ident, Endpoint, Reference, Scalar, UnknownReference, Value,
+    },
+    ApiModel,
+};
+
+use super::Error;
+
+/// Name of the function which configures a service, given the name of the equivalent axum router function.
+///
+/// `build_router` becomes `configure_service`, and `build_pets_router` becomes `configure_pets_service`.
+fn service_fn_name(router: &str) -> String {
+    let name = router.strip_prefix("build_").unwrap_or(router);
+    let name = name.strip_suffix("router").unwrap_or(name);
+    format!("configure_{name}service")
+}
+
+/// Unpack an extracted parameter object into one variable per parameter.
+fn unpack_parameter_object<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    ref_: Reference,
+    object: &crate::codegen::Object,
+    extractor: TokenStream,
+    variable_ident: syn::Ident,
+) -> Result<(TokenStream, TokenStream, Vec<syn::Ident>), Error> {
+    let type_ident = model
+        .definition(ref_, &name_resolver)
+        .map_err(Error::context("getting type ident of parameter object"))?;
+    let field_names = object
+        .members
+        .keys()
+        .map(|name| make_ident(&name.to_snake_case()))
+        .collect::<Vec<_>>();
+
+    let parameter = quote!(#variable_ident: #extractor<#type_ident>);
+    let extract = quote! {
+        let #type_ident { #( #field_names ),* } = #variable_ident.into_inner();
+    };
+    Ok((parameter, extract, field_names))
+}
+
+/// Route an endpoint to the method of the API trait which implements it.
+///
+/// Returns `None` for endpoints which cannot be served, which have a `multipart/form-data` request body.
+fn build_route<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    api_trait: &str,
+    endpoint: &Endpoint,
+) -> Result<Option<TokenStream>, Error> {
+    let path = &endpoint.path;
+    let method = endpoint.verb.emit_actix();
+    let prefix = quote!(openapi_gen::actix_compat);
+    let web = quote!(openapi_gen::reexport::actix_web::web);
+
+    // handler parameters, which actix extracts
+    let mut parameters = Vec::new();
+    // statements which extract the remaining arguments from the handler parameters
+    let mut extract = Vec::new();
+    let mut parameter_idents = Vec::new();
+    let mut uses_request = false;
+
+    // arguments are extracted in the same order as by the axum router:
+    //   0. context
+    //   1. credentials
+    //   2. path parameters
+    //   3. query parameters
+    //   4. header parameters
+    //   5. body
+
+    if model.options.context {
+        let api_trait = make_ident(api_trait);
+        let variable_ident = make_ident("context");
+        parameters.push(quote!(#variable_ident: <Instance as #api_trait>::Context));
+        parameter_idents.push(variable_ident);
+    }
+
+    if let Some(type_) = endpoint.credentials_type() {
+        uses_request = true;
+        extract.push(quote! {
+            let credentials = <#type_ as #prefix::security::Credentials>::extract(&request)?;
+        });
+        parameter_idents.push(make_ident("credentials"));
+    }
+
+    if let Some((ref_, _item, object)) =
+        endpoint
+            .path_parameter_object(model)
+            .map_err(Error::context(
+                "attempting to extract path parameter object",
+            ))?
+    {
+        let (parameter, unpack, field_names) = unpack_parameter_object(
+            model,
+            &name_resolver,
+            ref_,
+            object,
+            quote!(#web::Path),
+            make_ident("path_parameters"),
+        )?;
+        parameters.push(parameter);
+        extract.push(unpack);
+        parameter_idents.extend(field_names);
+    }
+
+    if let Some((ref_, _item, object)) =
+        endpoint
+            .query_parameter_object(model)
+            .map_err(Error::context(
+                "attempting to extract query parameter object",
+            ))?
+    {
+        let (parameter, unpack, field_names) = unpack_parameter_object(
+            model,
+            &name_resolver,
+            ref_,
+            object,
+            quote!(#web::Query),
+            make_ident("query_parameters"),
+        )?;
+        parameters.push(parameter);
+        extract.push(unpack);
+        parameter_idents.extend(field_names);
+    }
+
+    for (key, param) in endpoint.headers.iter() {
+        let item = model
+            .resolve(param.item_ref)
+            .map_err(Error::context(format!("getting item for param \"{key}\"")))?;
+
+        let type_ident =
+            model
+                .definition(param.item_ref, &name_resolver)
+                .map_err(Error::context(format!(
+                    "getting type ident for {}",
+                    &item.rust_name
+                )))?;
+        let variable_ident = make_ident(&item.rust_name.to_snake_case());
+        let header_name = param.spec_name.to_lowercase();
+
+        // the accept header has no canonical form, but it can be parsed
+        let is_accept = matches!(
+            resolve_concrete(model, param.item_ref)
+                .map_err(Error::context(format!("resolving header \"{key}\"")))?
+                .value,
+            Value::Scalar(Scalar::AcceptHeader)
+        );
+        let helper = if is_accept {
+            quote!(#prefix::header_from_str)
+        } else {
+            quote!(#prefix::header)
+        };
+
+        let mut value = quote!(#helper::<#type_ident>(&request, #header_name)?);
+        if param.required {
+            value = quote!(#prefix::required(#header_name, #value)?);
+        }
+        uses_request = true;
+        extract.push(quote!(let #variable_ident = #value;));
+        parameter_idents.push(variable_ident);
+    }
+
+    if let Some(ref_) = endpoint.request_body {
+        // add body parameter last
+        let item = model
+            .resolve(ref_)
+            .map_err(Error::context("getting item for request body"))?;
+
+        let type_ident = make_ident(&item.rust_name);
+        let variable_ident = make_ident("request_body");
+        let bytes = quote!(#variable_ident: #web::Bytes);
+
+        // form bodies decode objects according to their encoding; anything else is left to actix
+        let form_object = if item.is_form_urlencoded() {
+            body_object(model, ref_)
+                .map_err(Error::context("resolving form request body"))?
+                .map(|(_ref, object_name, _object)| object_name)
+        } else {
+            None
+        };
+
+        if item.is_json() || (item.is_form_urlencoded() && form_object.is_none()) {
+            let extractor = if item.is_json() {
+                quote!(#web::Json)
+            } else {
+                quote!(#web::Form)
+            };
+            parameters.push(quote!(#variable_ident: #extractor<#type_ident>));
+            extract.push(quote!(let #variable_ident = #variable_ident.into_inner();));
+        } else if let Some(object_name) = form_object {
+            let object_ident = make_ident(object_name);
+            let decode = quote!(#prefix::form_body::<#object_ident>(&#variable_ident));
+            parameters.push(bytes);
+            extract.push(if item.nullable {
+                quote! {
+                    let #variable_ident = (!#variable_ident.is_empty()).then(|| #decode).transpose()?;
+                }
+            } else {
+                quote!(let #variable_ident = #decode?;)
+            });
+        } else if item.is_multipart_form_data() {
+            // actix-web has no multipart extractor of its own, so this operation is not routed
+            return Ok(None);
+        } else if item.is_canonical_text(model) {
+            let type_ = model
+                .definition(ref_, &name_resolver)
+                .map_err(Error::context("getting text request body type"))?;
+            uses_request = true;
+            parameters.push(bytes);
+            extract.push(quote! {
+                let #variable_ident = #prefix::text_body::<#type_>(&request, &#variable_ident)?;
+            });
+        } else if matches!(
+            resolve_concrete(model, ref_)
+                .map_err(Error::context("resolving request body"))?
+                .value,
+            Value::Scalar(Scalar::OctetStream)
+        ) {
+            let type_ = model
+                .definition(ref_, &name_resolver)
+                .map_err(Error::context("getting octet stream request body type"))?;
+            parameters.push(quote!(#variable_ident: #type_));
+        } else {
+            parameters.push(bytes);
+            extract.push(quote!(let #variable_ident = #variable_ident.to_vec();));
+        }
+
+        parameter_idents.push(variable_ident);
+    }
+
+    if uses_request {
+        parameters.insert(
+            0,
+            quote!(request: openapi_gen::reexport::actix_web::HttpRequest),
+        );
+    }
+
+    let method_name = make_ident(&endpoint.function_name(None));
+
+    Ok(Some(quote! {
+        .route(
+            #path,
+            #method.to({
+                let instance = instance.clone();
+                move |#( #parameters ),*| {
+                    let instance = instance.clone();
+                    async move {
+                        #( #extract )*
+                        Ok::<_, #prefix::Rejection>(instance.#method_name(#( #parameter_idents ),*).await)
+                    }
+                }
+            })
+        )
+    }))
+}
+
+/// Create a function which configures an actix-web service to serve `endpoints` from an implementation of
+/// some API traits.
+fn service_fn<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    name: &str,
+    subject: &str,
+    bounds: &[&str],
+    endpoints: &[(&str, &Endpoint)],
+) -> Result<TokenStream, Error> {
+    let mut routes = Vec::<TokenStream>::new();
+    let mut unrouted = Vec::new();
+
+    for (api_trait, endpoint) in endpoints {
+        match build_route(model, &name_resolver, api_trait, endpoint)? {
+            Some(route) => routes.push(route),
+            None => unrouted.push(format!("`{} {}`", endpoint.verb, endpoint.path)),
+        }
+    }
+
+    let routes = (!routes.is_empty()).then(|| quote!(config #( #routes )*;));
+
+    let ident = make_ident(name);
+    let bounds = bounds
+        .iter()
+        .map(|bound| make_ident(bound))
+        .collect::<Vec<_>>();
+    let mut associated_bounds = Vec::new();
+    if model.options.context {
+        associated_bounds.push(quote! {
+            #(
+                , <Instance as #bounds>::Context: openapi_gen::reexport::actix_web::FromRequest
+            )*
+        });
+    }
+    if model.options.is_fallible() {
+        associated_bounds.push(quote! {
+            #(
+                , <Instance as #bounds>::Error: Into<openapi_gen::reexport::actix_web::Error>
+            )*
+        });
+    }
+    let docs = format!(" Configure an actix-web service to serve {subject}.");
+    let base_path_docs = (!model.servers.is_empty()).then(|| {
+        quote! {
+            ///
+            /// Routes are not mounted at [`BASE_PATH`]; configure a `Scope` at that path to do so.
+        }
+    });
+
+    let unrouted_docs = (!unrouted.is_empty()).then(|| {
+        let docs = format!(
+            " Operations with a `multipart/form-data` request body are not served: {}.",
+            unrouted.join(", ")
+        );
+        quote! {
+            ///
+            #[doc = #docs]
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #docs]
+        ///
+        /// The returned function can be passed to `App::configure` or `Scope::configure`.
+        #base_path_docs
+        #unrouted_docs
+        pub fn #ident<Instance>(
+            instance: Instance,
+        ) -> impl Clone + Send + FnOnce(&mut openapi_gen::reexport::actix_web::web::ServiceConfig)
+        where
+            Instance: 'static + #( #bounds + )* Send + Sync
+            #( #associated_bounds )*
+        {
+            // neither is used if no operation is routed
+            #[allow(unused_variables)]
+            let instance = ::std::sync::Arc::new(instance);
+            move |
+                #[allow(unused_variables)]
+                config: &mut openapi_gen::reexport::actix_web::web::ServiceConfig
+            | {
+                #routes
+            }
+        }
+    })
+}
+
+/// Create `fn configure_service`, the actix-web equivalent of `fn build_router`.
+///
+/// When emitting one trait per tag, this instead creates one function per trait, and `fn configure_service`
+/// requires an implementation of every trait.
+pub(crate) fn fn_configure_service<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    let api_traits = model.api_traits();
+    let mut service_fns = Vec::with_capacity(api_traits.len() + 1);
+
+    for api_trait in &api_traits {
+        let subject = format!("an instance of [`trait {0}`][{0}]", api_trait.name);
+        service_fns.push(service_fn(
+            model,
+            &name_resolver,
+            &service_fn_name(api_trait.router),
+            &subject,
+            &[api_trait.name],
+            &api_trait
+                .endpoints
+                .iter()
+                .map(|endpoint| (api_trait.name, *endpoint))
+                .collect::<Vec<_>>(),
+        )?);
+    }
+
+    if model.options.trait_per_tag {
+        let bounds = api_traits
+            .iter()
+            .map(|api_trait| api_trait.name)
+            .collect::<Vec<_>>();
+        let endpoints = api_traits
+            .iter()
+            .flat_map(|api_trait| {
+                api_trait
+                    .endpoints
+                    .iter()
+                    .map(|endpoint| (api_trait.name, *endpoint))
+            })
+            .collect::<Vec<_>>();
+        service_fns.push(service_fn(
+            model,
+            &name_resolver,
+            "configure_service",
+            "an implementation of every API trait",
+            &bounds,
+            &endpoints,
+        )?);
+    }
+
+    Ok(quote! {
+        #( #service_fns )*
+    })
+}
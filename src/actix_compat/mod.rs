@@ -0,0 +1,330 @@
+//! Actix-web compatibility boilerplate generator.
+//!
+//! When the `actix` option is set, generated code includes:
+//!
+//! - `impl Responder` for all response types
+//! - `impl Credentials` for all credential types
+//! - `fn configure_service`, which configures an actix-web service to serve an implementation of the API
+//!
+//! Requests are routed and extracted in the same way as by the router of the axum integration, and responses
+//! carry the same status codes, headers, and bodies. Operations with a `multipart/form-data` request body are not
+//! served; the documentation of `fn configure_service` lists them.
+//!
+//! actix-web depends on an older version of the `http` crate than the rest of this crate. The remaining
+//! functions in this module bridge the two; they are used by the generated code, and are not generally useful
+//! on their own.
+
+use std::str::FromStr;
+
+use actix_web::{body::MessageBody, HttpRequest, HttpResponse, HttpResponseBuilder, ResponseError};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    actix_compat::{configure::fn_configure_service, responder::impl_responder},
+    codegen::{
+        endpoint::dispatch::{impl_credentials_credentials, impl_scheme_credentials},
+        Reference, UnknownReference,
+    },
+    form::{FormEncoding, FormError},
+    security::SecurityRejection,
+    text::{FromText, TextError},
+    ApiModel, CanonicalForm, CanonicalizeError, ValidationError,
+};
+
+mod configure;
+mod responder;
+
+pub mod security;
+
+pub(crate) fn actix_items<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    if !model.options.actix {
+        return Ok(TokenStream::default());
+    }
+
+    let security = quote!(openapi_gen::actix_compat::security);
+    let request = quote!(openapi_gen::reexport::actix_web::HttpRequest);
+    let scheme_impls = model
+        .security_schemes
+        .values()
+        .map(|scheme| impl_scheme_credentials(&security, &request, scheme));
+    let credentials_impls = model
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| impl_credentials_credentials(model, &security, &request, endpoint));
+
+    let mut responder_impls = Vec::with_capacity(model.endpoints.len());
+    for endpoint in model.endpoints.iter() {
+        responder_impls.push(
+            impl_responder(model, endpoint.response)
+                .map_err(Error::context("implementing `Responder`"))?,
+        );
+    }
+
+    let configure_service = fn_configure_service(model, name_resolver)?;
+
+    Ok(quote! {
+        #( #scheme_impls )*
+        #( #credentials_impls )*
+        #( #responder_impls )*
+        #configure_service
+    })
+}
+
+/// Convert a status code into the version used by actix-web.
+pub(crate) fn actix_status(status: http::StatusCode) -> actix_web::http::StatusCode {
+    actix_web::http::StatusCode::from_u16(status.as_u16())
+        .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Begin a response with the given status.
+pub fn response(status: http::StatusCode) -> HttpResponseBuilder {
+    HttpResponse::build(actix_status(status))
+}
+
+/// Produce a response, or `500 Internal Server Error` if it cannot be encoded.
+pub fn respond(response: impl FnOnce() -> Result<HttpResponse, EncodeError>) -> HttpResponse {
+    response().unwrap_or_else(|err| HttpResponse::InternalServerError().body(err.to_string()))
+}
+
+/// Add a header to a response, via its canonical form.
+pub fn insert_header<T>(
+    response: &mut HttpResponseBuilder,
+    name: &'static str,
+    value: &T,
+) -> Result<(), EncodeError>
+where
+    T: CanonicalForm,
+{
+    let value = value.canonicalize()?.to_string();
+    response.insert_header((name, value));
+    Ok(())
+}
+
+/// Complete a response with a JSON body.
+pub fn json<T>(
+    mut response: HttpResponseBuilder,
+    content_type: &'static str,
+    body: &T,
+) -> Result<HttpResponse, EncodeError>
+where
+    T: Serialize,
+{
+    let body = serde_json::to_vec(body)?;
+    Ok(response.content_type(content_type).body(body))
+}
+
+/// Complete a response with a `text/*` body, via its canonical form.
+pub fn text<T>(
+    mut response: HttpResponseBuilder,
+    content_type: &'static str,
+    body: &T,
+) -> Result<HttpResponse, EncodeError>
+where
+    T: CanonicalForm,
+{
+    let (content_type, body) = crate::text::encode(content_type, body)?;
+    Ok(response.content_type(content_type).body(body))
+}
+
+/// Complete a response with any other body.
+pub fn raw<B>(
+    mut response: HttpResponseBuilder,
+    content_type: Option<&'static str>,
+    body: B,
+) -> HttpResponse
+where
+    B: 'static + MessageBody,
+{
+    if let Some(content_type) = content_type {
+        response.content_type(content_type);
+    }
+    response.body(body)
+}
+
+/// Extract a request header, via its canonical form.
+pub fn header<T>(request: &HttpRequest, name: &'static str) -> Result<Option<T>, Rejection>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    request
+        .headers()
+        .get(name)
+        .map(|value| crate::text::decode(None, value.as_bytes()))
+        .transpose()
+        .map_err(|source| Rejection::Header { name, source })
+}
+
+/// Extract a request header, via its `FromStr` implementation.
+pub fn header_from_str<T>(request: &HttpRequest, name: &'static str) -> Result<Option<T>, Rejection>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Some(value) = request.headers().get(name) else {
+        return Ok(None);
+    };
+    let invalid = |reason: String| Rejection::Header {
+        name,
+        source: TextError::Validate(ValidationError::reason::<T>(reason.as_str())),
+    };
+    let value = value.to_str().map_err(|err| invalid(err.to_string()))?;
+    value
+        .parse()
+        .map(Some)
+        .map_err(|err: T::Err| invalid(err.to_string()))
+}
+
+/// Require that a request header is present.
+pub fn required<T>(name: &'static str, value: Option<T>) -> Result<T, Rejection> {
+    value.ok_or(Rejection::MissingHeader(name))
+}
+
+/// Decode an `application/x-www-form-urlencoded` request body, respecting the encodings of its array properties.
+pub fn form_body<T>(body: &[u8]) -> Result<T, Rejection>
+where
+    T: DeserializeOwned + FormEncoding,
+{
+    crate::form::from_bytes(body).map_err(Into::into)
+}
+
+/// Decode a `text/*` request body, via its canonical form.
+pub fn text_body<T>(request: &HttpRequest, body: &[u8]) -> Result<T, Rejection>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    let content_type = request
+        .headers()
+        .get("content-type")
+        .and_then(|value| value.to_str().ok());
+    crate::text::decode(content_type, body).map_err(Into::into)
+}
+
+/// A response could not be encoded.
+#[derive(Debug, thiserror::Error)]
+pub enum EncodeError {
+    #[error("failed to encode json")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Text(#[from] TextError),
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+}
+
+/// A request could not be extracted.
+///
+/// Failures which actix-web detects itself, such as malformed JSON bodies, are reported by actix-web.
+#[derive(Debug, thiserror::Error)]
+pub enum Rejection {
+    #[error(transparent)]
+    Security(#[from] SecurityRejection),
+    #[error("invalid header \"{name}\"")]
+    Header {
+        name: &'static str,
+        #[source]
+        source: TextError,
+    },
+    #[error("missing required header \"{0}\"")]
+    MissingHeader(&'static str),
+    #[error(transparent)]
+    Form(#[from] FormError),
+    #[error(transparent)]
+    Text(#[from] TextError),
+}
+
+impl Rejection {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> http::StatusCode {
+        match self {
+            Rejection::Security(rejection) => rejection.status(),
+            Rejection::Header { .. } | Rejection::MissingHeader(_) => http::StatusCode::BAD_REQUEST,
+            Rejection::Form(_) | Rejection::Text(TextError::Validate(_)) => {
+                http::StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Rejection::Text(TextError::UnsupportedCharset(_)) => {
+                http::StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            Rejection::Text(_) => http::StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// The value of the `WWW-Authenticate` header which accompanies this error, if any.
+    pub fn challenge(&self) -> Option<String> {
+        match self {
+            Rejection::Security(rejection) => rejection.challenge(),
+            _ => None,
+        }
+    }
+}
+
+impl ResponseError for Rejection {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_status(self.status())
+    }
+
+    /// Respond with this error.
+    ///
+    /// With feature `api-problem`, the response is a problem whose title is the error, and whose detail is its
+    /// chain of sources. Otherwise, it is the error as plain text. Rejected credentials are answered with a
+    /// `WWW-Authenticate` challenge.
+    fn error_response(&self) -> HttpResponse {
+        let mut builder = response(self.status());
+        if let Some(challenge) = self.challenge() {
+            builder.insert_header((actix_web::http::header::WWW_AUTHENTICATE, challenge));
+        }
+
+        #[cfg(feature = "api-problem")]
+        let response = {
+            let mut err: &dyn std::error::Error = self;
+            let mut detail = String::new();
+            while let Some(predecessor) = err.source() {
+                detail.extend(format!("{predecessor}; ").chars());
+                err = predecessor;
+            }
+
+            let problem = http_api_problem::HttpApiProblem::new(self.status())
+                .title(self.to_string())
+                .detail(detail);
+            respond(|| json(builder, "application/problem+json", &problem))
+        };
+
+        #[cfg(not(feature = "api-problem"))]
+        let response = raw(builder, Some("text/plain; charset=utf-8"), self.to_string());
+
+        response
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{msg}")]
+pub struct Error {
+    msg: String,
+    #[source]
+    inner: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl Error {
+    fn new(msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        let inner = None;
+        Self { msg, inner }
+    }
+
+    fn context<C, E>(context: C) -> impl FnOnce(E) -> Self
+    where
+        C: Into<String>,
+        Box<dyn 'static + std::error::Error + Send + Sync>: From<E>,
+    {
+        move |err| {
+            let msg = context.into();
+            let inner = Some(err.into());
+            Self { msg, inner }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    actix_compat::Error,
+    codegen::{endpoint::dispatch::encode_response, make_ident, Reference},
+    ApiModel,
+};
+
+/// Implement `Responder` for a response type.
+///
+/// Failure to encode a header or body responds with `500 Internal Server Error`.
+pub(crate) fn impl_responder(
+    model: &ApiModel,
+    response_enum: Reference,
+) -> Result<TokenStream, Error> {
+    let item = model
+        .resolve(response_enum)
+        .map_err(Error::context("getting response item"))?;
+    let response_ident = make_ident(&item.rust_name);
+
+    let respond = encode_response(model, &quote!(openapi_gen::actix_compat), response_enum)
+        .map_err(Error::context("encoding response"))?;
+
+    Ok(quote! {
+        impl openapi_gen::reexport::actix_web::Responder for #response_ident {
+            type Body = openapi_gen::reexport::actix_web::body::BoxBody;
+
+            fn respond_to(
+                self,
+                _request: &openapi_gen::reexport::actix_web::HttpRequest,
+            ) -> openapi_gen::reexport::actix_web::HttpResponse {
+                #respond
+            }
+        }
+    })
+}
@@ -0,0 +1,165 @@
+//! Runtime support for extracting credentials.
+//!
+//! Generated credential types implement [`Credentials`] by calling the functions in this module, which convert
+//! the head of the request into the version of `http` used by the other integrations, and extract credentials
+//! from it with the same functions. The head is converted once per request, however many schemes are tried. Rejections are the same as those of the `axum` integration: credentials
+//! which are absent or malformed produce `401 Unauthorized` with a `WWW-Authenticate` challenge; credentials
+//! which satisfy only part of a security requirement produce `403 Forbidden`.
+
+use std::rc::Rc;
+
+use actix_web::HttpRequest;
+use http::request::Parts;
+
+pub use crate::security::SecurityRejection;
+use crate::security::{parts, Basic, Bearer};
+
+/// Credentials which can be extracted from a request.
+pub trait Credentials: Sized {
+    /// Extract these credentials from a request.
+    fn extract(request: &HttpRequest) -> Result<Self, SecurityRejection>;
+}
+
+/// Operations which permit anonymous access receive credentials only when they are present.
+///
+/// Credentials which are present but invalid are still rejected.
+impl<T> Credentials for Option<T>
+where
+    T: Credentials,
+{
+    fn extract(request: &HttpRequest) -> Result<Self, SecurityRejection> {
+        match T::extract(request) {
+            Ok(credentials) => Ok(Some(credentials)),
+            Err(SecurityRejection::Missing { .. }) => Ok(None),
+            Err(rejection) => Err(rejection),
+        }
+    }
+}
+
+/// The converted head of a request, cached in its extensions.
+#[derive(Clone)]
+struct ConvertedParts(Rc<Parts>);
+
+/// The head of a request in the version of `http` from which the shared extractors read credentials.
+///
+/// The head is converted on first use and cached in the extensions of the request.
+fn request_parts(request: &HttpRequest) -> Rc<Parts> {
+    let cached = request.extensions().get::<ConvertedParts>().cloned();
+    if let Some(ConvertedParts(parts)) = cached {
+        return parts;
+    }
+    let parts = Rc::new(convert_parts(request));
+    request
+        .extensions_mut()
+        .insert(ConvertedParts(Rc::clone(&parts)));
+    parts
+}
+
+/// Convert the head of a request into the version of `http` from which the shared extractors read credentials.
+///
+/// Only the query and the headers are carried over, as credentials are never presented elsewhere. Headers which
+/// cannot be converted are dropped.
+fn convert_parts(request: &HttpRequest) -> Parts {
+    let (mut parts, ()) = http::Request::new(()).into_parts();
+    if let Ok(uri) = http::Uri::try_from(request.uri().to_string()) {
+        parts.uri = uri;
+    }
+    for (name, value) in request.headers() {
+        let name = http::HeaderName::from_bytes(name.as_str().as_bytes());
+        let value = http::HeaderValue::from_bytes(value.as_bytes());
+        if let (Ok(name), Ok(value)) = (name, value) {
+            parts.headers.append(name, value);
+        }
+    }
+    parts
+}
+
+/// Extract an API key from a request header.
+pub fn api_key_header(
+    request: &HttpRequest,
+    scheme: &'static str,
+    name: &'static str,
+) -> Result<String, SecurityRejection> {
+    parts::api_key_header(&request_parts(request), scheme, name)
+}
+
+/// Extract an API key from the query string.
+pub fn api_key_query(
+    request: &HttpRequest,
+    scheme: &'static str,
+    name: &'static str,
+) -> Result<String, SecurityRejection> {
+    parts::api_key_query(&request_parts(request), scheme, name)
+}
+
+/// Extract an API key from a cookie.
+pub fn api_key_cookie(
+    request: &HttpRequest,
+    scheme: &'static str,
+    name: &'static str,
+) -> Result<String, SecurityRejection> {
+    parts::api_key_cookie(&request_parts(request), scheme, name)
+}
+
+/// Extract credentials of the HTTP `Basic` authentication scheme.
+pub fn basic(request: &HttpRequest, scheme: &'static str) -> Result<Basic, SecurityRejection> {
+    parts::basic(&request_parts(request), scheme)
+}
+
+/// Extract credentials of the HTTP `Bearer` authentication scheme.
+pub fn bearer(request: &HttpRequest, scheme: &'static str) -> Result<Bearer, SecurityRejection> {
+    parts::bearer(&request_parts(request), scheme)
+}
+
+/// Extract the credentials of any other HTTP authentication scheme, without interpreting them.
+pub fn credentials(
+    request: &HttpRequest,
+    scheme: &'static str,
+    auth_scheme: &'static str,
+) -> Result<String, SecurityRejection> {
+    parts::credentials(&request_parts(request), scheme, auth_scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[test]
+    fn credentials_are_extracted_from_the_converted_request() {
+        let request = TestRequest::with_uri("/things?apiKey=secret")
+            .insert_header(("Authorization", "Bearer token"))
+            .insert_header(("Cookie", "sessionId=abc; theme=dark"))
+            .to_http_request();
+
+        assert_eq!(
+            api_key_query(&request, "query", "apiKey").unwrap(),
+            "secret"
+        );
+        assert_eq!(
+            api_key_cookie(&request, "cookie", "sessionId").unwrap(),
+            "abc"
+        );
+        assert_eq!(bearer(&request, "bearer").unwrap().token, "token");
+        assert!(matches!(
+            basic(&request, "basic"),
+            Err(SecurityRejection::Missing {
+                scheme: "basic",
+                auth_scheme: "Basic"
+            })
+        ));
+    }
+
+    #[test]
+    fn the_request_head_is_converted_once() {
+        let request = TestRequest::default()
+            .insert_header(("Authorization", "Bearer token"))
+            .to_http_request();
+
+        assert!(Rc::ptr_eq(
+            &request_parts(&request),
+            &request_parts(&request)
+        ));
+    }
+}
@@ -1,6 +1,19 @@
-use axum::{extract::rejection::JsonRejection, response::IntoResponse};
+use axum::{
+    extract::{
+        path::ErrorKind,
+        rejection::{FormRejection, JsonRejection, PathRejection},
+    },
+    response::IntoResponse,
+};
+use axum_extra::typed_header::TypedHeaderRejection;
 use http_api_problem::{HttpApiProblem, StatusCode};
 
+use super::{
+    form::UrlEncodedFormRejection, json::JsonBodyRejection, multipart::MultipartError,
+    query::QueryRejection, security::SecurityRejection, text::TextRejection,
+};
+use crate::validation::{Source, Violation};
+
 #[derive(Debug)]
 pub struct ApiProblemRejection(pub HttpApiProblem);
 
@@ -12,9 +25,111 @@ impl From<JsonRejection> for ApiProblemRejection {
             JsonRejection::MissingJsonContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
             _ => StatusCode::BAD_REQUEST,
         };
+        Self::from_error(status, &value)
+    }
+}
+
+impl From<FormRejection> for ApiProblemRejection {
+    fn from(value: FormRejection) -> Self {
+        let status = match value {
+            FormRejection::FailedToDeserializeForm(_) => StatusCode::BAD_REQUEST,
+            FormRejection::FailedToDeserializeFormBody(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            FormRejection::InvalidFormContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            _ => StatusCode::BAD_REQUEST,
+        };
+        Self::from_error(status, &value)
+    }
+}
+
+impl From<UrlEncodedFormRejection> for ApiProblemRejection {
+    fn from(value: UrlEncodedFormRejection) -> Self {
+        match value {
+            UrlEncodedFormRejection::Form(rejection) => rejection.into(),
+            UrlEncodedFormRejection::Decode(_) => Self::from_error(value.status(), &value),
+        }
+    }
+}
+
+impl From<MultipartError> for ApiProblemRejection {
+    fn from(value: MultipartError) -> Self {
+        Self::from_error(value.status(), &value)
+    }
+}
+
+impl From<TextRejection> for ApiProblemRejection {
+    fn from(value: TextRejection) -> Self {
+        Self::from_error(value.status(), &value)
+    }
+}
+
+impl From<SecurityRejection> for ApiProblemRejection {
+    fn from(value: SecurityRejection) -> Self {
+        Self::from_error(value.status(), &value)
+    }
+}
+
+impl From<JsonBodyRejection> for ApiProblemRejection {
+    fn from(value: JsonBodyRejection) -> Self {
+        let problem = Self::from_error(value.status(), &value);
+        match value {
+            JsonBodyRejection::Data {
+                pointer,
+                expected,
+                reason,
+            } => problem.with_violation(Violation {
+                source: Source::Body,
+                name: None,
+                pointer,
+                reason,
+                expected,
+            }),
+            _ => problem,
+        }
+    }
+}
+
+impl From<PathRejection> for ApiProblemRejection {
+    fn from(value: PathRejection) -> Self {
+        let name = match &value {
+            PathRejection::FailedToDeserializePathParams(err) => match err.kind() {
+                ErrorKind::ParseErrorAtKey { key, .. }
+                | ErrorKind::InvalidUtf8InPathParam { key } => Some(key.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+        Self::from_error(value.status(), &value).invalid_param(
+            Source::Path,
+            name,
+            value.body_text(),
+        )
+    }
+}
+
+impl From<QueryRejection> for ApiProblemRejection {
+    fn from(value: QueryRejection) -> Self {
+        let problem = Self::from_error(value.status(), &value);
+        problem.invalid_param(Source::Query, value.name, value.reason)
+    }
+}
+
+impl From<TypedHeaderRejection> for ApiProblemRejection {
+    fn from(value: TypedHeaderRejection) -> Self {
+        let name = value.name().as_str().to_owned();
+        Self::from_error(StatusCode::BAD_REQUEST, &value).invalid_param(
+            Source::Header,
+            Some(name),
+            value.to_string(),
+        )
+    }
+}
+
+impl ApiProblemRejection {
+    /// Produce a problem whose title is the error, and whose detail is its chain of sources.
+    fn from_error(status: StatusCode, value: &dyn std::error::Error) -> Self {
         let title = value.to_string();
 
-        let mut err = &value as &dyn std::error::Error;
+        let mut err = value;
         let mut detail = String::new();
         while let Some(predecessor) = err.source() {
             detail.extend(format!("{predecessor}; ").chars());
@@ -23,6 +138,22 @@ impl From<JsonRejection> for ApiProblemRejection {
 
         Self(HttpApiProblem::new(status).title(title).detail(detail))
     }
+
+    /// Name the parameter which caused the rejection in the extension member `invalid-params`.
+    fn invalid_param(self, source: Source, name: Option<String>, reason: String) -> Self {
+        self.with_violation(Violation {
+            source,
+            name,
+            pointer: String::new(),
+            reason,
+            expected: None,
+        })
+    }
+
+    /// Describe the value which caused the rejection in the extension member `invalid-params`.
+    fn with_violation(self, violation: Violation) -> Self {
+        Self(self.0.value("invalid-params", &[violation]))
+    }
 }
 
 impl IntoResponse for ApiProblemRejection {
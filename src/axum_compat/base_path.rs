@@ -0,0 +1,21 @@
+//! Runtime support for mounting the API at the base path of its server.
+
+use axum::Router;
+
+/// Mount `router` at `base_path`.
+///
+/// A base path of `/`, or the empty string, mounts the router at the root.
+pub fn nest<S>(base_path: &str, router: Router<S>) -> Router<S>
+where
+    S: 'static + Clone + Send + Sync,
+{
+    let base_path = base_path.trim_end_matches('/');
+    if base_path.is_empty() {
+        return router;
+    }
+    if base_path.starts_with('/') {
+        Router::new().nest(base_path, router)
+    } else {
+        Router::new().nest(&format!("/{base_path}"), router)
+    }
+}
@@ -5,11 +5,16 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    codegen::{make_ident, Endpoint, Reference, UnknownReference},
+    codegen::{
+        endpoint::request_body::{body_object, resolve_concrete},
+        make_ident,
+        validation::emit_checks,
+        Endpoint, Object, Reference, Scalar, UnknownReference, Value,
+    },
     ApiModel,
 };
 
-use super::Error;
+use super::{from_multipart::multipart_object, Error};
 
 /// Convert the variable identifier from curly brackets to leading colons
 ///
@@ -54,9 +59,67 @@ fn to_colon_path(curly_bracket_path: &str) -> Result<String, Error> {
     Ok(out)
 }
 
+/// Wrap an extractor so that it rejects requests with a problem document.
+///
+/// Without feature `api-problem`, the extractor is left unchanged.
+fn with_problem_rejection(binding: TokenStream, type_: TokenStream) -> (TokenStream, TokenStream) {
+    #[cfg(feature = "api-problem")]
+    let (binding, type_) = {
+        let with_rejection = quote!(openapi_gen::reexport::axum_extra::extract::WithRejection);
+        let problem_rejection = quote!(openapi_gen::axum_compat::ApiProblemRejection);
+        (
+            quote!(#with_rejection(#binding, _)),
+            quote!(#with_rejection<#type_, #problem_rejection>),
+        )
+    };
+    (binding, type_)
+}
+
+/// Emit statements which validate each member of a path or query parameter object.
+///
+/// Emits nothing unless the `validate` option is set.
+fn validate_parameters(
+    model: &ApiModel,
+    source: TokenStream,
+    object: &Object,
+    validations: &mut Vec<TokenStream>,
+) -> Result<(), Error> {
+    if !model.options.validate {
+        return Ok(());
+    }
+    for (name, member) in &object.members {
+        let checks = emit_checks(model, member.definition, quote!(value))
+            .map_err(Error::context(format!("validating parameter \"{name}\"")))?;
+        if checks.is_empty() {
+            continue;
+        }
+        let field_name = make_ident(&name.to_snake_case());
+        let bind = if member.inline_option {
+            quote!(if let Some(value) = &#field_name { #checks })
+        } else {
+            quote!(let value = &#field_name; #checks)
+        };
+        validations.push(quote! {
+            violations.parameter(openapi_gen::validation::Source::#source, #name, |violations| {
+                #bind
+            });
+        });
+    }
+    Ok(())
+}
+
+/// Emit an `Option<&'static str>` expression.
+fn option_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
 fn build_route<'a>(
     model: &ApiModel,
     name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    api_trait: &str,
     endpoint: &Endpoint,
 ) -> Result<TokenStream, Error> {
     let path = to_colon_path(&endpoint.path)?;
@@ -66,12 +129,35 @@ fn build_route<'a>(
     let mut parameters = Vec::new();
     let mut parameter_idents = Vec::new();
     let mut optional_parameter_map = Vec::new();
+    let mut validations = Vec::new();
 
     // extractors work from the first-encountered to the last:
-    //   1. path parameters
-    //   2. query parameters
-    //   3. header parameters
-    //   4. body
+    //   0. context
+    //   1. credentials
+    //   2. path parameters
+    //   3. query parameters
+    //   4. header parameters
+    //   5. body
+
+    if model.options.context {
+        let api_trait = make_ident(api_trait);
+        let variable_ident = make_ident("context");
+        parameters.push(quote!(#variable_ident: <Instance as #api_trait>::Context));
+        parameter_idents.push(variable_ident);
+    }
+
+    if let Some(security) = &endpoint.security {
+        let variable_ident = make_ident("credentials");
+        let credentials = make_ident(&security.credentials);
+        if security.anonymous {
+            // unlike `Option`, this rejects credentials which are present but invalid
+            let extractor = quote!(openapi_gen::axum_compat::security::Optional);
+            parameters.push(quote!(#extractor(#variable_ident): #extractor<#credentials>));
+        } else {
+            parameters.push(quote!(#variable_ident: #credentials));
+        }
+        parameter_idents.push(variable_ident);
+    }
 
     if let Some((ref_, _item, object)) =
         endpoint
@@ -93,11 +179,14 @@ fn build_route<'a>(
             .map(|name| make_ident(&name.to_snake_case()))
             .collect::<Vec<_>>();
 
-        let binding = quote!(#extractor(#type_ident{ #( #field_names ),* }));
-        let bind_type = quote!(#extractor<#type_ident>);
+        let (binding, bind_type) = with_problem_rejection(
+            quote!(#extractor(#type_ident{ #( #field_names ),* })),
+            quote!(#extractor<#type_ident>),
+        );
 
         parameters.push(quote!(#binding: #bind_type));
         parameter_idents.extend(field_names);
+        validate_parameters(model, quote!(Path), object, &mut validations)?;
     }
 
     if let Some((ref_, _item, object)) =
@@ -107,7 +196,8 @@ fn build_route<'a>(
                 "attempting to extract query parameter object",
             ))?
     {
-        let extractor = quote!(#prefix::Query);
+        // unlike axum's extractor, this one names the parameter which is invalid
+        let extractor = quote!(openapi_gen::axum_compat::query::Query);
 
         let type_ident = model
             .definition(ref_, &name_resolver)
@@ -120,11 +210,11 @@ fn build_route<'a>(
             .map(|name| make_ident(&name.to_snake_case()))
             .collect::<Vec<_>>();
 
-        let binding = quote!(#extractor(#type_ident{ #( #field_names ),* }));
-        let bind_type = quote!(#extractor<#type_ident>);
-
-        parameters.push(quote!(#binding: #bind_type));
+        parameters.push(quote! {
+            #extractor(#type_ident{ #( #field_names ),* }): #extractor<#type_ident>
+        });
         parameter_idents.extend(field_names);
+        validate_parameters(model, quote!(Query), object, &mut validations)?;
     }
 
     for (key, param) in endpoint.headers.iter() {
@@ -143,8 +233,9 @@ fn build_route<'a>(
 
         let extractor = quote!(openapi_gen::reexport::axum_extra::TypedHeader);
 
+        // optional headers which fail to parse are treated as absent, so only required headers are rejected
         let (binding, bind_type) = if param.required {
-            (
+            with_problem_rejection(
                 quote!(#extractor(#variable_ident)),
                 quote!(#extractor<#type_ident>),
             )
@@ -160,6 +251,23 @@ fn build_route<'a>(
 
         parameters.push(quote!(#binding: #bind_type));
 
+        if model.options.validate {
+            let checks = emit_checks(model, param.item_ref, quote!(value))
+                .map_err(Error::context(format!("validating param \"{key}\"")))?;
+            if !checks.is_empty() {
+                let bind = if param.required {
+                    quote!(let value = &#variable_ident; #checks)
+                } else {
+                    quote!(if let Some(value) = &#variable_ident { #checks })
+                };
+                validations.push(quote! {
+                    violations.parameter(openapi_gen::validation::Source::Header, #key, |violations| {
+                        #bind
+                    });
+                });
+            }
+        }
+
         parameter_idents.push(variable_ident);
     }
     if let Some(ref_) = endpoint.request_body {
@@ -171,78 +279,355 @@ fn build_route<'a>(
         let type_ident = make_ident(&item.rust_name);
         let variable_ident = make_ident("request_body");
 
+        // form bodies decode objects according to their encoding; other forms are left to axum
+        let form_object = if item.is_form_urlencoded() {
+            body_object(model, ref_)
+                .map_err(Error::context("resolving form request body"))?
+                .map(|(_ref, object_name, _object)| object_name)
+        } else {
+            None
+        };
+
         if item.is_json() {
-            // mutability here may or may not be unused depending on the set of feature flags enabled;
-            // either way, it is no error
-            #[allow(unused_mut)]
-            let mut binding = quote!(#prefix::Json(#variable_ident));
-            #[allow(unused_mut)]
-            let mut type_ = quote!(#prefix::Json<#type_ident>);
-
-            #[cfg(feature = "api-problem")]
-            {
-                let with_rejection =
-                    quote!(openapi_gen::reexport::axum_extra::extract::WithRejection);
-                let problem_rejection = quote!(openapi_gen::axum_compat::ApiProblemRejection);
-                binding = quote!(#with_rejection(#binding, _));
-                type_ = quote!(#with_rejection<#type_, #problem_rejection>);
-            }
+            // unlike axum's extractor, this one reports where the body is invalid
+            let extractor = quote!(openapi_gen::axum_compat::json::Json);
+            parameters.push(quote! {
+                #extractor(#variable_ident): #extractor<#type_ident>
+            });
+        } else if item.is_form_urlencoded() && form_object.is_none() {
+            let extractor = quote!(#prefix::Form);
+            let (binding, type_) = with_problem_rejection(
+                quote!(#extractor(#variable_ident)),
+                quote!(#extractor<#type_ident>),
+            );
 
             parameters.push(quote! {
                 #binding: #type_
             });
+        } else if item.is_multipart_form_data() || form_object.is_some() {
+            let (object_name, extractor) = match form_object {
+                Some(object_name) => (
+                    object_name,
+                    quote!(openapi_gen::axum_compat::form::UrlEncodedForm),
+                ),
+                None => {
+                    let (_ref, object_name, _object) = body_object(model, ref_)
+                        .map_err(Error::context("resolving multipart request body"))?
+                        .ok_or_else(|| Error::new("multipart request body must be an object"))?;
+                    (
+                        object_name,
+                        quote!(openapi_gen::axum_compat::multipart::MultipartForm),
+                    )
+                }
+            };
+            let object_ident = make_ident(object_name);
+
+            if item.nullable {
+                optional_parameter_map.push(quote! {
+                    let #variable_ident = #variable_ident.map(|#variable_ident| #variable_ident.0);
+                });
+                parameters.push(quote! {
+                    #variable_ident: Option<#extractor<#object_ident>>
+                });
+            } else {
+                parameters.push(quote! {
+                    #extractor(#variable_ident): #extractor<#object_ident>
+                });
+            }
+        } else if item.is_canonical_text(model) {
+            let type_ = model
+                .definition(ref_, &name_resolver)
+                .map_err(Error::context("getting text request body type"))?;
+            let extractor = quote!(openapi_gen::axum_compat::text::Text);
+            parameters.push(quote! {
+                #extractor(#variable_ident): #extractor<#type_>
+            });
+        } else if matches!(
+            resolve_concrete(model, ref_)
+                .map_err(Error::context("resolving request body"))?
+                .value,
+            Value::Scalar(Scalar::OctetStream)
+        ) {
+            let type_ = model
+                .definition(ref_, &name_resolver)
+                .map_err(Error::context("getting octet stream request body type"))?;
+            parameters.push(quote! {
+                #variable_ident: #type_
+            });
         } else {
             parameters.push(quote! {
                 #variable_ident: Vec<u8>
             });
         }
 
+        // bodies which are passed on as raw bytes are never validated
+        let is_decoded = item.is_json()
+            || item.is_form_urlencoded()
+            || item.is_multipart_form_data()
+            || item.is_canonical_text(model);
+        if model.options.validate && is_decoded {
+            let checks = emit_checks(model, ref_, quote!(value))
+                .map_err(Error::context("validating request body"))?;
+            if !checks.is_empty() {
+                validations.push(quote! {
+                    violations.body(|violations| {
+                        let value = &#variable_ident;
+                        #checks
+                    });
+                });
+            }
+        }
+
         parameter_idents.push(variable_ident);
     }
 
     let method_name = make_ident(&endpoint.function_name(None));
 
+    let call = if validations.is_empty() {
+        quote!(instance.#method_name(#( #parameter_idents ),*).await)
+    } else {
+        let into_response =
+            quote!(openapi_gen::reexport::axum::response::IntoResponse::into_response);
+        quote! {
+            let mut violations = openapi_gen::validation::Violations::new();
+            #( #validations )*
+            if let Err(rejection) = violations.into_result() {
+                return #into_response(rejection);
+            }
+            #into_response(instance.#method_name(#( #parameter_idents ),*).await)
+        }
+    };
+
+    let customize = quote!(openapi_gen::axum_compat::customize);
+    let operation_id = option_str(endpoint.operation_id.as_deref());
+    let method = make_ident(&endpoint.verb.to_string());
+    let spec_path = &endpoint.path;
+    let tag = option_str(endpoint.tag.as_deref());
+
     Ok(quote! {
         .route(
             #path,
-            #verb({
-                let instance = instance.clone();
-                move |#( #parameters ),*| async move {
-                    #( #optional_parameter_map )*
-                    instance.#method_name(#( #parameter_idents ),*).await
-                }
-            })
+            #customize::Customize::route(
+                &customize,
+                &#customize::Operation {
+                    operation_id: #operation_id,
+                    method: openapi_gen::reexport::http::Method::#method,
+                    path: #spec_path,
+                    tag: #tag,
+                },
+                #verb({
+                    let instance = instance.clone();
+                    move |#( #parameters ),*| async move {
+                        #( #optional_parameter_map )*
+                        #call
+                    }
+                }),
+            )
         )
     })
 }
 
-/// Create `fn build_router`, which transforms an arbitrary `Api` instance into a `Router`.
-pub(crate) fn fn_build_router<'a>(
+/// Create a function which transforms an implementation of some API traits into a `Router` serving `endpoints`.
+///
+/// When the specification declares servers, this also creates `fn {name}_at`, which mounts the router at a
+/// base path chosen at runtime; `fn {name}` then mounts it at the base path of the default server.
+fn router_fn<'a>(
     model: &ApiModel,
     name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    name: &str,
+    subject: &str,
+    bounds: &[&str],
+    endpoints: &[(&str, &Endpoint)],
 ) -> Result<TokenStream, Error> {
     let mut routes = Vec::<TokenStream>::new();
 
-    for endpoint in model.endpoints.iter() {
-        let route = build_route(model, &name_resolver, endpoint)?;
+    for (api_trait, endpoint) in endpoints {
+        let route = build_route(model, &name_resolver, api_trait, endpoint)?;
         routes.push(route);
     }
 
+    let ident = make_ident(name);
+    let bounds = bounds
+        .iter()
+        .map(|bound| make_ident(bound))
+        .collect::<Vec<_>>();
+    let mut associated_bounds = Vec::new();
+    if model.options.context {
+        associated_bounds.push(quote! {
+            #(
+                , <Instance as #bounds>::Context: openapi_gen::reexport::axum::extract::FromRequestParts<()>
+            )*
+        });
+    }
+    if model.options.is_fallible() {
+        associated_bounds.push(quote! {
+            #(
+                , <Instance as #bounds>::Error: openapi_gen::reexport::axum::response::IntoResponse
+            )*
+        });
+    }
+
+    let customize_bound = quote!(Customization: openapi_gen::axum_compat::customize::Customize);
+    let ident_with = make_ident(&format!("{name}_with"));
+    let customize_docs = " Before it is added to the router, the route of each operation is passed through `customize`.";
+
+    if model.servers.is_empty() {
+        let docs = format!(" Transform {subject} into a [`Router`][axum::Router].");
+        return Ok(quote! {
+            #[doc = #docs]
+            pub fn #ident<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+            where
+                Instance: 'static + #( #bounds + )* Send + Sync
+                #( #associated_bounds )*
+            {
+                #ident_with(instance, ())
+            }
+
+            #[doc = #docs]
+            ///
+            #[doc = #customize_docs]
+            pub fn #ident_with<Instance, Customization>(
+                instance: Instance,
+                // `customize` is unused if there are no endpoints
+                #[allow(unused_variables)]
+                customize: Customization,
+            ) -> openapi_gen::reexport::axum::Router
+            where
+                Instance: 'static + #( #bounds + )* Send + Sync,
+                #customize_bound
+                #( #associated_bounds )*
+            {
+                // `instance` is unused if there are no endpoints
+                #[allow(unused_variables)]
+                let instance = ::std::sync::Arc::new(instance);
+                openapi_gen::reexport::axum::Router::new()
+                #( #routes )*
+            }
+        });
+    }
+
+    let ident_at = make_ident(&format!("{name}_at"));
+    let ident_at_with = make_ident(&format!("{name}_at_with"));
+    let docs =
+        format!(" Transform {subject} into a [`Router`][axum::Router], mounted at [`BASE_PATH`].");
+    let docs_at =
+        format!(" Transform {subject} into a [`Router`][axum::Router], mounted at `base_path`.");
+
     Ok(quote! {
-        /// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
-        pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+        #[doc = #docs]
+        pub fn #ident<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+        where
+            Instance: 'static + #( #bounds + )* Send + Sync
+            #( #associated_bounds )*
+        {
+            #ident_at(instance, BASE_PATH)
+        }
+
+        #[doc = #docs_at]
+        pub fn #ident_at<Instance>(instance: Instance, base_path: &str) -> openapi_gen::reexport::axum::Router
+        where
+            Instance: 'static + #( #bounds + )* Send + Sync
+            #( #associated_bounds )*
+        {
+            #ident_at_with(instance, base_path, ())
+        }
+
+        #[doc = #docs]
+        ///
+        #[doc = #customize_docs]
+        pub fn #ident_with<Instance, Customization>(
+            instance: Instance,
+            customize: Customization,
+        ) -> openapi_gen::reexport::axum::Router
         where
-            Instance: 'static + Api + Send + Sync
+            Instance: 'static + #( #bounds + )* Send + Sync,
+            #customize_bound
+            #( #associated_bounds )*
+        {
+            #ident_at_with(instance, BASE_PATH, customize)
+        }
+
+        #[doc = #docs_at]
+        ///
+        #[doc = #customize_docs]
+        pub fn #ident_at_with<Instance, Customization>(
+            instance: Instance,
+            base_path: &str,
+            // `customize` is unused if there are no endpoints
+            #[allow(unused_variables)]
+            customize: Customization,
+        ) -> openapi_gen::reexport::axum::Router
+        where
+            Instance: 'static + #( #bounds + )* Send + Sync,
+            #customize_bound
+            #( #associated_bounds )*
         {
             // `instance` is unused if there are no endpoints
             #[allow(unused_variables)]
             let instance = ::std::sync::Arc::new(instance);
-            openapi_gen::reexport::axum::Router::new()
-            #( #routes )*
+            let router = openapi_gen::reexport::axum::Router::new()
+            #( #routes )*;
+            openapi_gen::axum_compat::base_path::nest(base_path, router)
         }
     })
 }
 
+/// Create `fn build_router`, which transforms an arbitrary `Api` instance into a `Router`.
+///
+/// When emitting one trait per tag, this instead creates one function per trait, and `fn build_router`
+/// requires an implementation of every trait.
+pub(crate) fn fn_build_router<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    let api_traits = model.api_traits();
+    let mut router_fns = Vec::with_capacity(api_traits.len() + 1);
+
+    for api_trait in &api_traits {
+        let subject = format!("an instance of [`trait {0}`][{0}]", api_trait.name);
+        router_fns.push(router_fn(
+            model,
+            &name_resolver,
+            api_trait.router,
+            &subject,
+            &[api_trait.name],
+            &api_trait
+                .endpoints
+                .iter()
+                .map(|endpoint| (api_trait.name, *endpoint))
+                .collect::<Vec<_>>(),
+        )?);
+    }
+
+    if model.options.trait_per_tag {
+        let bounds = api_traits
+            .iter()
+            .map(|api_trait| api_trait.name)
+            .collect::<Vec<_>>();
+        let endpoints = api_traits
+            .iter()
+            .flat_map(|api_trait| {
+                api_trait
+                    .endpoints
+                    .iter()
+                    .map(|endpoint| (api_trait.name, *endpoint))
+            })
+            .collect::<Vec<_>>();
+        router_fns.push(router_fn(
+            model,
+            &name_resolver,
+            "build_router",
+            "an implementation of every API trait",
+            &bounds,
+            &endpoints,
+        )?);
+    }
+
+    Ok(quote! {
+        #( #router_fns )*
+    })
+}
+
 #[cfg(test)]
 mod tests {
     mod to_colon_path {
@@ -0,0 +1,53 @@
+//! Runtime support for customizing the routes of a generated router.
+//!
+//! Each router function has a `_with` variant, such as `build_router_with`, which passes the route of every
+//! operation through a [`Customize`] implementation before adding it to the router. This is the place to apply
+//! layers such as rate limits, timeouts, or authorization to particular operations:
+//!
+//! ```ignore
+//! let router = build_router_with(instance, |operation: &Operation, route: MethodRouter| {
+//!     if operation.tag == Some("admin") {
+//!         route.layer(RequireAuthorizationLayer::bearer("secret"))
+//!     } else {
+//!         route
+//!     }
+//! });
+//! ```
+
+use axum::routing::MethodRouter;
+use http::Method;
+
+/// Metadata describing an operation of the specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    /// The `operationId` of the operation, if it declares one.
+    pub operation_id: Option<&'static str>,
+    /// The method at which the operation is served.
+    pub method: Method,
+    /// The path template of the operation, as written in the specification.
+    pub path: &'static str,
+    /// The first tag of the operation, if it has any.
+    pub tag: Option<&'static str>,
+}
+
+/// Transforms the route of each operation before it is added to a generated router.
+pub trait Customize {
+    /// Transform the route which serves `operation`.
+    fn route(&self, operation: &Operation, route: MethodRouter) -> MethodRouter;
+}
+
+/// Leaves every route unchanged.
+impl Customize for () {
+    fn route(&self, _operation: &Operation, route: MethodRouter) -> MethodRouter {
+        route
+    }
+}
+
+impl<F> Customize for F
+where
+    F: Fn(&Operation, MethodRouter) -> MethodRouter,
+{
+    fn route(&self, operation: &Operation, route: MethodRouter) -> MethodRouter {
+        self(operation, route)
+    }
+}
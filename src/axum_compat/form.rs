@@ -0,0 +1,63 @@
+//! Runtime support for `application/x-www-form-urlencoded` request bodies.
+//!
+//! Form bodies whose schema is an object are extracted via [`UrlEncodedForm`], which respects the
+//! `style` and `explode` settings recorded by the generated [`FormEncoding`] implementation.
+
+use axum::{
+    async_trait,
+    extract::{rejection::FormRejection, FromRequest, Request},
+    response::{IntoResponse, Response},
+    Form,
+};
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::form::{FormEncoding, FormError};
+
+/// Extractor which decodes an `application/x-www-form-urlencoded` request body into `T`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UrlEncodedForm<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for UrlEncodedForm<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + FormEncoding,
+{
+    type Rejection = UrlEncodedFormRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Form(pairs) = Form::<Vec<(String, String)>>::from_request(req, state).await?;
+        crate::form::from_pairs(pairs).map(Self).map_err(Into::into)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UrlEncodedFormRejection {
+    #[error(transparent)]
+    Form(#[from] FormRejection),
+    #[error(transparent)]
+    Decode(#[from] FormError),
+}
+
+impl UrlEncodedFormRejection {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            UrlEncodedFormRejection::Form(rejection) => rejection.status(),
+            UrlEncodedFormRejection::Decode(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+}
+
+impl IntoResponse for UrlEncodedFormRejection {
+    #[cfg(feature = "api-problem")]
+    fn into_response(self) -> Response {
+        super::ApiProblemRejection::from(self).into_response()
+    }
+
+    #[cfg(not(feature = "api-problem"))]
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
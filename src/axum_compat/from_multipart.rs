@@ -0,0 +1,115 @@
+use heck::AsSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    axum_compat::Error,
+    codegen::{endpoint::request_body::resolve_concrete, make_ident, Item, Object, Scalar, Value},
+    ApiModel,
+};
+
+/// `true` when values of this item are raw bytes, which should be taken directly from the part data.
+fn is_binary(item: &Item) -> bool {
+    !item.nullable && matches!(item.value, Value::Scalar(Scalar::Binary))
+}
+
+/// Implement `FromMultipart` for the object underlying a `multipart/form-data` request body.
+///
+/// Binary properties are taken directly from the part data; all other properties are deserialized from their part.
+/// Array properties collect every part sharing the property's name.
+pub(crate) fn impl_from_multipart(
+    model: &ApiModel,
+    request_body: &Item,
+    object_name: &str,
+    object: &Object,
+) -> Result<TokenStream, Error> {
+    let object_ident = make_ident(object_name);
+    let prefix = quote!(openapi_gen::axum_compat::multipart);
+
+    let mut takes = Vec::with_capacity(object.members.len());
+    let mut fields = Vec::with_capacity(object.members.len());
+
+    for (member_name, member) in object.members.iter() {
+        let mut field_name = format!("{}", AsSnakeCase(member_name));
+        model.deconflict_member_or_variant_ident(&mut field_name);
+        let field_ident = make_ident(&field_name);
+
+        if member.read_only {
+            fields.push(quote!(#field_ident: Default::default()));
+            continue;
+        }
+
+        let encoding = request_body.encoding.get(member_name);
+        let content_type = match encoding.and_then(|encoding| encoding.content_type.as_deref()) {
+            Some(content_type) => quote!(Some(#content_type)),
+            None => quote!(None),
+        };
+        let headers = encoding
+            .map(|encoding| encoding.headers.as_slice())
+            .unwrap_or_default();
+        let encoding = quote! {
+            &#prefix::PartEncoding {
+                content_type: #content_type,
+                headers: &[#( #headers ),*],
+            }
+        };
+
+        let member_item = resolve_concrete(model, member.definition).map_err(Error::context(
+            format!("resolving multipart member \"{member_name}\""),
+        ))?;
+
+        let field_value = match &member_item.value {
+            Value::List(list) if !member_item.nullable => {
+                let list_item = resolve_concrete(model, list.item).map_err(Error::context(
+                    format!("resolving multipart list item of \"{member_name}\""),
+                ))?;
+                takes.push(quote!(let #field_ident = parts.take_all(#member_name, #encoding)?;));
+                let collect = if is_binary(list_item) {
+                    quote!(#field_ident.into_iter().map(#prefix::Part::into_vec).collect())
+                } else {
+                    quote!(#field_ident.iter().map(#prefix::Part::deserialize).collect::<Result<_, _>>())
+                };
+                match (member.inline_option, is_binary(list_item)) {
+                    (false, true) => collect,
+                    (false, false) => quote!(#collect?),
+                    (true, true) => quote!((!#field_ident.is_empty()).then(|| #collect)),
+                    (true, false) => {
+                        quote!((!#field_ident.is_empty()).then(|| #collect).transpose()?)
+                    }
+                }
+            }
+            _ => {
+                takes.push(quote!(let #field_ident = parts.take(#member_name, #encoding)?;));
+                match (member.inline_option, is_binary(member_item)) {
+                    (false, true) => {
+                        quote!(#prefix::Part::required(#member_name, #field_ident)?.into_vec())
+                    }
+                    (false, false) => {
+                        quote!(#prefix::Part::required(#member_name, #field_ident)?.deserialize()?)
+                    }
+                    (true, true) => quote!(#field_ident.map(#prefix::Part::into_vec)),
+                    (true, false) => quote! {
+                        #field_ident.as_ref().map(#prefix::Part::deserialize).transpose()?
+                    },
+                }
+            }
+        };
+
+        fields.push(quote!(#field_ident: #field_value));
+    }
+
+    Ok(quote! {
+        impl #prefix::FromMultipart for #object_ident {
+            fn from_parts(
+                // `parts` is unused if the object has no members
+                #[allow(unused_variables)]
+                parts: &mut #prefix::Parts,
+            ) -> Result<Self, #prefix::MultipartError> {
+                #( #takes )*
+                Ok(Self {
+                    #( #fields ),*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,126 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::security::{requirement_variant_name, KeyLocation, SchemeKind, SecurityScheme},
+        make_ident, Endpoint,
+    },
+    ApiModel,
+};
+
+/// Wrap the body of `from_request_parts` in an implementation of `FromRequestParts` for `ident`.
+fn wrap_impl(ident: &syn::Ident, state: TokenStream, body: TokenStream) -> TokenStream {
+    quote! {
+        #[openapi_gen::reexport::async_trait::async_trait]
+        impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for #ident
+        where
+            S: Send + Sync,
+        {
+            type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+
+            async fn from_request_parts(
+                parts: &mut openapi_gen::reexport::http::request::Parts,
+                #state: &S,
+            ) -> Result<Self, Self::Rejection> {
+                #body
+            }
+        }
+    }
+}
+
+/// Implement `FromRequestParts` for the credential type of a security scheme.
+pub(crate) fn impl_scheme_from_request_parts(scheme: &SecurityScheme) -> TokenStream {
+    let prefix = quote!(openapi_gen::axum_compat::security);
+    let ident = make_ident(&scheme.rust_name);
+    let name = &scheme.name;
+
+    let extract = match &scheme.kind {
+        SchemeKind::ApiKey {
+            location,
+            name: key,
+        } => match location {
+            KeyLocation::Header => {
+                let key = key.to_lowercase();
+                quote!(#prefix::api_key_header(parts, #name, #key))
+            }
+            KeyLocation::Query => quote!(#prefix::api_key_query(parts, #name, #key)),
+            KeyLocation::Cookie => quote!(#prefix::api_key_cookie(parts, #name, #key)),
+        },
+        SchemeKind::Basic => quote!(#prefix::basic(parts, #name)),
+        SchemeKind::Bearer => quote!(#prefix::bearer(parts, #name)),
+        SchemeKind::Http { scheme } => quote!(#prefix::credentials(parts, #name, #scheme)),
+    };
+
+    wrap_impl(&ident, quote!(_state), quote!(#extract.map(Self)))
+}
+
+/// Implement `FromRequestParts` for the credentials enum of an endpoint, if it has one.
+///
+/// Requirements are tried in order; the first which is satisfied in full produces the credentials.
+pub(crate) fn impl_credentials_from_request_parts(
+    model: &ApiModel,
+    endpoint: &Endpoint,
+) -> Option<TokenStream> {
+    let security = endpoint
+        .security
+        .as_ref()
+        .filter(|security| security.has_credentials_enum())?;
+    let ident = make_ident(&security.credentials);
+    let rejection = quote!(openapi_gen::axum_compat::security::SecurityRejection);
+
+    let requirements = security
+        .requirements
+        .iter()
+        .enumerate()
+        .map(|(idx, requirement)| {
+            let variant = make_ident(&requirement_variant_name(model, requirement));
+            let schemes = requirement
+                .keys()
+                .map(|name| &model.security_schemes[name])
+                .collect::<Vec<_>>();
+            let variables = schemes
+                .iter()
+                .map(|scheme| make_ident(&scheme.rust_name.to_snake_case()))
+                .collect::<Vec<_>>();
+            let extract = schemes.iter().map(|scheme| {
+                let scheme_ident = make_ident(&scheme.rust_name);
+                quote!(<#scheme_ident as openapi_gen::reexport::axum::extract::FromRequestParts<S>>::from_request_parts(parts, state).await)
+            });
+            let combine = |err: TokenStream| {
+                if idx == 0 {
+                    err
+                } else {
+                    quote!(rejection.or(#err))
+                }
+            };
+
+            if schemes.len() == 1 {
+                let err = combine(quote!(err));
+                quote! {
+                    let rejection = match #( #extract )* {
+                        Ok(#( #variables )*) => return Ok(Self::#variant( #( #variables )* )),
+                        Err(err) => #err,
+                    };
+                }
+            } else {
+                let err = combine(quote!(#rejection::requirement([ #( #variables.err() ),* ])));
+                quote! {
+                    let rejection = match ( #( #extract ),* ) {
+                        ( #( Ok(#variables) ),* ) => return Ok(Self::#variant( #( #variables ),* )),
+                        ( #( #variables ),* ) => #err,
+                    };
+                }
+            }
+        });
+
+    Some(wrap_impl(
+        &ident,
+        quote!(state),
+        quote! {
+            #( #requirements )*
+            Err(rejection)
+        },
+    ))
+}
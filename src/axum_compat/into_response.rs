@@ -4,7 +4,11 @@ use quote::quote;
 
 use crate::{
     axum_compat::Error,
-    codegen::{make_ident, value::object::BODY_IDENT, Object, Reference, Value},
+    codegen::{
+        make_ident,
+        value::{object::BODY_IDENT, one_of_enum::VariantStatus},
+        Object, Reference, Value,
+    },
     ApiModel,
 };
 
@@ -47,7 +51,7 @@ fn impl_into_response_for_response_type(
     response: &Reference,
     response_name: &str,
     variant_name: &str,
-    status_code: Option<http::StatusCode>,
+    status: Option<VariantStatus>,
 ) -> Result<TokenStream, Error> {
     let item = model
         .resolve(response)
@@ -63,8 +67,10 @@ fn impl_into_response_for_response_type(
         make_ident(&binding)
     };
     let item_ident = make_ident(&item.rust_name);
-    let status = match status_code {
-        Some(status_code) => {
+    // variants whose status is only known at runtime carry it alongside their content
+    let mut unpack_status = None;
+    let status = match status {
+        Some(VariantStatus::Code(status_code)) => {
             let status_name = status_code
                 .canonical_reason()
                 .map(|reason| reason.to_shouty_snake_case())
@@ -72,21 +78,24 @@ fn impl_into_response_for_response_type(
             let ident = make_ident(&status_name);
             quote!(openapi_gen::reexport::http::status::StatusCode::#ident)
         }
-        None => quote!(openapi_gen::AsStatusCode::as_status_code(&#item_ident)),
+        Some(VariantStatus::Range(_) | VariantStatus::Default) => {
+            // named after the variant so that it cannot be shadowed by a header
+            let status_ident = make_ident(&format!("{variant_binding}_status"));
+            unpack_status = Some(quote! {
+                let #status_ident = openapi_gen::AsStatusCode::as_status_code(&#variant_binding);
+                let #variant_binding = #variant_binding.into_body();
+            });
+            quote!(#status_ident)
+        }
+        None => return Err(Error::new("failed to get status for response variant")),
     };
 
-    // if the item is a default item, assume it implements `IntoResponse`
-    if variant_name == "Default" {
-        return Ok(quote! {
-            #response_ident::#variant_ident(#variant_binding) => {
-                #variant_binding.into_response()
-            }
-        });
-    }
-
     let mut headers = Vec::new();
 
-    if let Some(content_type) = item.content_type.as_ref() {
+    // text bodies set their own content type, as it names the charset in which they are encoded
+    let canonical_text = item.is_canonical_text(model);
+
+    if let Some(content_type) = item.content_type.as_ref().filter(|_| !canonical_text) {
         let key = quote!(openapi_gen::reexport::http::header::CONTENT_TYPE);
         let value = quote!(openapi_gen::reexport::http::HeaderValue::from_static(#content_type));
         headers.push((key, value));
@@ -147,12 +156,22 @@ fn impl_into_response_for_response_type(
     let header_map_ident_comma = (!headers.is_empty()).then_some(quote!(header_map,));
 
     // wrap the body in a JSON wrapper if it is not a unit type, and
-    // the item content-type ends with "json"
-    let wrap_with_json =
-        !matches!(&item.value, Value::Scalar(crate::codegen::Scalar::Unit)) && item.is_json();
+    // the item content-type ends with "json".
+    // problem details are not wrapped: they respond with their own `application/problem+json` content type
+    let wrap_with_json = !matches!(
+        &item.value,
+        Value::Scalar(crate::codegen::Scalar::Unit | crate::codegen::Scalar::ApiProblem)
+    ) && item.is_json();
 
     let body = if wrap_with_json {
         quote!(openapi_gen::reexport::axum::Json(#body))
+    } else if let Some(content_type) = item.content_type.as_ref().filter(|_| canonical_text) {
+        quote! {
+            openapi_gen::axum_compat::text::TextResponse {
+                content_type: #content_type,
+                value: #body,
+            }
+        }
     } else {
         quote!(#body)
     };
@@ -167,6 +186,7 @@ fn impl_into_response_for_response_type(
 
     Ok(quote! {
         #response_ident::#variant_ident(#variant_binding) => {
+            #unpack_status
             #unpack_object
             #define_header_map
             #into_response
@@ -211,7 +231,7 @@ pub(crate) fn impl_into_response(
             &variant.definition,
             response_name,
             variant_name,
-            variant.status_code,
+            variant.status,
         )?);
     }
 
@@ -0,0 +1,183 @@
+//! Runtime support for `application/json` request bodies.
+//!
+//! JSON bodies are extracted via [`Json`], which tracks the path of each value as it is deserialized. When a
+//! body does not match its type, the [`JsonBodyRejection`] says where: a JSON pointer to the value which could
+//! not be deserialized, and what was expected there.
+
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{rejection::BytesRejection, FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use http::{header::CONTENT_TYPE, HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_path_to_error::Segment;
+
+use crate::validation::push_pointer_segment;
+
+/// Extractor which deserializes an `application/json` request body into `T`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for Json<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = JsonBodyRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !has_json_content_type(req.headers()) {
+            return Err(JsonBodyRejection::MissingJsonContentType);
+        }
+        let data = Bytes::from_request(req, state).await?;
+        decode(&data).map(Self)
+    }
+}
+
+/// `true` when the request declares `application/json`, or another type with the suffix `+json`.
+fn has_json_content_type(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<mime::Mime>().ok())
+        .is_some_and(|mime| {
+            mime.type_() == mime::APPLICATION
+                && (mime.subtype() == mime::JSON || mime.suffix() == Some(mime::JSON))
+        })
+}
+
+/// Deserialize a JSON document, tracking the path to any value which does not match its type.
+pub fn decode<T>(data: &[u8]) -> Result<T, JsonBodyRejection>
+where
+    T: DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(data);
+    let value = serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(JsonBodyRejection::from_path_error)?;
+    deserializer.end().map_err(JsonBodyRejection::Syntax)?;
+    Ok(value)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum JsonBodyRejection {
+    #[error(transparent)]
+    Bytes(#[from] BytesRejection),
+    #[error("expected request with `Content-Type: application/json`")]
+    MissingJsonContentType,
+    #[error("failed to parse the request body as JSON")]
+    Syntax(#[source] serde_json::Error),
+    #[error("failed to deserialize the JSON body at `{pointer}`: {reason}")]
+    Data {
+        /// JSON pointer to the value which could not be deserialized.
+        pointer: String,
+        /// What was expected instead of the value, if known.
+        expected: Option<String>,
+        /// What is wrong with the value.
+        reason: String,
+    },
+}
+
+impl JsonBodyRejection {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            JsonBodyRejection::Bytes(rejection) => rejection.status(),
+            JsonBodyRejection::MissingJsonContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            JsonBodyRejection::Syntax(_) => StatusCode::BAD_REQUEST,
+            JsonBodyRejection::Data { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn from_path_error(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let mut pointer = String::new();
+        for segment in err.path().iter() {
+            match segment {
+                Segment::Seq { index } => push_pointer_segment(&mut pointer, index),
+                Segment::Map { key } => push_pointer_segment(&mut pointer, key),
+                Segment::Enum { variant } => push_pointer_segment(&mut pointer, variant),
+                Segment::Unknown => {}
+            }
+        }
+
+        let err = err.into_inner();
+        if !err.is_data() {
+            return Self::Syntax(err);
+        }
+
+        // the pointer makes the position which serde_json appends redundant
+        let mut reason = err.to_string();
+        let position = format!(" at line {} column {}", err.line(), err.column());
+        if reason.ends_with(&position) {
+            reason.truncate(reason.len() - position.len());
+        }
+
+        // serde reports missing fields at their object; point at the field instead
+        if let Some(field) = reason
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+        {
+            push_pointer_segment(&mut pointer, field);
+        }
+
+        let expected = reason
+            .split_once(", expected ")
+            .map(|(_, expected)| expected.to_owned());
+
+        Self::Data {
+            pointer,
+            expected,
+            reason,
+        }
+    }
+}
+
+impl IntoResponse for JsonBodyRejection {
+    #[cfg(feature = "api-problem")]
+    fn into_response(self) -> Response {
+        super::ApiProblemRejection::from(self).into_response()
+    }
+
+    #[cfg(not(feature = "api-problem"))]
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Pet {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    fn data_error(json: &str) -> (String, Option<String>) {
+        match decode::<Pet>(json.as_bytes()) {
+            Err(JsonBodyRejection::Data {
+                pointer, expected, ..
+            }) => (pointer, expected),
+            other => panic!("expected a data error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejections_point_at_the_invalid_value() {
+        assert_eq!(
+            data_error(r#"{"name": "Rex", "tags": ["good", 7]}"#),
+            ("/tags/1".to_owned(), Some("a string".to_owned()))
+        );
+        assert_eq!(data_error(r#"{"name": "Rex"}"#), ("/tags".to_owned(), None));
+        assert!(matches!(
+            decode::<Pet>(br#"{"name": "Rex""#),
+            Err(JsonBodyRejection::Syntax(_))
+        ));
+    }
+}
@@ -6,21 +6,40 @@
 //! - `impl Header` for all header types
 //! - `fn build_router` to convert the implementation into an appropriate router
 //!
+//! `multipart/form-data` request bodies additionally get an `impl FromMultipart`, and credential types
+//! get an `impl FromRequestParts`.
+//!
 //! For simplicity, we provide a single function `axum_items` which generates everything required.
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    axum_compat::{header::impl_header, into_response::impl_into_response},
-    codegen::{Reference, Scalar, UnknownReference, Value},
+    axum_compat::{
+        from_multipart::{impl_from_multipart, multipart_object},
+        from_request_parts::{impl_credentials_from_request_parts, impl_scheme_from_request_parts},
+        header::impl_header,
+        into_response::impl_into_response,
+    },
+    codegen::{endpoint::request_body::body_object, Reference, Scalar, UnknownReference, Value},
     ApiModel,
 };
 
 mod build_router;
+mod from_multipart;
+mod from_request_parts;
 mod header;
 mod into_response;
 
+pub mod base_path;
+pub mod customize;
+pub mod form;
+pub mod json;
+pub mod multipart;
+pub mod query;
+pub mod security;
+pub mod text;
+
 #[cfg(feature = "api-problem")]
 mod api_problem_rejection;
 #[cfg(feature = "api-problem")]
@@ -40,6 +59,15 @@ pub(crate) fn axum_items<'a>(
         header_impls.push(impl_header(model, header_item)?);
     }
 
+    let scheme_impls = model
+        .security_schemes
+        .values()
+        .map(impl_scheme_from_request_parts);
+    let credentials_impls = model
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| impl_credentials_from_request_parts(model, endpoint));
+
     let mut into_response_impls = Vec::with_capacity(model.endpoints.len());
     for endpoint in model.endpoints.iter() {
         let reference = endpoint.response;
@@ -50,11 +78,46 @@ pub(crate) fn axum_items<'a>(
         );
     }
 
+    let mut from_multipart_impls = Vec::new();
+    let mut implemented_multipart_objects = Vec::new();
+    for request_body in model
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| endpoint.request_body)
+    {
+        let item = model
+            .resolve(request_body)
+            .map_err(Error::context("getting item for request body"))?;
+        if !item.is_multipart_form_data() {
+            continue;
+        }
+        let (object_ref, object_name, object) = body_object(model, request_body)
+            .map_err(Error::context("resolving multipart request body"))?
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "multipart request body '{}' must be an object",
+                    &item.rust_name
+                ))
+            })?;
+        if implemented_multipart_objects.contains(&object_ref) {
+            continue;
+        }
+        implemented_multipart_objects.push(object_ref);
+
+        from_multipart_impls.push(
+            impl_from_multipart(model, item, object_name, object)
+                .map_err(Error::context("implementing `FromMultipart`"))?,
+        );
+    }
+
     let build_router = build_router::fn_build_router(model, name_resolver)?;
 
     Ok(quote! {
         #( #header_impls )*
+        #( #scheme_impls )*
+        #( #credentials_impls )*
         #( #into_response_impls )*
+        #( #from_multipart_impls )*
         #build_router
     })
 }
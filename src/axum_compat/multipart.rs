@@ -0,0 +1,277 @@
+//! Runtime support for `multipart/form-data` request bodies.
+//!
+//! Generated code implements [`FromMultipart`] for each `multipart/form-data` request body,
+//! and the generated router extracts it via [`MultipartForm`].
+//!
+//! Every part, including file parts, is buffered in memory before it is decoded; streaming parts are not currently
+//! supported. The whole request body is therefore subject to axum's [`DefaultBodyLimit`], which is 2 MiB unless the
+//! router is given a different limit, for example with `DefaultBodyLimit::max`.
+//!
+//! [`DefaultBodyLimit`]: axum::extract::DefaultBodyLimit
+
+use std::collections::{HashMap, VecDeque};
+
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{
+        multipart::{MultipartError as FieldError, MultipartRejection},
+        FromRequest, Multipart, Request,
+    },
+    response::{IntoResponse, Response},
+};
+use http::{HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+
+/// Types which can be decoded from the parts of a `multipart/form-data` request body.
+pub trait FromMultipart: Sized {
+    /// Decode an instance of this type, taking the parts it requires from `parts`.
+    fn from_parts(parts: &mut Parts) -> Result<Self, MultipartError>;
+}
+
+/// Extractor which decodes a `multipart/form-data` request body into `T`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultipartForm<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for MultipartForm<T>
+where
+    S: Send + Sync,
+    T: FromMultipart,
+{
+    type Rejection = MultipartError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let multipart = Multipart::from_request(req, state).await?;
+        let mut parts = Parts::collect(multipart).await?;
+        T::from_parts(&mut parts).map(Self)
+    }
+}
+
+/// Encoding constraints on a single part, as declared by the `encoding` object of the media type.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PartEncoding {
+    /// Permitted content types for this part.
+    ///
+    /// This may be a comma-separated list, and may contain wildcards such as `image/*`.
+    pub content_type: Option<&'static str>,
+    /// Names of headers which must be present on this part.
+    pub headers: &'static [&'static str],
+}
+
+impl PartEncoding {
+    /// `true` if the given content type is permitted by this encoding.
+    ///
+    /// Parts which do not declare a content type are always permitted.
+    fn permits(&self, content_type: Option<&str>) -> bool {
+        let (Some(permitted), Some(content_type)) = (self.content_type, content_type) else {
+            return true;
+        };
+        let essence = essence(content_type);
+        permitted.split(',').map(essence).any(|pattern| {
+            pattern == "*/*"
+                || pattern.eq_ignore_ascii_case(essence)
+                || pattern
+                    .strip_suffix("/*")
+                    .zip(essence.split_once('/'))
+                    .map(|(pattern_type, (type_, _subtype))| {
+                        pattern_type.eq_ignore_ascii_case(type_)
+                    })
+                    .unwrap_or_default()
+        })
+    }
+}
+
+/// Strip parameters and whitespace from a content type.
+fn essence(content_type: &str) -> &str {
+    content_type.split(';').next().unwrap_or_default().trim()
+}
+
+/// A single buffered part of a `multipart/form-data` request body.
+#[derive(Debug, Clone)]
+pub struct Part {
+    pub name: String,
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub headers: HeaderMap,
+    pub data: Bytes,
+}
+
+impl Part {
+    /// Ensure that a required part was present.
+    pub fn required(name: &str, part: Option<Part>) -> Result<Part, MultipartError> {
+        part.ok_or_else(|| MultipartError::MissingPart(name.to_owned()))
+    }
+
+    /// The raw data of this part.
+    pub fn into_bytes(self) -> Bytes {
+        self.data
+    }
+
+    /// The raw data of this part, copied into a `Vec`.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.data.into()
+    }
+
+    /// Deserialize the data of this part.
+    ///
+    /// Parts with a JSON content type are deserialized as JSON. All other parts must be valid UTF-8;
+    /// their text is interpreted as a JSON value if possible, and as a string otherwise.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, MultipartError> {
+        let deserialize_err = |source| MultipartError::Deserialize {
+            name: self.name.clone(),
+            source,
+        };
+
+        let is_json = self
+            .content_type
+            .as_deref()
+            .map(|content_type| essence(content_type).ends_with("json"))
+            .unwrap_or_default();
+        if is_json {
+            return serde_json::from_slice(&self.data).map_err(deserialize_err);
+        }
+
+        let text = std::str::from_utf8(&self.data).map_err(|source| MultipartError::Utf8 {
+            name: self.name.clone(),
+            source,
+        })?;
+        serde_json::from_str(text)
+            .or_else(|_| serde_json::from_value(serde_json::Value::String(text.to_owned())))
+            .map_err(deserialize_err)
+    }
+}
+
+/// All parts of a `multipart/form-data` request body, by name.
+#[derive(Debug, Clone, Default)]
+pub struct Parts(HashMap<String, VecDeque<Part>>);
+
+impl Parts {
+    /// Buffer all named parts of a multipart request body.
+    ///
+    /// Parts without a name cannot be associated with any property, and are discarded.
+    pub async fn collect(mut multipart: Multipart) -> Result<Self, MultipartError> {
+        let mut parts = HashMap::<_, VecDeque<_>>::new();
+        while let Some(field) = multipart.next_field().await? {
+            let Some(name) = field.name().map(ToOwned::to_owned) else {
+                continue;
+            };
+            let file_name = field.file_name().map(ToOwned::to_owned);
+            let content_type = field.content_type().map(ToOwned::to_owned);
+            let headers = field.headers().clone();
+            let data = field.bytes().await?;
+            parts.entry(name.clone()).or_default().push_back(Part {
+                name,
+                file_name,
+                content_type,
+                headers,
+                data,
+            });
+        }
+        Ok(Self(parts))
+    }
+
+    fn check(part: &Part, encoding: &PartEncoding) -> Result<(), MultipartError> {
+        if !encoding.permits(part.content_type.as_deref()) {
+            return Err(MultipartError::ContentType {
+                name: part.name.clone(),
+                expected: encoding.content_type.unwrap_or_default().to_owned(),
+                found: part.content_type.clone().unwrap_or_default(),
+            });
+        }
+        if let Some(header) = encoding
+            .headers
+            .iter()
+            .find(|header| !part.headers.contains_key(**header))
+        {
+            return Err(MultipartError::MissingHeader {
+                name: part.name.clone(),
+                header: (*header).to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Take the first part with the given name, ensuring it matches its encoding.
+    pub fn take(
+        &mut self,
+        name: &str,
+        encoding: &PartEncoding,
+    ) -> Result<Option<Part>, MultipartError> {
+        let Some(part) = self.0.get_mut(name).and_then(VecDeque::pop_front) else {
+            return Ok(None);
+        };
+        Self::check(&part, encoding)?;
+        Ok(Some(part))
+    }
+
+    /// Take all parts with the given name, ensuring each matches its encoding.
+    pub fn take_all(
+        &mut self,
+        name: &str,
+        encoding: &PartEncoding,
+    ) -> Result<Vec<Part>, MultipartError> {
+        let parts = self.0.remove(name).unwrap_or_default();
+        for part in &parts {
+            Self::check(part, encoding)?;
+        }
+        Ok(parts.into())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MultipartError {
+    #[error(transparent)]
+    Rejection(#[from] MultipartRejection),
+    #[error("failed to read multipart field")]
+    Field(#[from] FieldError),
+    #[error("missing required part \"{0}\"")]
+    MissingPart(String),
+    #[error("part \"{name}\" has content type \"{found}\"; expected \"{expected}\"")]
+    ContentType {
+        name: String,
+        expected: String,
+        found: String,
+    },
+    #[error("part \"{name}\" is missing required header \"{header}\"")]
+    MissingHeader { name: String, header: String },
+    #[error("part \"{name}\" is not valid utf-8")]
+    Utf8 {
+        name: String,
+        #[source]
+        source: std::str::Utf8Error,
+    },
+    #[error("part \"{name}\" could not be deserialized")]
+    Deserialize {
+        name: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl MultipartError {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            MultipartError::Rejection(rejection) => rejection.status(),
+            MultipartError::Field(err) => err.status(),
+            MultipartError::ContentType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            MultipartError::Deserialize { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            MultipartError::MissingPart(_)
+            | MultipartError::MissingHeader { .. }
+            | MultipartError::Utf8 { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for MultipartError {
+    #[cfg(feature = "api-problem")]
+    fn into_response(self) -> Response {
+        super::ApiProblemRejection::from(self).into_response()
+    }
+
+    #[cfg(not(feature = "api-problem"))]
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,115 @@
+//! Runtime support for query parameters.
+//!
+//! Query parameters are extracted via [`Query`], which tracks the parameter being deserialized. When the query
+//! string does not match its type, the [`QueryRejection`] names the parameter which could not be deserialized.
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    response::{IntoResponse, Response},
+};
+use http::{request::Parts, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_path_to_error::Segment;
+
+/// Extractor which deserializes the query string of a request into `T`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for Query<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = QueryRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        decode(parts.uri.query().unwrap_or_default()).map(Self)
+    }
+}
+
+/// Deserialize a query string, tracking the parameter which does not match its type.
+pub fn decode<T>(query: &str) -> Result<T, QueryRejection>
+where
+    T: DeserializeOwned,
+{
+    let deserializer = serde_html_form::Deserializer::new(form_urlencoded::parse(query.as_bytes()));
+    serde_path_to_error::deserialize(deserializer).map_err(QueryRejection::from_path_error)
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to deserialize the query string: {reason}")]
+pub struct QueryRejection {
+    /// The name of the parameter which could not be deserialized, if known.
+    pub name: Option<String>,
+    /// What is wrong with the parameter.
+    pub reason: String,
+}
+
+impl QueryRejection {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn from_path_error(err: serde_path_to_error::Error<serde_html_form::de::Error>) -> Self {
+        let name = err.path().iter().find_map(|segment| match segment {
+            Segment::Map { key } => Some(key.clone()),
+            _ => None,
+        });
+        let reason = err.into_inner().to_string();
+
+        // serde reports missing fields at their object; name the field instead
+        let name = name.or_else(|| {
+            reason
+                .strip_prefix("missing field `")
+                .and_then(|rest| rest.strip_suffix('`'))
+                .map(ToOwned::to_owned)
+        });
+
+        Self { name, reason }
+    }
+}
+
+impl IntoResponse for QueryRejection {
+    #[cfg(feature = "api-problem")]
+    fn into_response(self) -> Response {
+        super::ApiProblemRejection::from(self).into_response()
+    }
+
+    #[cfg(not(feature = "api-problem"))]
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Search {
+        owner: String,
+        limit: Option<u32>,
+    }
+
+    fn rejected_name(query: &str) -> Option<String> {
+        decode::<Search>(query)
+            .expect_err("the query should be rejected")
+            .name
+    }
+
+    #[test]
+    fn rejections_name_the_invalid_parameter() {
+        assert_eq!(
+            rejected_name("owner=alice&limit=ten").as_deref(),
+            Some("limit")
+        );
+        assert_eq!(rejected_name("limit=3").as_deref(), Some("owner"));
+        assert!(decode::<Search>("owner=alice").is_ok());
+    }
+}
@@ -0,0 +1,59 @@
+//! Runtime support for extracting credentials.
+//!
+//! Generated credential types implement `FromRequestParts` by calling the functions in this module.
+//! Credentials which are absent or malformed produce `401 Unauthorized` with a `WWW-Authenticate` challenge;
+//! credentials which satisfy only part of a security requirement produce `403 Forbidden`.
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    response::{IntoResponse, Response},
+};
+use http::{header::WWW_AUTHENTICATE, request::Parts, HeaderValue};
+
+pub use crate::security::{
+    parts::{api_key_cookie, api_key_header, api_key_query, basic, bearer, credentials},
+    SecurityRejection,
+};
+
+/// Credentials of an operation which permits anonymous access.
+///
+/// Unlike `Option<T>`, this extracts `None` only when the credentials are absent. Credentials which are present
+/// but invalid are still rejected.
+#[derive(Debug, Clone)]
+pub struct Optional<T>(pub Option<T>);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for Optional<T>
+where
+    T: FromRequestParts<S, Rejection = SecurityRejection>,
+    S: Send + Sync,
+{
+    type Rejection = SecurityRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match T::from_request_parts(parts, state).await {
+            Ok(credentials) => Ok(Self(Some(credentials))),
+            Err(SecurityRejection::Missing { .. }) => Ok(Self(None)),
+            Err(rejection) => Err(rejection),
+        }
+    }
+}
+
+impl IntoResponse for SecurityRejection {
+    fn into_response(self) -> Response {
+        let challenge = self.challenge();
+
+        #[cfg(feature = "api-problem")]
+        let mut response = super::ApiProblemRejection::from(self).into_response();
+        #[cfg(not(feature = "api-problem"))]
+        let mut response = (self.status(), self.to_string()).into_response();
+
+        if let Some(challenge) =
+            challenge.and_then(|challenge| HeaderValue::try_from(challenge).ok())
+        {
+            response.headers_mut().insert(WWW_AUTHENTICATE, challenge);
+        }
+        response
+    }
+}
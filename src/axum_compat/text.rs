@@ -0,0 +1,97 @@
+//! Runtime support for `text/*` request and response bodies.
+//!
+//! Text bodies with scalar schemas are extracted via [`Text`], and responded via [`TextResponse`].
+//! Both go through the [`CanonicalForm`] of the body type.
+
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{rejection::BytesRejection, FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use http::{header::CONTENT_TYPE, StatusCode};
+
+use crate::{
+    text::{FromText, TextError},
+    CanonicalForm,
+};
+
+/// Extractor which decodes a `text/*` request body into `T` via its canonical form.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Text<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for Text<T>
+where
+    S: Send + Sync,
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    type Rejection = TextRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned);
+        let data = Bytes::from_request(req, state).await?;
+        crate::text::decode(content_type.as_deref(), &data)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+/// A `text/*` response body, encoded via the canonical form of `T`.
+#[derive(Debug, Clone)]
+pub struct TextResponse<T> {
+    /// Content type of the response, as declared by the specification.
+    pub content_type: &'static str,
+    pub value: T,
+}
+
+impl<T> IntoResponse for TextResponse<T>
+where
+    T: CanonicalForm,
+{
+    fn into_response(self) -> Response {
+        match crate::text::encode(self.content_type, &self.value) {
+            Ok((content_type, data)) => ([(CONTENT_TYPE, content_type)], data).into_response(),
+            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TextRejection {
+    #[error(transparent)]
+    Bytes(#[from] BytesRejection),
+    #[error(transparent)]
+    Text(#[from] TextError),
+}
+
+impl TextRejection {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            TextRejection::Bytes(rejection) => rejection.status(),
+            TextRejection::Text(TextError::UnsupportedCharset(_)) => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            TextRejection::Text(TextError::Validate(_)) => StatusCode::UNPROCESSABLE_ENTITY,
+            TextRejection::Text(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for TextRejection {
+    #[cfg(feature = "api-problem")]
+    fn into_response(self) -> Response {
+        super::ApiProblemRejection::from(self).into_response()
+    }
+
+    #[cfg(not(feature = "api-problem"))]
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
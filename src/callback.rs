@@ -0,0 +1,224 @@
+//! Resolution of callback URLs from runtime expressions.
+//!
+//! A callback is sent to a URL computed from the request which registered it. The URL is a template in which
+//! runtime expressions are enclosed in curly braces:
+//!
+//! ```text
+//! https://example.com/notify?id={$request.body#/id}&from={$request.header.x-origin}
+//! ```
+//!
+//! Generated code exposes the template of each callback as a constant; [`resolve_url`] evaluates it.
+
+use http::{HeaderMap, Method, Uri};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Characters which are percent-encoded in values substituted into the path or query of a URL: all but unreserved ones.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// The request which registered a callback, against which its URL expression is resolved.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestContext<'a> {
+    pub method: &'a Method,
+    pub uri: &'a Uri,
+    pub headers: &'a HeaderMap,
+    /// Path parameters of the request, by name.
+    pub path_parameters: &'a [(&'a str, &'a str)],
+    /// Body of the request, if it had a JSON body.
+    pub body: Option<&'a serde_json::Value>,
+}
+
+impl<'a> RequestContext<'a> {
+    /// Create a context for a request without path parameters or a body.
+    pub fn new(method: &'a Method, uri: &'a Uri, headers: &'a HeaderMap) -> Self {
+        Self {
+            method,
+            uri,
+            headers,
+            path_parameters: &[],
+            body: None,
+        }
+    }
+
+    /// Set the path parameters of this request.
+    pub fn with_path_parameters(mut self, path_parameters: &'a [(&'a str, &'a str)]) -> Self {
+        self.path_parameters = path_parameters;
+        self
+    }
+
+    /// Set the JSON body of this request.
+    pub fn with_body(mut self, body: &'a serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Evaluate a single runtime expression, such as `$request.body#/callbackUrl`.
+    pub fn evaluate(&self, expression: &str) -> Result<String, ExpressionError> {
+        let missing = || ExpressionError::Missing(expression.to_owned());
+
+        match expression {
+            "$url" => return Ok(self.uri.to_string()),
+            "$method" => return Ok(self.method.to_string()),
+            _ => {}
+        }
+
+        let source = expression
+            .strip_prefix("$request.")
+            .ok_or_else(|| ExpressionError::Unsupported(expression.to_owned()))?;
+
+        if let Some(name) = source.strip_prefix("header.") {
+            return self
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(ToOwned::to_owned)
+                .ok_or_else(missing);
+        }
+
+        if let Some(name) = source.strip_prefix("query.") {
+            let query = self.uri.query().unwrap_or_default();
+            return form_urlencoded::parse(query.as_bytes())
+                .find(|(key, _value)| key == name)
+                .map(|(_key, value)| value.into_owned())
+                .ok_or_else(missing);
+        }
+
+        if let Some(name) = source.strip_prefix("path.") {
+            return self
+                .path_parameters
+                .iter()
+                .find(|(key, _value)| *key == name)
+                .map(|(_key, value)| (*value).to_owned())
+                .ok_or_else(missing);
+        }
+
+        if let Some(pointer) = source.strip_prefix("body") {
+            let pointer = match pointer {
+                "" => "",
+                _ => pointer
+                    .strip_prefix('#')
+                    .ok_or_else(|| ExpressionError::Unsupported(expression.to_owned()))?,
+            };
+            let value = self.body.and_then(|body| body.pointer(pointer));
+            return match value {
+                Some(serde_json::Value::String(value)) => Ok(value.clone()),
+                Some(value) => Ok(value.to_string()),
+                None => Err(missing()),
+            };
+        }
+
+        Err(ExpressionError::Unsupported(expression.to_owned()))
+    }
+}
+
+/// `true` when text appended to `url` falls within its path or query, rather than its scheme or authority.
+fn in_path_or_query(url: &str) -> bool {
+    match url.split_once("://") {
+        Some((_scheme, rest)) => rest.contains(['/', '?']),
+        None => !url.is_empty(),
+    }
+}
+
+/// Resolve a callback URL template against the request which registered the callback.
+///
+/// Each runtime expression in the template, enclosed in curly braces, is replaced by its value. Values within the
+/// path or query of the URL are percent-encoded; values which supply the start of the URL, such as a callback URL
+/// registered by the consumer, are substituted as they are.
+pub fn resolve_url(template: &str, context: &RequestContext) -> Result<String, ExpressionError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| ExpressionError::Unterminated(template.to_owned()))?;
+        let value = context.evaluate(&rest[start + 1..start + end])?;
+        if in_path_or_query(&out) {
+            out.extend(utf8_percent_encode(&value, COMPONENT));
+        } else {
+            out.push_str(&value);
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExpressionError {
+    #[error("unterminated runtime expression in \"{0}\"")]
+    Unterminated(String),
+    #[error("unsupported runtime expression \"{0}\"")]
+    Unsupported(String),
+    #[error("runtime expression \"{0}\" has no value for this request")]
+    Missing(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_body_pointer() {
+        let method = Method::POST;
+        let uri = Uri::from_static("https://example.com/subscribe?topic=news");
+        let headers = HeaderMap::new();
+        let body = serde_json::json!({ "callbackUrl": "https://consumer.example/hook", "id": 7 });
+        let context = RequestContext::new(&method, &uri, &headers).with_body(&body);
+
+        assert_eq!(
+            resolve_url("{$request.body#/callbackUrl}", &context).unwrap(),
+            "https://consumer.example/hook",
+        );
+        assert_eq!(
+            resolve_url(
+                "https://consumer.example/{$request.query.topic}?id={$request.body#/id}",
+                &context
+            )
+            .unwrap(),
+            "https://consumer.example/news?id=7",
+        );
+    }
+
+    #[test]
+    fn percent_encodes_values_within_the_path_and_query() {
+        let method = Method::POST;
+        let uri = Uri::from_static("https://example.com/subscribe?topic=a%20b%26c");
+        let headers = HeaderMap::new();
+        let body = serde_json::json!({ "callbackUrl": "https://consumer.example/hook?key=1", "host": "consumer.example:8443" });
+        let context = RequestContext::new(&method, &uri, &headers).with_body(&body);
+
+        assert_eq!(
+            resolve_url(
+                "{$request.body#/callbackUrl}&topic={$request.query.topic}",
+                &context
+            )
+            .unwrap(),
+            "https://consumer.example/hook?key=1&topic=a%20b%26c",
+        );
+        assert_eq!(
+            resolve_url(
+                "https://{$request.body#/host}/topics/{$request.query.topic}",
+                &context
+            )
+            .unwrap(),
+            "https://consumer.example:8443/topics/a%20b%26c",
+        );
+    }
+
+    #[test]
+    fn unterminated_expression() {
+        let method = Method::POST;
+        let uri = Uri::from_static("https://example.com/subscribe");
+        let headers = HeaderMap::new();
+        let context = RequestContext::new(&method, &uri, &headers);
+
+        assert!(matches!(
+            resolve_url("{$request.body#/callbackUrl", &context),
+            Err(ExpressionError::Unterminated(_))
+        ));
+    }
+}
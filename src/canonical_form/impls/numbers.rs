@@ -59,3 +59,34 @@ impl CanonicalForm for i32 {
         i64::from(*self).canonicalize()
     }
 }
+
+impl CanonicalForm for u64 {
+    type ParseableFrom = Number;
+    type JsonRepresentation = Number;
+
+    fn validate(from: &Self::ParseableFrom) -> Result<Self, ValidationError> {
+        from.as_u64().ok_or_else(|| {
+            ValidationError::reason::<Self>(format!("number not unsigned integer: {from}"))
+        })
+    }
+
+    fn canonicalize(&self) -> Result<Self::JsonRepresentation, CanonicalizeError> {
+        Ok((*self).into())
+    }
+}
+
+impl CanonicalForm for u32 {
+    type ParseableFrom = Number;
+    type JsonRepresentation = Number;
+
+    fn validate(from: &Self::ParseableFrom) -> Result<Self, ValidationError> {
+        u64::validate(from).and_then(|n| {
+            n.try_into()
+                .map_err(|err| ValidationError::reason::<Self>(Reason::from_err(err)))
+        })
+    }
+
+    fn canonicalize(&self) -> Result<Self::JsonRepresentation, CanonicalizeError> {
+        u64::from(*self).canonicalize()
+    }
+}
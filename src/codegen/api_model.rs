@@ -15,18 +15,36 @@ use quote::quote;
 const OPENAPI_GEN_VERSION: &str = env!("CARGO_PKG_VERSION");
 const OPENAPI_GEN_GIT_SHA: &str = env!("VERGEN_GIT_SHA");
 
+#[cfg(feature = "actix-support")]
+use crate::actix_compat;
 #[cfg(feature = "axum-support")]
 use crate::axum_compat;
+#[cfg(feature = "reqwest-support")]
+use crate::reqwest_compat;
+#[cfg(feature = "tower-support")]
+use crate::tower_compat;
 
 use crate::{
     codegen::{
         endpoint::{
-            self, insert_endpoints, parameter::insert_parameter, request_body::create_request_body,
+            self,
+            callback::{insert_callbacks, Callback},
+            insert_endpoints,
+            link::{self, emit_links},
+            parameter::insert_parameter,
+            request_body::{body_object, create_request_body, impl_form_encoding},
             response::create_response_variants,
+            security::{emit_security, insert_security_schemes, SecurityScheme},
+            tag::{insert_tags, Tag},
         },
+        example::{self, emit_example_api},
         item::{EmitError, ParseItemError},
         make_ident,
+        mock::emit_mock,
+        options::Options,
         rust_keywords::is_rust_keyword,
+        server::{self, emit_servers, insert_servers, Server},
+        validation::emit_validate_impls,
         Endpoint, Item, Scalar,
     },
     fix_block_comments::fix_block_comments_to_string,
@@ -92,8 +110,10 @@ impl AsBackref for Ref {
 pub struct ApiModel<Ref = Reference> {
     /// Input file data
     input_file: Option<PathBuf>,
+    /// Code generation options.
+    pub(crate) options: Options,
     /// Item definitions.
-    definitions: Vec<Item<Ref>>,
+    pub(crate) definitions: Vec<Item<Ref>>,
     /// Map from the outer item identifier to the item.
     ///
     /// Keys here are just the identifier name: `Foo`.
@@ -105,6 +125,16 @@ pub struct ApiModel<Ref = Reference> {
     named_references: HashMap<String, usize>,
     /// Api endpoints. These will be used later to generate `trait Api`.
     pub(crate) endpoints: Vec<Endpoint<Ref>>,
+    /// Callbacks and webhooks. These will be used later to generate `trait Callbacks`.
+    pub(crate) callbacks: Vec<Callback<Ref>>,
+    /// Security schemes by name.
+    ///
+    /// Keys here are the bare scheme name, as used in security requirements: `bearerAuth`.
+    pub(crate) security_schemes: IndexMap<String, SecurityScheme>,
+    /// Servers which host the API, in order of declaration.
+    pub(crate) servers: Vec<Server>,
+    /// Operation tags, in order of declaration.
+    pub(crate) tags: Vec<Tag>,
     /// Response variants by reference name.
     ///
     /// This is used to minimize duplication when merging variants from predefined response objects.
@@ -118,10 +148,15 @@ impl<R> Default for ApiModel<R> {
     fn default() -> Self {
         Self {
             input_file: Default::default(),
+            options: Default::default(),
             definitions: Default::default(),
             items: Default::default(),
             named_references: Default::default(),
             endpoints: Default::default(),
+            callbacks: Default::default(),
+            security_schemes: Default::default(),
+            servers: Default::default(),
+            tags: Default::default(),
             response_variants: Default::default(),
         }
     }
@@ -379,10 +414,15 @@ impl ApiModel<Ref> {
     pub fn resolve_refs(self) -> Result<ApiModel<Reference>, Error> {
         let Self {
             input_file,
+            options,
             definitions,
             items,
             named_references,
             endpoints,
+            callbacks,
+            security_schemes,
+            servers,
+            tags,
             response_variants,
         } = self;
 
@@ -405,6 +445,11 @@ impl ApiModel<Ref> {
             .map(|endpoint| endpoint.resolve_refs(resolver))
             .collect::<Result<_, _>>()?;
 
+        let callbacks = callbacks
+            .into_iter()
+            .map(|callback| callback.resolve_refs(resolver))
+            .collect::<Result<_, _>>()?;
+
         let response_variants = response_variants
             .into_iter()
             .map(|(name, variants)| -> Result<_, UnknownReference> {
@@ -418,16 +463,31 @@ impl ApiModel<Ref> {
 
         Ok(ApiModel {
             input_file,
+            options,
             definitions,
             items,
             named_references,
             endpoints,
+            callbacks,
+            security_schemes,
+            servers,
+            tags,
             response_variants,
         })
     }
 }
 
 impl ApiModel {
+    /// Code generation options.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Override the code generation options which were read from the specification.
+    pub fn options_mut(&mut self) -> &mut Options {
+        &mut self.options
+    }
+
     /// Emit the definition for an item by reference.
     ///
     /// If the item is trivial, emits the definition dirctly. Otherwise, emits the appropriate resolved name.
@@ -447,6 +507,34 @@ impl ApiModel {
         }
     }
 
+    /// The attribute which boxes the futures of async trait methods, unless they are emitted natively.
+    ///
+    /// This applies both to the generated traits and to their generated implementations.
+    pub(crate) fn async_trait_attribute(&self) -> Option<TokenStream> {
+        (!self.options.native_async)
+            .then(|| quote!(#[openapi_gen::reexport::async_trait::async_trait]))
+    }
+
+    /// Emit the signature of an async trait method.
+    ///
+    /// When emitting native async methods, this is a plain method which returns `impl Future + Send`.
+    pub(crate) fn async_method_signature(
+        &self,
+        name: &syn::Ident,
+        parameters: TokenStream,
+        output: TokenStream,
+    ) -> TokenStream {
+        if self.options.native_async {
+            quote! {
+                fn #name(#parameters) -> impl ::std::future::Future<Output = #output> + Send
+            }
+        } else {
+            quote! {
+                async fn #name(#parameters) -> #output
+            }
+        }
+    }
+
     /// Emit a footer to the module header with data about the input file
     fn emit_header_footer(&self) -> Option<String> {
         use md5::{Digest, Md5};
@@ -476,7 +564,7 @@ impl ApiModel {
     /// Emit the module header.
     ///
     /// One can choose to skip omitting the module documentation. This is most useful in a testing context.
-    fn emit_header(&self, emit_docs: bool) -> TokenStream {
+    pub(crate) fn emit_header(&self, emit_docs: bool) -> TokenStream {
         let mut out = quote!(#![allow(non_camel_case_types)]);
         if emit_docs {
             let timestamp = time::OffsetDateTime::now_utc()
@@ -513,11 +601,8 @@ Your changes may be overwritten without notice.
         out
     }
 
-    /// Emit the items of this model as a token stream.
-    ///
-    /// This is largely for future-proofing, so we can embed this more easily in
-    /// a proc macro in the future if we so desire.
-    pub fn emit_items_to_token_stream(&self, emit_docs: bool) -> Result<TokenStream, Error> {
+    /// Emit the items of this model, grouped by the part of the API to which they belong.
+    pub(crate) fn emit_sections(&self) -> Result<Sections, Error> {
         let names = self
             .items
             .iter()
@@ -530,53 +615,267 @@ Your changes may be overwritten without notice.
                 .ok_or_else(|| UnknownReference(format!("{ref_:?}")))
         };
 
-        let header = self.emit_header(emit_docs);
-
         let items = self
             .definitions
             .iter()
             .map(|item| item.emit(self, &name_resolver))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let endpoints = self
-            .endpoints
-            .iter()
-            .map(|endpoint| endpoint.emit(self, &name_resolver))
+        let servers = emit_servers(self);
+
+        let security = emit_security(self);
+
+        let context = self.options.context.then(|| {
+            quote! {
+                /// Request context, which is passed as the first argument of every method.
+                ///
+                /// The generated router extracts it from each request, so it must implement the extractor trait
+                /// of the framework: `FromRequestParts<()>` for axum, or `FromRequest` for actix-web.
+                type Context: Send + 'static;
+            }
+        });
+
+        let error = self.options.is_fallible().then(|| {
+            quote! {
+                /// The error with which methods fail.
+                ///
+                /// The generated router responds with the error in place of a response, so it must implement the
+                /// response trait of the framework: `IntoResponse` for axum, `IntoHttpResponse` for tower, or
+                /// `Into<actix_web::Error>` for actix-web.
+                type Error: Send + 'static;
+            }
+        });
+
+        let async_trait = self.async_trait_attribute();
+
+        let trait_api = self
+            .api_traits()
+            .into_iter()
+            .map(|api_trait| -> Result<_, Error> {
+                let docs = api_trait.docs.map(|docs| quote!(#[doc = #docs]));
+                let name = make_ident(api_trait.name);
+                let endpoints = api_trait
+                    .endpoints
+                    .iter()
+                    .map(|endpoint| endpoint.emit(self, &name_resolver))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(quote! {
+                    #docs
+                    #async_trait
+                    pub trait #name {
+                        #context
+                        #error
+
+                        #(
+                            #endpoints
+                        )*
+                    }
+                })
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let trait_api = quote! {
-            #[openapi_gen::reexport::async_trait::async_trait]
-            pub trait Api {
-                #(
-                    #endpoints
-                )*
+        let trait_callbacks = (!self.callbacks.is_empty())
+            .then(|| -> Result<_, Error> {
+                let url_expressions = self
+                    .callbacks
+                    .iter()
+                    .filter_map(|callback| callback.emit_url_expression());
+                let callbacks = self
+                    .callbacks
+                    .iter()
+                    .map(|callback| callback.emit(self, &name_resolver))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(quote! {
+                    #( #url_expressions )*
+
+                    /// Requests which this API sends to its consumers.
+                    #async_trait
+                    pub trait Callbacks {
+                        /// The error produced when a callback cannot be delivered.
+                        type Error;
+
+                        #(
+                            #callbacks
+                        )*
+                    }
+                })
+            })
+            .transpose()?;
+
+        let links = emit_links(self, &name_resolver)?;
+
+        let mut form_encodings = Vec::new();
+        let mut form_objects = Vec::new();
+        for request_body in self
+            .endpoints
+            .iter()
+            .chain(self.callbacks.iter().map(|callback| &callback.endpoint))
+            .filter_map(|endpoint| endpoint.request_body)
+        {
+            let item = &self[request_body];
+            if !item.is_form_urlencoded() {
+                continue;
             }
-        };
+            let Some((object_ref, object_name, object)) = body_object(self, request_body)? else {
+                continue;
+            };
+            // an object is encoded one way, whichever request bodies share it
+            let form_encoding = impl_form_encoding(self, item, object_name, object)?;
+            let encoding = form_encoding.to_string();
+            match form_objects
+                .iter()
+                .find(|(existing_ref, _)| *existing_ref == object_ref)
+            {
+                Some((_, existing)) if *existing == encoding => continue,
+                Some(_) => return Err(Error::ConflictingFormEncoding(object_name.to_owned())),
+                None => {}
+            }
+            form_objects.push((object_ref, encoding));
+            form_encodings.push(form_encoding);
+        }
 
         #[cfg(not(feature = "axum-support"))]
         let axum = TokenStream::default();
         #[cfg(feature = "axum-support")]
         let axum = axum_compat::axum_items(self, &name_resolver)?;
 
+        #[cfg(not(feature = "actix-support"))]
+        let actix = TokenStream::default();
+        #[cfg(feature = "actix-support")]
+        let actix = actix_compat::actix_items(self, &name_resolver)?;
+
+        #[cfg(not(feature = "tower-support"))]
+        let tower = TokenStream::default();
+        #[cfg(feature = "tower-support")]
+        let tower = tower_compat::tower_items(self, &name_resolver)?;
+
+        #[cfg(not(feature = "reqwest-support"))]
+        let reqwest = TokenStream::default();
+        #[cfg(feature = "reqwest-support")]
+        let reqwest = reqwest_compat::reqwest_items(self, &name_resolver)?;
+
+        let mock = emit_mock(self, &name_resolver)?;
+        let examples = emit_example_api(self, &name_resolver)?;
+        let validation = emit_validate_impls(self)?;
+
+        Ok(Sections {
+            items,
+            api: quote! {
+                #servers
+                #security
+                #( #trait_api )*
+                #trait_callbacks
+                #links
+                #( #form_encodings )*
+            },
+            validation,
+            axum,
+            actix,
+            tower,
+            reqwest,
+            mock,
+            examples,
+        })
+    }
+
+    /// Emit the items of this model as a token stream.
+    ///
+    /// This is largely for future-proofing, so we can embed this more easily in
+    /// a proc macro in the future if we so desire.
+    pub fn emit_items_to_token_stream(&self, emit_docs: bool) -> Result<TokenStream, Error> {
+        let header = self.emit_header(emit_docs);
+        let Sections {
+            items,
+            api,
+            validation,
+            axum,
+            actix,
+            tower,
+            reqwest,
+            mock,
+            examples,
+        } = self.emit_sections()?;
+
         Ok(quote! {
             #header
             #( #items )*
-            #trait_api
+            #api
+            #validation
             #axum
+            #actix
+            #tower
+            #reqwest
+            #mock
+            #examples
         })
     }
 
     /// Emit the items defined by this model as Rust code.
     pub fn emit_items(&self, emit_docs: bool) -> Result<String, Error> {
         let tokens = self.emit_items_to_token_stream(emit_docs)?;
-        let buffer = tokens.to_string();
-        let file = syn::parse_str::<syn::File>(&buffer)
-            .map_err(|err| Error::CodegenParse { err, buffer })?;
-        let pretty = prettyplease::unparse(&file);
-        let fixed =
-            fix_block_comments_to_string(&pretty).map_err(Error::fix_block_comments(&pretty))?;
-        Ok(fixed)
+        let file = parse_file(tokens)?;
+        pretty_print(&file)
     }
+
+    /// Emit the items defined by this model as a token stream which can be expanded in item position.
+    ///
+    /// Neither `include!` nor macros accept inner attributes, so the attributes of the module header are applied to
+    /// each item instead, and the module documentation is omitted.
+    pub fn emit_includable_items_to_token_stream(&self) -> Result<TokenStream, Error> {
+        let tokens = self.emit_items_to_token_stream(false)?;
+        let file = parse_file(tokens)?;
+        let attributes = file
+            .attrs
+            .into_iter()
+            .map(|attribute| attribute.meta)
+            .collect::<Vec<_>>();
+        Ok(file
+            .items
+            .into_iter()
+            .map(|item| quote!(#( #[#attributes] )* #item))
+            .collect())
+    }
+
+    /// Emit the items defined by this model as Rust code which can be embedded into a module with `include!`.
+    pub fn emit_includable_items(&self) -> Result<String, Error> {
+        let tokens = self.emit_includable_items_to_token_stream()?;
+        let file = parse_file(tokens)?;
+        pretty_print(&file)
+    }
+}
+
+/// Generated code, grouped by the part of the API to which it belongs.
+pub(crate) struct Sections {
+    /// Item definitions, in order of definition.
+    pub items: Vec<TokenStream>,
+    /// Servers, security, traits, links, and encodings.
+    pub api: TokenStream,
+    /// `impl Validate` for the types of requests.
+    pub validation: TokenStream,
+    /// Items which depend on feature `axum-support`.
+    pub axum: TokenStream,
+    /// Items which depend on feature `actix-support`.
+    pub actix: TokenStream,
+    /// Items which depend on feature `tower-support`.
+    pub tower: TokenStream,
+    /// Items which depend on feature `reqwest-support`.
+    pub reqwest: TokenStream,
+    /// The mock implementation of the API traits.
+    pub mock: TokenStream,
+    /// The implementation of the API traits which responds with examples.
+    pub examples: TokenStream,
+}
+
+/// Parse generated code as a Rust file.
+pub(crate) fn parse_file(tokens: TokenStream) -> Result<syn::File, Error> {
+    let buffer = tokens.to_string();
+    syn::parse_str::<syn::File>(&buffer).map_err(|err| Error::CodegenParse { err, buffer })
+}
+
+/// Format a Rust file of generated code.
+pub(crate) fn pretty_print(file: &syn::File) -> Result<String, Error> {
+    let pretty = prettyplease::unparse(file);
+    fix_block_comments_to_string(&pretty).map_err(Error::fix_block_comments(&pretty))
 }
 
 impl std::ops::Index<Reference> for ApiModel<Reference> {
@@ -612,8 +911,10 @@ where
 impl ApiModel {
     pub fn new(spec: &OpenAPI, input_file: Option<impl AsRef<Path>>) -> Result<Self, Error> {
         let input_file = input_file.map(|path_ref| path_ref.as_ref().to_owned());
+        let options = Options::from_spec(spec).map_err(Error::ParseOptions)?;
         let mut model = ApiModel::<Ref> {
             input_file,
+            options,
             ..Default::default()
         };
 
@@ -690,7 +991,11 @@ impl ApiModel {
             }
         }
 
+        insert_servers(spec, &mut model)?;
+        insert_security_schemes(spec, &mut model)?;
         insert_endpoints(spec, &mut model)?;
+        insert_tags(spec, &mut model);
+        insert_callbacks(spec, &mut model)?;
 
         model.resolve_refs()
     }
@@ -704,6 +1009,8 @@ pub struct UnknownReference(pub String);
 pub enum Error {
     #[error(transparent)]
     UnknownReference(#[from] UnknownReference),
+    #[error("parsing code generation options")]
+    ParseOptions(#[source] serde_json::Error),
     #[error("parsing item schema")]
     ParseItem(#[from] ParseItemError),
     #[error("resolving path operation")]
@@ -728,9 +1035,26 @@ pub enum Error {
     },
     #[error("inserting component headers")]
     InsertHeader(#[from] header::Error),
+    #[error("emitting response link")]
+    EmitLink(#[from] link::Error),
+    #[error("inserting server")]
+    InsertServer(#[from] server::Error),
+    #[error("emitting example api")]
+    EmitExamples(#[from] example::Error),
+    #[error("object \"{0}\" is the body of form requests with different encodings")]
+    ConflictingFormEncoding(String),
     #[cfg(feature = "axum-support")]
     #[error("implementing axum compatibility")]
     AxumCompat(#[from] axum_compat::Error),
+    #[cfg(feature = "actix-support")]
+    #[error("implementing actix compatibility")]
+    ActixCompat(#[from] actix_compat::Error),
+    #[cfg(feature = "tower-support")]
+    #[error("implementing tower compatibility")]
+    TowerCompat(#[from] tower_compat::Error),
+    #[cfg(feature = "reqwest-support")]
+    #[error("implementing reqwest compatibility")]
+    ReqwestCompat(#[from] reqwest_compat::Error),
     #[error("fixing block comments")]
     FixBlockComments {
         #[source]
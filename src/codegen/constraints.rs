@@ -0,0 +1,216 @@
+//! Constraints of a schema which are not encoded in its Rust type.
+
+use openapiv3::{SchemaKind, Type, VariantOrUnknownOrEmpty};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::codegen::{item::ParseItemError, Scalar, Value};
+
+/// Constraints of a schema which are not encoded in its Rust type.
+///
+/// These are only checked when the `validate` option is set.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Constraints {
+    /// `minLength` of a string.
+    pub min_length: Option<usize>,
+    /// `maxLength` of a string.
+    pub max_length: Option<usize>,
+    /// `pattern` of a string.
+    pub pattern: Option<String>,
+    /// `format` of a string which is represented as a `String` but can still be checked.
+    pub format: Option<String>,
+    /// `minimum` of a number, and whether it is exclusive.
+    pub minimum: Option<(f64, bool)>,
+    /// `maximum` of a number, and whether it is exclusive.
+    pub maximum: Option<(f64, bool)>,
+    /// `multipleOf` of a number.
+    pub multiple_of: Option<f64>,
+    /// `enum` of an integer.
+    pub integer_enum: Vec<i64>,
+    /// `enum` of a number.
+    pub number_enum: Vec<f64>,
+    /// `minItems` of an array.
+    pub min_items: Option<usize>,
+    /// `maxItems` of an array.
+    pub max_items: Option<usize>,
+}
+
+fn emit_option(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(value) => {
+            let value = Literal::usize_unsuffixed(value);
+            quote!(Some(#value))
+        }
+        None => quote!(None),
+    }
+}
+
+impl Constraints {
+    /// Collect the constraints of a schema.
+    ///
+    /// With feature `string-pattern`, a `pattern` which is not a valid regular expression is an error.
+    pub(crate) fn from_schema_kind(schema_kind: &SchemaKind) -> Result<Self, ParseItemError> {
+        let constraints = match schema_kind {
+            SchemaKind::Type(Type::String(string_type)) => Self {
+                min_length: string_type.min_length,
+                max_length: string_type.max_length,
+                pattern: string_type.pattern.clone(),
+                format: match &string_type.format {
+                    VariantOrUnknownOrEmpty::Unknown(format) => crate::validation::FORMATS
+                        .contains(&format.as_str())
+                        .then(|| format.clone()),
+                    _ => None,
+                },
+                ..Default::default()
+            },
+            SchemaKind::Type(Type::Number(number_type)) => Self {
+                minimum: number_type
+                    .minimum
+                    .map(|minimum| (minimum, number_type.exclusive_minimum)),
+                maximum: number_type
+                    .maximum
+                    .map(|maximum| (maximum, number_type.exclusive_maximum)),
+                multiple_of: number_type.multiple_of,
+                number_enum: number_type
+                    .enumeration
+                    .iter()
+                    .filter_map(|value| Option::<f64>::from(*value))
+                    .collect(),
+                ..Default::default()
+            },
+            SchemaKind::Type(Type::Integer(integer_type)) => Self {
+                minimum: integer_type
+                    .minimum
+                    .map(|minimum| (minimum as f64, integer_type.exclusive_minimum)),
+                maximum: integer_type
+                    .maximum
+                    .map(|maximum| (maximum as f64, integer_type.exclusive_maximum)),
+                multiple_of: integer_type.multiple_of.map(|divisor| divisor as f64),
+                integer_enum: integer_type
+                    .enumeration
+                    .iter()
+                    .filter_map(|value| Option::<i64>::from(*value))
+                    .collect(),
+                ..Default::default()
+            },
+            SchemaKind::Type(Type::Array(array_type)) => Self {
+                min_items: array_type.min_items,
+                max_items: array_type.max_items,
+                ..Default::default()
+            },
+            _ => Self::default(),
+        };
+
+        #[cfg(feature = "string-pattern")]
+        if let Some(pattern) = &constraints.pattern {
+            regress::Regex::new(pattern).map_err(|source| ParseItemError::Pattern {
+                pattern: pattern.clone(),
+                source,
+            })?;
+        }
+
+        Ok(constraints)
+    }
+
+    /// Emit statements which check these constraints against `value`, a reference to a value of `value_kind`.
+    ///
+    /// Only the constraints which apply to the kind of value are checked. Integers whose range is encoded in
+    /// their type are not checked again. The statements record violations in `violations`.
+    pub(crate) fn emit_checks(&self, value_kind: &Value, value: &TokenStream) -> TokenStream {
+        let prefix = quote!(openapi_gen::validation);
+        let mut checks = Vec::new();
+
+        match value_kind {
+            Value::Scalar(Scalar::String) => {
+                if self.min_length.is_some() || self.max_length.is_some() {
+                    let min = emit_option(self.min_length);
+                    let max = emit_option(self.max_length);
+                    checks.push(quote!(#prefix::length(#value, #min, #max, violations);));
+                }
+                #[cfg(feature = "string-pattern")]
+                if let Some(pattern) = &self.pattern {
+                    checks.push(quote! {
+                        {
+                            static PATTERN: #prefix::Pattern = #prefix::Pattern::new(#pattern);
+                            #prefix::pattern(#value, &PATTERN, violations);
+                        }
+                    });
+                }
+                if let Some(format) = &self.format {
+                    checks.push(quote!(#prefix::format(#value, #format, violations);));
+                }
+            }
+            Value::Scalar(
+                Scalar::F64 | Scalar::F32 | Scalar::I64 | Scalar::I32 | Scalar::U64 | Scalar::U32,
+            ) => {
+                let number = if matches!(value_kind, Value::Scalar(Scalar::F64)) {
+                    quote!(*#value)
+                } else {
+                    quote!(*#value as f64)
+                };
+                // unsigned integers encode an inclusive minimum of 0 in their type
+                let unsigned = matches!(value_kind, Value::Scalar(Scalar::U64 | Scalar::U32));
+                if let Some((minimum, exclusive)) = self
+                    .minimum
+                    .filter(|&(minimum, exclusive)| !(unsigned && minimum == 0.0 && !exclusive))
+                {
+                    let minimum = Literal::f64_unsuffixed(minimum);
+                    checks
+                        .push(quote!(#prefix::minimum(#number, #minimum, #exclusive, violations);));
+                }
+                if let Some((maximum, exclusive)) = self.maximum {
+                    let maximum = Literal::f64_unsuffixed(maximum);
+                    checks
+                        .push(quote!(#prefix::maximum(#number, #maximum, #exclusive, violations);));
+                }
+                if let Some(divisor) = self.multiple_of {
+                    let divisor = Literal::f64_unsuffixed(divisor);
+                    checks.push(quote!(#prefix::multiple_of(#number, #divisor, violations);));
+                }
+                if let Some(allowed) = self.emit_enum(value_kind) {
+                    checks.push(quote!(#prefix::one_of(#value, &[#( #allowed ),*], violations);));
+                }
+            }
+            Value::List(_) | Value::Set(_) => {
+                if self.min_items.is_some() || self.max_items.is_some() {
+                    let min = emit_option(self.min_items);
+                    let max = emit_option(self.max_items);
+                    checks.push(quote!(#prefix::items(#value.len(), #min, #max, violations);));
+                }
+            }
+            _ => {}
+        }
+
+        quote!(#( #checks )*)
+    }
+
+    /// Emit the members of `enum` as unsuffixed literals of the type of `value_kind`.
+    ///
+    /// Members which cannot be represented by that type are dropped, as no value could equal them.
+    fn emit_enum(&self, value_kind: &Value) -> Option<Vec<Literal>> {
+        let integers = self.integer_enum.iter().copied();
+        let allowed = match value_kind {
+            Value::Scalar(Scalar::F64 | Scalar::F32) => self
+                .number_enum
+                .iter()
+                .map(|&value| Literal::f64_unsuffixed(value))
+                .collect::<Vec<_>>(),
+            Value::Scalar(Scalar::I64) => integers.map(Literal::i64_unsuffixed).collect(),
+            Value::Scalar(Scalar::I32) => integers
+                .filter_map(|value| i32::try_from(value).ok())
+                .map(Literal::i32_unsuffixed)
+                .collect(),
+            Value::Scalar(Scalar::U64) => integers
+                .filter_map(|value| u64::try_from(value).ok())
+                .map(Literal::u64_unsuffixed)
+                .collect(),
+            Value::Scalar(Scalar::U32) => integers
+                .filter_map(|value| u32::try_from(value).ok())
+                .map(Literal::u32_unsuffixed)
+                .collect(),
+            _ => return None,
+        };
+        let declared = self.integer_enum.len() + self.number_enum.len();
+        (declared > 0).then_some(allowed)
+    }
+}
@@ -0,0 +1,216 @@
+use heck::ToShoutySnakeCase;
+use openapiv3::{OpenAPI, PathItem};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{make_ident, Ref, Reference, UnknownReference},
+    openapi_compat::path_items,
+    ApiModel,
+};
+
+use super::{create_endpoint, verb::Verb, Endpoint, Error};
+
+/// Webhooks are read from this extension, as OpenAPI 3.0 has no native `webhooks` field.
+///
+/// The `webhooks` field of OpenAPI 3.1 is moved here by [`deserialize_spec`][crate::deserialize_spec].
+pub(crate) const WEBHOOKS_EXTENSION: &str = "x-webhooks";
+
+/// A request which the API sends to its consumers, rather than receiving from them.
+///
+/// Callbacks are registered by an operation of the API, and sent to a URL computed from a runtime expression.
+/// Webhooks are registered out of band, and sent to a URL which the API already knows.
+#[derive(Debug, Clone)]
+pub struct Callback<Ref = Reference> {
+    /// Name of the callback or webhook.
+    pub name: String,
+    /// Name of the operation which registers this callback, or `None` for webhooks.
+    pub registered_by: Option<String>,
+    /// Runtime expression from which the callback URL is computed: `{$request.body#/callbackUrl}`.
+    ///
+    /// `None` for webhooks.
+    pub url_expression: Option<String>,
+    /// The request which is sent, and the responses which are expected.
+    ///
+    /// The endpoint path is the URL expression for callbacks, and the webhook name for webhooks.
+    pub endpoint: Endpoint<Ref>,
+}
+
+impl Callback<Ref> {
+    pub(crate) fn resolve_refs(
+        self,
+        resolver: impl Fn(&Ref) -> Result<Reference, UnknownReference>,
+    ) -> Result<Callback<Reference>, UnknownReference> {
+        let Self {
+            name,
+            registered_by,
+            url_expression,
+            endpoint,
+        } = self;
+        let endpoint = endpoint.resolve_refs(resolver)?;
+        Ok(Callback {
+            name,
+            registered_by,
+            url_expression,
+            endpoint,
+        })
+    }
+}
+
+impl<R> Callback<R> {
+    /// Name of the constant which holds the URL expression of this callback.
+    pub(crate) fn url_expression_const_name(&self) -> String {
+        format!(
+            "{}_URL",
+            self.endpoint.function_name(None).to_shouty_snake_case()
+        )
+    }
+
+    fn doc_string(&self) -> String {
+        let mut docs = self.endpoint.doc_string();
+        match self.registered_by.as_deref() {
+            Some(registered_by) => docs.push_str(&format!(
+                "Callback `{}`, registered by `{registered_by}`.\n\n",
+                self.name
+            )),
+            None => docs.push_str(&format!("Webhook `{}`.\n\n", self.name)),
+        }
+        docs
+    }
+}
+
+impl Callback {
+    /// Emit the method of `trait Callbacks` which sends this callback.
+    pub fn emit<'a>(
+        &self,
+        api_model: &ApiModel,
+        name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    ) -> Result<TokenStream, UnknownReference> {
+        let docs = self.doc_string();
+        let docs = quote!(#[doc = #docs]);
+        let function_name = make_ident(&self.endpoint.function_name(None));
+
+        let parameters = self
+            .endpoint
+            .function_parameters(api_model)?
+            .map(|(name, ref_, required)| {
+                let param_name = make_ident(&name);
+                let mut type_name = api_model.definition(ref_, &name_resolver)?;
+                if !required {
+                    type_name = quote!(Option< #type_name >);
+                }
+                Ok(quote!(#param_name: #type_name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let request_body = self
+            .endpoint
+            .request_body
+            .map(|ref_| {
+                let type_name = api_model.definition(ref_, &name_resolver)?;
+
+                Ok(quote!(request_body: #type_name))
+            })
+            .transpose()?;
+
+        let response_body = api_model.definition(self.endpoint.response, name_resolver)?;
+
+        let signature = api_model.async_method_signature(
+            &function_name,
+            quote! {
+                &self,
+                url: &str,
+                #(
+                    #parameters,
+                )*
+                #request_body
+            },
+            quote!(Result<#response_body, Self::Error>),
+        );
+
+        Ok(quote! {
+            #docs
+            #signature;
+        })
+    }
+
+    /// Emit a constant holding the URL expression of this callback, if it has one.
+    pub fn emit_url_expression(&self) -> Option<TokenStream> {
+        let url_expression = self.url_expression.as_deref()?;
+        let docs = format!(
+            "URL expression of callback `{}`.\n\nResolve it against the registering request with [`openapi_gen::callback::resolve_url`].",
+            self.name
+        );
+        let const_name = make_ident(&self.url_expression_const_name());
+        Some(quote! {
+            #[doc = #docs]
+            pub const #const_name: &str = #url_expression;
+        })
+    }
+}
+
+/// Iterate over the OpenApi specification, constructing callbacks and webhooks and inserting each into the model.
+pub(crate) fn insert_callbacks(spec: &OpenAPI, model: &mut ApiModel<Ref>) -> Result<(), Error> {
+    for (path, path_item) in path_items(spec) {
+        for (verb, operation) in path_item.iter() {
+            let registered_by = match operation.operation_id.as_deref() {
+                Some(operation_id) => operation_id.to_owned(),
+                None => {
+                    let verb: Verb = verb.parse().map_err(|err| Error::UnknownVerb {
+                        verb: verb.to_string(),
+                        err,
+                    })?;
+                    format!("{verb} {path}")
+                }
+            };
+
+            for (name, callback) in &operation.callbacks {
+                for (url_expression, callback_item) in callback {
+                    for (verb, operation) in callback_item.iter() {
+                        let endpoint = create_endpoint(
+                            spec,
+                            model,
+                            url_expression,
+                            callback_item,
+                            verb,
+                            operation,
+                        )?;
+                        model.callbacks.push(Callback {
+                            name: name.clone(),
+                            registered_by: Some(registered_by.clone()),
+                            url_expression: Some(url_expression.clone()),
+                            endpoint,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    let webhooks = spec
+        .extensions
+        .get(WEBHOOKS_EXTENSION)
+        .and_then(serde_json::Value::as_object)
+        .into_iter()
+        .flatten();
+    for (name, webhook_item) in webhooks {
+        let webhook_item =
+            serde_json::from_value::<PathItem>(webhook_item.clone()).map_err(|err| {
+                Error::ParseWebhook {
+                    name: name.clone(),
+                    err,
+                }
+            })?;
+        for (verb, operation) in webhook_item.iter() {
+            let endpoint = create_endpoint(spec, model, name, &webhook_item, verb, operation)?;
+            model.callbacks.push(Callback {
+                name: name.clone(),
+                registered_by: None,
+                url_expression: None,
+                endpoint,
+            });
+        }
+    }
+
+    Ok(())
+}
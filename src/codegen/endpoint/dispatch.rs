@@ -0,0 +1,358 @@
+//! Code shared by the server integrations which dispatch requests without the help of axum.
+//!
+//! Each integration provides a runtime module with functions of the same names, which the emitted code calls.
+
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::{
+            request_body::resolve_concrete,
+            security::{requirement_variant_name, KeyLocation, SchemeKind, SecurityScheme},
+        },
+        make_ident,
+        value::{object::BODY_IDENT, one_of_enum::VariantStatus},
+        Endpoint, Object, Reference, Scalar, Value,
+    },
+    ApiModel,
+};
+
+/// `true` when the item is a problem details object, which is sent with its own content type.
+fn is_api_problem(value: &Value) -> bool {
+    #[cfg(feature = "api-problem")]
+    {
+        matches!(value, Value::Scalar(Scalar::ApiProblem))
+    }
+    #[cfg(not(feature = "api-problem"))]
+    {
+        let _ = value;
+        false
+    }
+}
+
+/// Emit a single `match` arm which encodes a variant of a response enum.
+///
+/// This follows the same rules as the `IntoResponse` implementation of the axum integration: the arm sets the
+/// status and headers of the variant, then encodes its body according to its content type.
+fn encode_variant(
+    model: &ApiModel,
+    prefix: &TokenStream,
+    response: Reference,
+    response_name: &str,
+    variant_name: &str,
+    status: Option<VariantStatus>,
+) -> Result<TokenStream, Error> {
+    let item = model
+        .resolve(response)
+        .map_err(Error::context(format!(
+            "response variant reference: {response:?} ({response_name})"
+        )))
+        .map_err(Error::context("getting response variant item"))?;
+
+    let response_ident = make_ident(response_name);
+    let variant_ident = make_ident(variant_name);
+    let variant_binding = make_ident(&variant_name.to_snake_case());
+    let item_ident = make_ident(&item.rust_name);
+
+    // variants whose status is only known at runtime carry it alongside their content
+    let mut unpack_status = None;
+    let status = match status {
+        Some(VariantStatus::Code(status_code)) => {
+            let status_name = status_code
+                .canonical_reason()
+                .map(|reason| reason.to_shouty_snake_case())
+                .ok_or_else(|| Error::new("failed to get status code for response variant"))?;
+            let ident = make_ident(&status_name);
+            quote!(openapi_gen::reexport::http::status::StatusCode::#ident)
+        }
+        Some(VariantStatus::Range(_) | VariantStatus::Default) => {
+            // named after the variant so that it cannot be shadowed by a header
+            let status_ident = make_ident(&format!("{variant_binding}_status"));
+            unpack_status = Some(quote! {
+                let #status_ident = openapi_gen::AsStatusCode::as_status_code(&#variant_binding);
+                let #variant_binding = #variant_binding.into_body();
+            });
+            quote!(#status_ident)
+        }
+        None => return Err(Error::new("failed to get status for response variant")),
+    };
+
+    let mut unpack_object = None;
+    let mut headers = Vec::new();
+    let body;
+    let mut body_ref = response;
+
+    if let Value::Object(Object {
+        is_generated_body_and_headers: true,
+        members,
+    }) = &item.value
+    {
+        // the identifier for the body is constant in this case
+        body = make_ident(BODY_IDENT);
+
+        let member_idents = members.keys().map(|member| make_ident(member));
+        unpack_object = Some(quote! {
+            let #item_ident { #( #member_idents ),* } = #variant_binding;
+        });
+
+        for (member_name, member) in members {
+            if member_name == BODY_IDENT {
+                body_ref = member.definition;
+                continue;
+            }
+            let header_ident = make_ident(member_name);
+            let header_name = member_name.to_lowercase();
+            headers.push(quote! {
+                #prefix::insert_header(&mut response, #header_name, &#header_ident)?;
+            });
+        }
+    } else {
+        body = variant_binding.clone();
+    }
+
+    let body_item = model
+        .resolve(body_ref)
+        .map_err(Error::context("getting response body item"))?;
+    let concrete_body = resolve_concrete(model, body_ref)
+        .map_err(Error::context("resolving response body item"))?;
+    let content = if item.content_type.is_some() {
+        item
+    } else {
+        body_item
+    };
+    let content_type = content.content_type.as_deref();
+
+    let respond = if matches!(concrete_body.value, Value::Scalar(Scalar::Unit)) {
+        quote!(#prefix::raw(response, None, #body))
+    } else if is_api_problem(&concrete_body.value) {
+        let content_type = content_type.unwrap_or("application/problem+json");
+        quote!(#prefix::json(response, #content_type, &#body)?)
+    } else if content.is_json() {
+        let content_type = content_type.unwrap_or("application/json");
+        quote!(#prefix::json(response, #content_type, &#body)?)
+    } else if content.is_text() && !body_item.nullable && body_item.is_canonical_scalar(model) {
+        quote!(#prefix::text(response, #content_type, &#body)?)
+    } else {
+        let content_type = match content_type {
+            Some(content_type) => quote!(Some(#content_type)),
+            None => quote!(None),
+        };
+        quote!(#prefix::raw(response, #content_type, #body))
+    };
+
+    let mutability = (!headers.is_empty()).then(|| quote!(mut));
+
+    Ok(quote! {
+        #response_ident::#variant_ident(#variant_binding) => {
+            #unpack_status
+            #unpack_object
+            let #mutability response = #prefix::response(#status);
+            #( #headers )*
+            #respond
+        }
+    })
+}
+
+/// Emit an expression which encodes `self`, a response enum, into a response.
+///
+/// The expression calls functions of the module at `prefix`, which must provide `respond`, `response`,
+/// `insert_header`, `json`, `text`, and `raw`. Failure to encode a header or body responds with
+/// `500 Internal Server Error`.
+pub(crate) fn encode_response(
+    model: &ApiModel,
+    prefix: &TokenStream,
+    response_enum: Reference,
+) -> Result<TokenStream, Error> {
+    let item = model
+        .resolve(response_enum)
+        .map_err(Error::context("getting response item"))?;
+
+    let response_name = &item.rust_name;
+
+    let Value::OneOfEnum(oo_enum) = &item.value else {
+        let err = Error::new(format!(
+            "response reference: {response_enum:?} ({response_name})"
+        ));
+        return Err(Error::context("expected response variant to be OneOfEnum")(
+            err,
+        ));
+    };
+
+    let mut branches = Vec::with_capacity(oo_enum.variants.len());
+    for variant in &oo_enum.variants {
+        let variant_name = variant.computed_name().ok_or_else(|| {
+            let err = Error::new("failed to get computed variant name for response variant");
+            Error::context("computing branches within `encode_response`")(err)
+        })?;
+
+        branches.push(encode_variant(
+            model,
+            prefix,
+            variant.definition,
+            response_name,
+            variant_name,
+            variant.status,
+        )?);
+    }
+
+    Ok(quote! {
+        #prefix::respond(move || {
+            Ok(match self {
+                #( #branches )*
+            })
+        })
+    })
+}
+
+/// Wrap the body of `extract` in an implementation of `Credentials` for `ident`.
+fn wrap_impl(
+    prefix: &TokenStream,
+    request: &TokenStream,
+    ident: &syn::Ident,
+    body: TokenStream,
+) -> TokenStream {
+    quote! {
+        impl #prefix::Credentials for #ident {
+            fn extract(
+                request: &#request,
+            ) -> Result<Self, #prefix::SecurityRejection> {
+                #body
+            }
+        }
+    }
+}
+
+/// Implement `Credentials` for the credential type of a security scheme.
+///
+/// `prefix` is the module which defines `trait Credentials` for requests of type `request`, as well as a
+/// function which extracts credentials of each kind of security scheme.
+pub(crate) fn impl_scheme_credentials(
+    prefix: &TokenStream,
+    request: &TokenStream,
+    scheme: &SecurityScheme,
+) -> TokenStream {
+    let ident = make_ident(&scheme.rust_name);
+    let name = &scheme.name;
+
+    let extract = match &scheme.kind {
+        SchemeKind::ApiKey {
+            location,
+            name: key,
+        } => match location {
+            KeyLocation::Header => {
+                let key = key.to_lowercase();
+                quote!(#prefix::api_key_header(request, #name, #key))
+            }
+            KeyLocation::Query => quote!(#prefix::api_key_query(request, #name, #key)),
+            KeyLocation::Cookie => quote!(#prefix::api_key_cookie(request, #name, #key)),
+        },
+        SchemeKind::Basic => quote!(#prefix::basic(request, #name)),
+        SchemeKind::Bearer => quote!(#prefix::bearer(request, #name)),
+        SchemeKind::Http { scheme } => quote!(#prefix::credentials(request, #name, #scheme)),
+    };
+
+    wrap_impl(prefix, request, &ident, quote!(#extract.map(Self)))
+}
+
+/// Implement `Credentials` for the credentials enum of an endpoint, if it has one.
+///
+/// Requirements are tried in order; the first which is satisfied in full produces the credentials.
+pub(crate) fn impl_credentials_credentials(
+    model: &ApiModel,
+    prefix: &TokenStream,
+    request: &TokenStream,
+    endpoint: &Endpoint,
+) -> Option<TokenStream> {
+    let security = endpoint
+        .security
+        .as_ref()
+        .filter(|security| security.has_credentials_enum())?;
+    let ident = make_ident(&security.credentials);
+
+    let requirements = security
+        .requirements
+        .iter()
+        .enumerate()
+        .map(|(idx, requirement)| {
+            let variant = make_ident(&requirement_variant_name(model, requirement));
+            let schemes = requirement
+                .keys()
+                .map(|name| &model.security_schemes[name])
+                .collect::<Vec<_>>();
+            let variables = schemes
+                .iter()
+                .map(|scheme| make_ident(&scheme.rust_name.to_snake_case()))
+                .collect::<Vec<_>>();
+            let extract = schemes.iter().map(|scheme| {
+                let scheme_ident = make_ident(&scheme.rust_name);
+                quote!(<#scheme_ident as #prefix::Credentials>::extract(request))
+            });
+            let combine = |err: TokenStream| {
+                if idx == 0 {
+                    err
+                } else {
+                    quote!(rejection.or(#err))
+                }
+            };
+
+            if schemes.len() == 1 {
+                let err = combine(quote!(err));
+                quote! {
+                    let rejection = match #( #extract )* {
+                        Ok(#( #variables )*) => return Ok(Self::#variant( #( #variables )* )),
+                        Err(err) => #err,
+                    };
+                }
+            } else {
+                let err = combine(
+                    quote!(#prefix::SecurityRejection::requirement([ #( #variables.err() ),* ])),
+                );
+                quote! {
+                    let rejection = match ( #( #extract ),* ) {
+                        ( #( Ok(#variables) ),* ) => return Ok(Self::#variant( #( #variables ),* )),
+                        ( #( #variables ),* ) => #err,
+                    };
+                }
+            }
+        });
+
+    Some(wrap_impl(
+        prefix,
+        request,
+        &ident,
+        quote! {
+            #( #requirements )*
+            Err(rejection)
+        },
+    ))
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{msg}")]
+pub struct Error {
+    msg: String,
+    #[source]
+    inner: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl Error {
+    fn new(msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        let inner = None;
+        Self { msg, inner }
+    }
+
+    fn context<C, E>(context: C) -> impl FnOnce(E) -> Self
+    where
+        C: Into<String>,
+        Box<dyn 'static + std::error::Error + Send + Sync>: From<E>,
+    {
+        move |err| {
+            let msg = context.into();
+            let inner = Some(err.into());
+            Self { msg, inner }
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::HashSet;
+
+use anyhow::anyhow;
+use heck::{AsSnakeCase, ToSnakeCase, ToUpperCamelCase};
+use indexmap::IndexMap;
+use openapiv3::{LinkOperation, OpenAPI, Response};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::{
+    codegen::{
+        make_ident,
+        value::{object::BODY_IDENT, one_of_enum::VariantStatus},
+        Object, Ref, Reference, UnknownReference, Value,
+    },
+    resolve_trait::Resolve,
+    ApiModel,
+};
+
+use super::{verb::Verb, Endpoint};
+
+/// The operation which a link targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// Target named by `operationId`.
+    OperationId(String),
+    /// Target named by a local `operationRef` such as `#/paths/~1users~1{id}/get`.
+    Operation { path: String, verb: Verb },
+}
+
+/// A link from some variants of a response to a follow-up operation.
+///
+/// Links are emitted as a struct holding the parameters of the target operation which the link computes,
+/// and a method on the response enum which computes it.
+#[derive(Debug, Clone)]
+pub struct Link {
+    /// Name of the link within the response.
+    pub name: String,
+    /// Name of the struct which holds the computed parameters.
+    pub rust_name: String,
+    pub description: Option<String>,
+    pub target: LinkTarget,
+    /// Runtime expressions or constants, by parameter name.
+    pub parameters: IndexMap<String, serde_json::Value>,
+    /// Indices of the variants of the response enum which provide this link.
+    pub variants: Vec<usize>,
+}
+
+impl Link {
+    fn target_name(&self) -> String {
+        match &self.target {
+            LinkTarget::OperationId(operation_id) => operation_id.clone(),
+            LinkTarget::Operation { path, verb } => format!("{verb} {path}"),
+        }
+    }
+}
+
+/// Parse a local `operationRef` into the path and verb which it points to.
+fn parse_operation_ref(operation_ref: &str) -> Option<LinkTarget> {
+    let pointer = operation_ref.strip_prefix("#/paths/")?;
+    let (path, verb) = pointer.rsplit_once('/')?;
+    let path = path.replace("~1", "/").replace("~0", "~");
+    let verb = verb.parse().ok()?;
+    Some(LinkTarget::Operation { path, verb })
+}
+
+/// Add the links of a response to `links`, recording that they are provided by the given variants.
+///
+/// Links with the same name as one already present must be the same link, which is then provided by more variants.
+pub(crate) fn collect_links(
+    spec: &OpenAPI,
+    model: &ApiModel<Ref>,
+    response_name: &str,
+    response: &Response,
+    variants: std::ops::Range<usize>,
+    links: &mut Vec<Link>,
+) -> Result<(), super::Error> {
+    let wrap_err = |err: anyhow::Error| super::Error::CreateResponse(err);
+
+    for (name, link_ref) in &response.links {
+        let link = Resolve::resolve(link_ref, spec).map_err(wrap_err)?;
+        let target = match &link.operation {
+            LinkOperation::OperationId(operation_id) => {
+                LinkTarget::OperationId(operation_id.clone())
+            }
+            LinkOperation::OperationRef(operation_ref) => parse_operation_ref(operation_ref)
+                .ok_or_else(|| {
+                    wrap_err(anyhow!(
+                        "link \"{name}\": only local operation references are supported: {operation_ref}"
+                    ))
+                })?,
+        };
+
+        if let Some(existing) = links.iter_mut().find(|link| &link.name == name) {
+            if existing.target != target
+                || existing.parameters != link.parameters
+                || existing.description != link.description
+            {
+                return Err(wrap_err(anyhow!(
+                    "link \"{name}\" is declared differently by several responses of the operation"
+                )));
+            }
+            existing.variants.extend(variants.clone());
+            continue;
+        }
+
+        let mut rust_name = format!(
+            "{}{}Link",
+            response_name.to_upper_camel_case(),
+            name.to_upper_camel_case()
+        );
+        model.deconflict_ident(&mut rust_name);
+
+        links.push(Link {
+            name: name.clone(),
+            rust_name,
+            description: link.description.clone(),
+            target,
+            parameters: link.parameters.clone(),
+            variants: variants.clone().collect(),
+        });
+    }
+
+    Ok(())
+}
+
+/// A runtime expression or constant, as it applies to a link parameter.
+enum LinkValue<'a> {
+    StatusCode,
+    Body(Option<&'a str>),
+    Header(&'a str),
+    /// Expressions which depend on the request cannot be computed from the response alone.
+    Request,
+    Constant(&'a serde_json::Value),
+}
+
+impl<'a> LinkValue<'a> {
+    fn parse(value: &'a serde_json::Value) -> Self {
+        let Some(expression) = value.as_str().filter(|value| value.starts_with('$')) else {
+            return Self::Constant(value);
+        };
+        if expression == "$statusCode" {
+            return Self::StatusCode;
+        }
+        if expression == "$url" || expression == "$method" || expression.starts_with("$request.") {
+            return Self::Request;
+        }
+        if let Some(header) = expression.strip_prefix("$response.header.") {
+            return Self::Header(header);
+        }
+        match expression.strip_prefix("$response.body") {
+            Some("") => Self::Body(None),
+            Some(pointer) => match pointer.strip_prefix('#') {
+                Some(pointer) => Self::Body(Some(pointer)),
+                None => Self::Constant(value),
+            },
+            None => Self::Constant(value),
+        }
+    }
+}
+
+/// The name of the struct field which holds the member `name` of an object.
+fn field_name(model: &ApiModel, name: &str) -> String {
+    let mut field_name = format!("{}", AsSnakeCase(name));
+    model.deconflict_member_or_variant_ident(&mut field_name);
+    field_name
+}
+
+/// Find a parameter of the target endpoint by its spec name, optionally qualified by location: `path.id`.
+///
+/// Returns the name of the field which holds the parameter, and its type.
+fn find_parameter(
+    model: &ApiModel,
+    target: &Endpoint,
+    name: &str,
+) -> Result<Option<(String, Reference)>, UnknownReference> {
+    let (location, name) = match name.split_once('.') {
+        Some((location @ ("path" | "query" | "header"), name)) => (Some(location), name),
+        _ => (None, name),
+    };
+
+    if matches!(location, None | Some("path")) {
+        if let Some((_ref, _item, object)) = target.path_parameter_object(model)? {
+            if let Some(member) = object.members.get(name) {
+                return Ok(Some((field_name(model, name), member.definition)));
+            }
+        }
+    }
+    if matches!(location, None | Some("query")) {
+        if let Some((_ref, _item, object)) = target.query_parameter_object(model)? {
+            if let Some(member) = object.members.get(name) {
+                return Ok(Some((field_name(model, name), member.definition)));
+            }
+        }
+    }
+    if matches!(location, None | Some("header")) {
+        if let Some((key, param)) = target
+            .headers
+            .iter()
+            .find(|(_key, param)| param.spec_name.eq_ignore_ascii_case(name))
+        {
+            return Ok(Some((field_name(model, key), param.item_ref)));
+        }
+    }
+    Ok(None)
+}
+
+/// Emit the struct which holds the parameters computed by a link, and the method of the response enum which computes it.
+pub(crate) fn emit_link<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    endpoint: &Endpoint,
+    link: &Link,
+) -> Result<TokenStream, Error> {
+    let target_name = link.target_name();
+    let target = model
+        .endpoints
+        .iter()
+        .find(|candidate| match &link.target {
+            LinkTarget::OperationId(operation_id) => {
+                candidate.operation_id.as_deref() == Some(operation_id.as_str())
+            }
+            LinkTarget::Operation { path, verb } => {
+                &candidate.path == path && candidate.verb == *verb
+            }
+        })
+        .ok_or_else(|| Error::UnknownOperation {
+            link: link.name.clone(),
+            operation: target_name.clone(),
+        })?;
+
+    let response_item = model.resolve(endpoint.response)?;
+    let response_ident = make_ident(&response_item.rust_name);
+    let Value::OneOfEnum(oo_enum) = &response_item.value else {
+        return Err(Error::NotAnEnum(response_item.rust_name.clone()));
+    };
+
+    let link_ident = make_ident(&link.rust_name);
+    let prefix = quote!(openapi_gen::link);
+
+    // parameters which depend on the request are left to the caller
+    let mut fields = Vec::new();
+    let mut field_names = HashSet::new();
+    let mut omitted = Vec::new();
+    for (name, value) in &link.parameters {
+        let value = LinkValue::parse(value);
+        if matches!(value, LinkValue::Request) {
+            omitted.push(format!("`{name}`"));
+            continue;
+        }
+        let (mut field_name, ref_) =
+            find_parameter(model, target, name)?.ok_or_else(|| Error::UnknownParameter {
+                link: link.name.clone(),
+                parameter: name.clone(),
+            })?;
+        // parameters of different locations may share a name
+        while !field_names.insert(field_name.clone()) {
+            field_name.push('_');
+        }
+        let type_ = model.definition(ref_, &name_resolver)?;
+        fields.push((make_ident(&field_name), type_, value));
+    }
+
+    let mut docs = format!(
+        "Parameters of `{target_name}`, computed from link `{}` of [`{}`].",
+        link.name, response_item.rust_name
+    );
+    if let Some(description) = link.description.as_deref() {
+        docs.push_str("\n\n");
+        docs.push_str(description);
+    }
+    if !omitted.is_empty() {
+        docs.push_str(&format!(
+            "\n\nParameters computed from the request ({}) are not included.",
+            omitted.join(", ")
+        ));
+    }
+
+    let field_defs = fields
+        .iter()
+        .map(|(field_ident, type_, _value)| quote!(pub #field_ident: #type_,));
+
+    let mut constructors = Vec::new();
+    let mut arms = Vec::new();
+    for &index in &link.variants {
+        let variant = oo_enum
+            .variants
+            .get(index)
+            .ok_or_else(|| Error::NotAnEnum(response_item.rust_name.clone()))?;
+        let variant_name = variant
+            .computed_name()
+            .ok_or_else(|| Error::NotAnEnum(response_item.rust_name.clone()))?;
+        let variant_ident = make_ident(variant_name);
+        let variant_binding = make_ident(&variant_name.to_snake_case());
+        let constructor_ident = make_ident(&format!("from_{}", variant_name.to_snake_case()));
+        let status = variant
+            .status
+            .ok_or_else(|| Error::NotAnEnum(response_item.rust_name.clone()))?;
+        let payload_type =
+            status.wrap_runtime(model.definition(variant.definition, &name_resolver)?);
+
+        let (inner, status) = match status {
+            VariantStatus::Code(status) => {
+                let status = Literal::u16_unsuffixed(status.as_u16());
+                (quote!(response), quote!(&#status))
+            }
+            VariantStatus::Range(_) | VariantStatus::Default => {
+                (quote!(response.body()), quote!(&response.status().as_u16()))
+            }
+        };
+
+        let variant_item = model.resolve(variant.definition)?;
+        let headers_object = match &variant_item.value {
+            Value::Object(Object {
+                is_generated_body_and_headers: true,
+                members,
+            }) => Some(members),
+            _ => None,
+        };
+        let body = if headers_object.is_some() {
+            let body_ident = make_ident(BODY_IDENT);
+            quote!(&#inner.#body_ident)
+        } else {
+            inner.clone()
+        };
+
+        let mut values = Vec::with_capacity(fields.len());
+        for (field_ident, _type, value) in &fields {
+            let value = match value {
+                LinkValue::StatusCode => quote!(#prefix::convert(#status)?),
+                LinkValue::Body(None) => quote!(#prefix::convert(#body)?),
+                LinkValue::Body(Some(pointer)) => quote!(#prefix::pointer(#body, #pointer)?),
+                LinkValue::Header(header) => {
+                    let member = headers_object
+                        .into_iter()
+                        .flatten()
+                        .filter(|(member_name, _member)| *member_name != BODY_IDENT)
+                        .find(|(_member_name, member)| {
+                            model
+                                .resolve(member.definition)
+                                .is_ok_and(|item| item.spec_name.eq_ignore_ascii_case(header))
+                        })
+                        .map(|(member_name, _member)| make_ident(&field_name(model, member_name)))
+                        .ok_or_else(|| Error::UnknownHeader {
+                            link: link.name.clone(),
+                            header: (*header).to_owned(),
+                        })?;
+                    quote!(#prefix::convert(&#inner.#member)?)
+                }
+                LinkValue::Constant(constant) => {
+                    let constant = constant.to_string();
+                    quote!(#prefix::constant(#constant)?)
+                }
+                LinkValue::Request => unreachable!("request parameters are omitted"),
+            };
+            values.push(quote!(#field_ident: #value,));
+        }
+
+        let constructor_docs = format!(
+            "Compute this link from the `{variant_name}` variant of [`{}`].",
+            response_item.rust_name
+        );
+        constructors.push(quote! {
+            #[doc = #constructor_docs]
+            pub fn #constructor_ident(response: &#payload_type) -> Result<Self, #prefix::LinkError> {
+                Ok(Self {
+                    #( #values )*
+                })
+            }
+        });
+        arms.push(quote! {
+            #response_ident::#variant_ident(#variant_binding) => Some(#link_ident::#constructor_ident(#variant_binding)),
+        });
+    }
+
+    if link.variants.len() < oo_enum.variants.len() {
+        arms.push(quote!(_ => None,));
+    }
+
+    let method_ident = make_ident(&format!("{}_link", link.name.to_snake_case()));
+    let method_docs = format!(
+        "Follow link `{}` to `{target_name}`.\n\nReturns `None` if this response does not provide the link.",
+        link.name
+    );
+
+    Ok(quote! {
+        #[doc = #docs]
+        #[derive(Debug, Clone)]
+        pub struct #link_ident {
+            #( #field_defs )*
+        }
+
+        impl #link_ident {
+            #( #constructors )*
+        }
+
+        impl #response_ident {
+            #[doc = #method_docs]
+            pub fn #method_ident(&self) -> Option<Result<#link_ident, #prefix::LinkError>> {
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+    })
+}
+
+/// Emit every link of every endpoint.
+pub(crate) fn emit_links<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    let mut out = TokenStream::new();
+    for endpoint in model
+        .endpoints
+        .iter()
+        .chain(model.callbacks.iter().map(|callback| &callback.endpoint))
+    {
+        for link in &endpoint.links {
+            out.extend(emit_link(model, &name_resolver, endpoint, link)?);
+        }
+    }
+    Ok(out)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    UnknownReference(#[from] UnknownReference),
+    #[error("link \"{link}\" targets unknown operation \"{operation}\"")]
+    UnknownOperation { link: String, operation: String },
+    #[error(
+        "link \"{link}\" sets parameter \"{parameter}\", which its target operation does not have"
+    )]
+    UnknownParameter { link: String, parameter: String },
+    #[error("link \"{link}\" reads header \"{header}\", which its response does not have")]
+    UnknownHeader { link: String, header: String },
+    #[error("response \"{0}\" is not a well-formed response enum")]
+    NotAnEnum(String),
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use heck::{AsUpperCamelCase, ToSnakeCase, ToUpperCamelCase};
 use indexmap::IndexMap;
-use openapiv3::OpenAPI;
+use openapiv3::{OpenAPI, Operation, PathItem};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -15,8 +15,16 @@ use crate::{
     ApiModel,
 };
 
+pub(crate) mod callback;
+
+#[cfg(any(feature = "actix-support", feature = "tower-support"))]
+pub(crate) mod dispatch;
+
 pub(crate) mod header;
 
+pub(crate) mod link;
+use link::Link;
+
 pub(crate) mod parameter;
 use parameter::{convert_param_ref, Parameter};
 
@@ -24,6 +32,11 @@ pub(crate) mod request_body;
 
 pub(crate) mod response;
 
+pub(crate) mod security;
+use security::{create_security, Security};
+
+pub(crate) mod tag;
+
 pub(crate) mod verb;
 use verb::Verb;
 
@@ -65,6 +78,10 @@ pub struct Endpoint<Ref = Reference> {
     ///
     /// If set, this is used as the basis for the rust name.
     pub operation_id: Option<String>,
+    /// First tag of the operation.
+    ///
+    /// When emitting one trait per tag, this determines the trait to which the endpoint belongs.
+    pub tag: Option<String>,
     /// Request body.
     ///
     /// This is overridden to `None` if `verb` is `GET`, `HEAD`, `DELETE`, or `TRACE`.
@@ -74,6 +91,12 @@ pub struct Endpoint<Ref = Reference> {
     /// This is always an enum, even in the event that there are 0 variants. (That is a degenerate case
     /// indicating a malformed OpenAPI specification).
     pub response: Ref,
+    /// Links from variants of the response to follow-up operations.
+    pub links: Vec<Link>,
+    /// Security requirements.
+    ///
+    /// Taken from `Operation::security`, falling back to `OpenAPI::security`. `None` if the endpoint is not secured.
+    pub security: Option<Security>,
 }
 
 type MaybeItemObject<'a, R> = Option<(R, &'a Item<R>, Object<R>)>;
@@ -168,7 +191,7 @@ impl<R> Endpoint<R> {
     ///
     /// Items are `(name, type, required)` where `name` is an appropriate parameter name, and `type` is convertable into a type ident.
     /// `required` is `true` when the item is mandatory.
-    fn function_parameters<'a>(
+    pub(crate) fn function_parameters<'a>(
         &'a self,
         model: &'a ApiModel<R>,
     ) -> Result<impl 'a + Iterator<Item = (String, R, bool)>, UnknownReference>
@@ -228,11 +251,14 @@ impl Endpoint<Ref> {
             operation_documentation,
             verb,
             operation_id,
+            tag,
             request_body,
             response,
             query_parameters,
             path_parameters,
             headers,
+            links,
+            security,
         } = self;
 
         let headers = headers
@@ -250,11 +276,14 @@ impl Endpoint<Ref> {
             operation_documentation,
             verb,
             operation_id,
+            tag,
             request_body,
             response,
             headers,
             path_parameters,
             query_parameters,
+            links,
+            security,
         })
     }
 }
@@ -294,17 +323,37 @@ impl Endpoint {
             })
             .transpose()?;
 
-        let response_body = api_model.definition(self.response, name_resolver)?;
+        let credentials = self
+            .credentials_type()
+            .map(|type_name| quote!(credentials: #type_name,));
 
-        Ok(quote! {
-            #docs
-            async fn #function_name (
+        let context = api_model
+            .options
+            .context
+            .then(|| quote!(context: Self::Context,));
+
+        let mut response_body = api_model.definition(self.response, name_resolver)?;
+        if api_model.options.is_fallible() {
+            response_body = quote!(Result<#response_body, Self::Error>);
+        }
+
+        let signature = api_model.async_method_signature(
+            &function_name,
+            quote! {
                 &self,
+                #context
+                #credentials
                 #(
                     #parameters,
                 )*
                 #request_body
-            ) -> #response_body;
+            },
+            response_body,
+        );
+
+        Ok(quote! {
+            #docs
+            #signature;
         })
     }
 }
@@ -397,139 +446,150 @@ fn make_param_object(
 pub(crate) fn insert_endpoints(spec: &OpenAPI, model: &mut ApiModel<Ref>) -> Result<(), Error> {
     for (path, path_item) in path_items(spec) {
         for (verb, operation) in path_item.iter() {
-            let verb: Verb = verb.parse().map_err(|err| Error::UnknownVerb {
-                verb: verb.to_string(),
-                err,
-            })?;
-            let endpoint_documentation = path_item
-                .description
-                .as_deref()
-                .or(path_item.summary.as_deref())
-                .map(ToOwned::to_owned);
-            let operation_documentation = operation
-                .description
-                .as_deref()
-                .or(operation.summary.as_deref())
-                .map(ToOwned::to_owned);
-
-            // we need to ensure that when at least one response variant has the same response code and more than one
-            // content type, the generated function includes the "Accept" header. This may or may not appear in the
-            // spec, so we ensure it appears first in the parameter list. After that point, if it does appear in the user spec,
-            // we can trust `IndexMap`'s update implementation to ensure it remains first in the emitted list.
-            let accept_header = response::has_responses_distinguished_only_by_content_type(
-                spec, operation,
-            )
-            .then(|| {
-                let format = openapiv3::ParameterSchemaOrContent::Schema(
-                    openapiv3::ReferenceOr::Item(openapiv3::Schema {
-                        schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::String(
-                            openapiv3::StringType {
-                                format: openapiv3::VariantOrUnknownOrEmpty::Unknown(
-                                    "accept-header".into(),
-                                ),
-                                ..Default::default()
-                            },
-                        )),
-                        schema_data: Default::default(),
-                    }),
-                );
-
-                let parameter = openapiv3::Parameter::Header {
-                    parameter_data: openapiv3::ParameterData {
-                        name: "accept".into(),
-                        description: Some("The content type expected by the client".into()),
-                        required: false,
-                        format,
-                        deprecated: Default::default(),
-                        example: Default::default(),
-                        examples: Default::default(),
-                        explode: Default::default(),
-                        extensions: Default::default(),
-                    },
-                    style: Default::default(),
-                };
-
-                openapiv3::ReferenceOr::Item(parameter)
-            });
-            let accept_header = accept_header.iter();
-
-            // first real params are the path item parameters
-            let path_item_params = path_item.parameters.iter();
-
-            // update with the operation parameters
-            let operation_params = operation.parameters.iter();
-
-            let mut path_parameters = Vec::new();
-            let mut query_parameters = Vec::new();
-            let mut headers = IndexMap::new();
-
-            // `IndexMap::from_iter` uses the same logic as its `extend`,
-            // which lets subsequent items override earlier items.
-            for maybe_param in accept_header
-                .chain(path_item_params)
-                .chain(operation_params)
-                .map(|param_ref| convert_param_ref(spec, model, param_ref))
-            {
-                let param = maybe_param?;
-                match param.location {
-                    ParameterLocation::Path => path_parameters.push(param),
-                    ParameterLocation::Query => query_parameters.push(param),
-                    ParameterLocation::Header => {
-                        headers.insert(param.rust_name.clone(), param);
-                    }
-                    ParameterLocation::Cookie => return Err(Error::CookesAreNotSupported),
-                }
-            }
+            let mut endpoint = create_endpoint(spec, model, path, path_item, verb, operation)?;
+            // callbacks are not secured by the api's own requirements, so we only compute these for endpoints
+            endpoint.security = create_security(spec, model, &endpoint, operation)?;
+            model.endpoints.push(endpoint);
+        }
+    }
+    Ok(())
+}
 
-            let uncased_item_name =
-                compute_uncased_item_name(operation.operation_id.as_deref(), verb, path, None);
-
-            let path_parameters =
-                make_param_object(model, "path", &uncased_item_name, path_parameters);
-
-            let query_parameters =
-                make_param_object(model, "query", &uncased_item_name, query_parameters);
-
-            let operation_id = operation.operation_id.clone();
-
-            let request_body = {
-                let spec_name =
-                    make_operation_spec_name(operation_id.as_deref(), "Request", verb, path);
-                operation
-                    .request_body
-                    .as_ref()
-                    .filter(|_request_body| verb.request_body_is_legal())
-                    .map(|body_ref| {
-                        request_body::create_request_body_from_ref(
-                            spec, model, &spec_name, body_ref,
-                        )
-                    })
-                    .transpose()?
+/// Construct an endpoint from a single operation of a path item, inserting its items into the model.
+pub(crate) fn create_endpoint(
+    spec: &OpenAPI,
+    model: &mut ApiModel<Ref>,
+    path: &str,
+    path_item: &PathItem,
+    verb: &str,
+    operation: &Operation,
+) -> Result<Endpoint<Ref>, Error> {
+    let verb: Verb = verb.parse().map_err(|err| Error::UnknownVerb {
+        verb: verb.to_string(),
+        err,
+    })?;
+    let endpoint_documentation = path_item
+        .description
+        .as_deref()
+        .or(path_item.summary.as_deref())
+        .map(ToOwned::to_owned);
+    let operation_documentation = operation
+        .description
+        .as_deref()
+        .or(operation.summary.as_deref())
+        .map(ToOwned::to_owned);
+
+    // we need to ensure that when at least one response variant has the same response code and more than one
+    // content type, the generated function includes the "Accept" header. This may or may not appear in the
+    // spec, so we ensure it appears first in the parameter list. After that point, if it does appear in the user spec,
+    // we can trust `IndexMap`'s update implementation to ensure it remains first in the emitted list.
+    let accept_header = response::has_responses_distinguished_only_by_content_type(spec, operation)
+        .then(|| {
+            let format = openapiv3::ParameterSchemaOrContent::Schema(openapiv3::ReferenceOr::Item(
+                openapiv3::Schema {
+                    schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::String(
+                        openapiv3::StringType {
+                            format: openapiv3::VariantOrUnknownOrEmpty::Unknown(
+                                "accept-header".into(),
+                            ),
+                            ..Default::default()
+                        },
+                    )),
+                    schema_data: Default::default(),
+                },
+            ));
+
+            let parameter = openapiv3::Parameter::Header {
+                parameter_data: openapiv3::ParameterData {
+                    name: "accept".into(),
+                    description: Some("The content type expected by the client".into()),
+                    required: false,
+                    format,
+                    deprecated: Default::default(),
+                    example: Default::default(),
+                    examples: Default::default(),
+                    explode: Default::default(),
+                    extensions: Default::default(),
+                },
+                style: Default::default(),
             };
 
-            let response = {
-                let spec_name =
-                    make_operation_spec_name(operation_id.as_deref(), "Response", verb, path);
-                response::create_responses(spec, model, &spec_name, &operation.responses)?
-            };
+            openapiv3::ReferenceOr::Item(parameter)
+        });
+    let accept_header = accept_header.iter();
 
-            let endpoint = Endpoint::<Ref> {
-                path: path.to_string(),
-                endpoint_documentation,
-                operation_documentation,
-                verb,
-                headers,
-                path_parameters,
-                query_parameters,
-                operation_id,
-                request_body,
-                response,
-            };
+    // first real params are the path item parameters
+    let path_item_params = path_item.parameters.iter();
 
-            model.endpoints.push(endpoint);
+    // update with the operation parameters
+    let operation_params = operation.parameters.iter();
+
+    let mut path_parameters = Vec::new();
+    let mut query_parameters = Vec::new();
+    let mut headers = IndexMap::new();
+
+    // `IndexMap::from_iter` uses the same logic as its `extend`,
+    // which lets subsequent items override earlier items.
+    for maybe_param in accept_header
+        .chain(path_item_params)
+        .chain(operation_params)
+        .map(|param_ref| convert_param_ref(spec, model, param_ref))
+    {
+        let param = maybe_param?;
+        match param.location {
+            ParameterLocation::Path => path_parameters.push(param),
+            ParameterLocation::Query => query_parameters.push(param),
+            ParameterLocation::Header => {
+                headers.insert(param.rust_name.clone(), param);
+            }
+            ParameterLocation::Cookie => return Err(Error::CookesAreNotSupported),
         }
     }
-    Ok(())
+
+    let uncased_item_name =
+        compute_uncased_item_name(operation.operation_id.as_deref(), verb, path, None);
+
+    let path_parameters = make_param_object(model, "path", &uncased_item_name, path_parameters);
+
+    let query_parameters = make_param_object(model, "query", &uncased_item_name, query_parameters);
+
+    let operation_id = operation.operation_id.clone();
+    let tag = operation.tags.first().cloned();
+
+    let request_body = {
+        let spec_name = make_operation_spec_name(operation_id.as_deref(), "Request", verb, path);
+        operation
+            .request_body
+            .as_ref()
+            .filter(|_request_body| verb.request_body_is_legal())
+            .map(|body_ref| {
+                request_body::create_request_body_from_ref(spec, model, &spec_name, body_ref)
+            })
+            .transpose()?
+    };
+
+    let (response, links) = {
+        let spec_name = make_operation_spec_name(operation_id.as_deref(), "Response", verb, path);
+        response::create_responses(spec, model, &spec_name, &operation.responses)?
+    };
+
+    let endpoint = Endpoint::<Ref> {
+        path: path.to_string(),
+        endpoint_documentation,
+        operation_documentation,
+        verb,
+        headers,
+        path_parameters,
+        query_parameters,
+        operation_id,
+        tag,
+        request_body,
+        response,
+        links,
+        security: None,
+    };
+
+    Ok(endpoint)
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -548,4 +608,14 @@ pub enum Error {
     CreateResponse(#[source] anyhow::Error),
     #[error("cookies are not supported")]
     CookesAreNotSupported,
+    #[error("could not create from supplied security scheme")]
+    CreateSecurityScheme(#[source] anyhow::Error),
+    #[error("unknown security scheme \"{0}\"")]
+    UnknownSecurityScheme(String),
+    #[error("could not parse webhook \"{name}\"")]
+    ParseWebhook {
+        name: String,
+        #[source]
+        err: serde_json::Error,
+    },
 }
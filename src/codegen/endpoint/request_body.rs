@@ -1,18 +1,65 @@
 use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
-use openapiv3::{MediaType, OpenAPI, ReferenceOr, Schema};
+use openapiv3::{Encoding, MediaType, OpenAPI, QueryStyle, ReferenceOr, Schema};
+
+use proc_macro2::TokenStream;
+use quote::quote;
 
 use crate::{
     codegen::{
-        api_model::{ApiModel, Ref},
+        api_model::{ApiModel, Ref, Reference, UnknownReference},
         endpoint::Error,
-        find_well_known_type,
+        find_well_known_type, make_ident,
         value::one_of_enum,
-        Item, Scalar, Value,
+        Item, Object, Scalar, Value,
     },
     openapi_compat::is_external,
 };
 
+/// Encoding of a single property of a `multipart/form-data` or `application/x-www-form-urlencoded` request body.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyEncoding {
+    /// Permitted content type(s) of this property's part, as declared by the specification.
+    pub content_type: Option<String>,
+    /// Names of headers which must accompany this property's part, lowercased.
+    pub headers: Vec<String>,
+    /// Serialization style of this property within an `application/x-www-form-urlencoded` body.
+    pub style: Option<QueryStyle>,
+    /// Whether array items of this property are separate form parameters.
+    pub explode: Option<bool>,
+}
+
+impl PropertyEncoding {
+    /// `explode` defaults to `true` for `form` style, and `false` otherwise.
+    pub(crate) fn explode(&self) -> bool {
+        self.explode
+            .unwrap_or(matches!(self.style, None | Some(QueryStyle::Form)))
+    }
+}
+
+impl From<&Encoding> for PropertyEncoding {
+    fn from(encoding: &Encoding) -> Self {
+        let content_type = encoding.content_type.clone();
+        let headers = encoding
+            .headers
+            .iter()
+            .filter(|(_name, header_ref)| {
+                header_ref
+                    .as_item()
+                    .map(|header| header.required)
+                    .unwrap_or_default()
+            })
+            .map(|(name, _header_ref)| name.to_lowercase())
+            .collect();
+        Self {
+            content_type,
+            headers,
+            style: encoding.style.clone(),
+            explode: encoding.explode,
+        }
+    }
+}
+
 fn wrap_err<E: Into<anyhow::Error>>(err: E) -> Error {
     Error::CreateRequestBody(err.into())
 }
@@ -73,9 +120,13 @@ pub(crate) fn convert_optional_schema_ref(
 /// If any schema is defined inline, this returns `false`.
 fn all_content_types_share_schema_def(content: &IndexMap<String, MediaType>) -> bool {
     !content.is_empty() && {
-        let Some(first_schema) = content.first()
-                .and_then(|(_content_type, media_type)| media_type.schema.as_ref())
-                .and_then(|schema_ref| schema_ref.as_ref_str()) else {return false};
+        let Some(first_schema) = content
+            .first()
+            .and_then(|(_content_type, media_type)| media_type.schema.as_ref())
+            .and_then(|schema_ref| schema_ref.as_ref_str())
+        else {
+            return false;
+        };
 
         content.values().all(|value| {
             value
@@ -103,17 +154,26 @@ pub(crate) fn create_request_body(
     let mut item = if request_body.content.len() == 1
         || all_content_types_share_schema_def(&request_body.content)
     {
-        let optional_schema_ref = request_body
-            .content
-            .first()
-            .and_then(|(_content_type, media_type)| media_type.schema.as_ref());
-        convert_optional_schema_ref(
+        let first = request_body.content.first();
+        let optional_schema_ref =
+            first.and_then(|(_content_type, media_type)| media_type.schema.as_ref());
+        let mut item = convert_optional_schema_ref(
             spec,
             model,
             spec_name.to_owned(),
             rust_name,
             optional_schema_ref,
-        )?
+        )?;
+        if let Some((content_type, media_type)) = first {
+            item.content_type = Some(content_type.to_owned());
+            item.encoding = media_type
+                .encoding
+                .iter()
+                .map(|(property, encoding)| (property.to_owned(), encoding.into()))
+                .collect();
+            item.adapt_to_octet_stream();
+        }
+        item
     } else {
         // someone had the ill grace to produce several different request types differentiated by the `content_type`.
         // this means we can't emit a simple item, but have to turn this into a `OneOf` enum.
@@ -179,3 +239,90 @@ pub(crate) fn create_request_body_from_ref(
         }
     }
 }
+
+/// Follow a chain of `Value::Ref`s until reaching an item with a concrete value.
+pub(crate) fn resolve_concrete(
+    model: &ApiModel,
+    mut ref_: Reference,
+) -> Result<&Item, UnknownReference> {
+    loop {
+        let item = model.resolve(ref_)?;
+        match &item.value {
+            Value::Ref(next) => ref_ = *next,
+            _ => return Ok(item),
+        }
+    }
+}
+
+/// Find the object which a request body decodes into.
+///
+/// Returns `None` when the request body is not an object.
+/// The returned name is the name of the emitted object type, which differs from the item name for nullable items.
+pub(crate) fn body_object(
+    model: &ApiModel,
+    request_body: Reference,
+) -> Result<Option<(Reference, &str, &Object)>, UnknownReference> {
+    let mut ref_ = request_body;
+    loop {
+        let item = model.resolve(ref_)?;
+        match &item.value {
+            Value::Ref(next) => ref_ = *next,
+            Value::Object(object) => {
+                let name = item.inner_name.as_deref().unwrap_or(&item.rust_name);
+                return Ok(Some((ref_, name, object)));
+            }
+            _ => return Ok(None),
+        }
+    }
+}
+
+/// Implement `FormEncoding` for the object underlying an `application/x-www-form-urlencoded` request body.
+///
+/// Only array properties whose items are not exploded need to be listed.
+pub(crate) fn impl_form_encoding(
+    model: &ApiModel,
+    request_body: &Item,
+    object_name: &str,
+    object: &Object,
+) -> Result<TokenStream, UnknownReference> {
+    let object_ident = make_ident(object_name);
+    let prefix = quote!(openapi_gen::form);
+
+    let mut properties = Vec::new();
+    for (member_name, member) in object.members.iter() {
+        let Some(encoding) = request_body.encoding.get(member_name) else {
+            continue;
+        };
+        if encoding.explode()
+            || !matches!(
+                resolve_concrete(model, member.definition)?.value,
+                Value::List(_) | Value::Set(_)
+            )
+        {
+            continue;
+        }
+        let style = match encoding.style {
+            Some(QueryStyle::SpaceDelimited) => quote!(SpaceDelimited),
+            Some(QueryStyle::PipeDelimited) => quote!(PipeDelimited),
+            // `deepObject` is only meaningful for object properties; we treat it as `form`
+            Some(QueryStyle::Form) | Some(QueryStyle::DeepObject) | None => {
+                quote!(Form)
+            }
+        };
+        properties.push(quote! {
+            #prefix::PropertyStyle {
+                name: #member_name,
+                style: #prefix::Style::#style,
+                explode: false,
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #prefix::FormEncoding for #object_ident {
+            const PROPERTIES: &'static [#prefix::PropertyStyle] = &[
+                #( #properties ),*
+            ];
+        }
+    })
+}
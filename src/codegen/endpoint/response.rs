@@ -1,15 +1,22 @@
 use anyhow::{anyhow, Context as _};
 use heck::{AsUpperCamelCase, ToSnakeCase, ToUpperCamelCase};
-use openapiv3::{Header, OpenAPI, Operation, ReferenceOr, Response, Responses, Schema, StatusCode};
+use indexmap::IndexMap;
+use openapiv3::{
+    Header, MediaType, OpenAPI, Operation, ReferenceOr, Response, Responses, Schema, StatusCode,
+};
 
 use crate::{
     codegen::{
         api_model::Ref,
-        endpoint::{header::create_header, Error},
+        endpoint::{
+            header::create_header,
+            link::{collect_links, Link},
+            Error,
+        },
         find_well_known_type,
         value::{
             object::{ObjectMember, BODY_IDENT},
-            one_of_enum,
+            one_of_enum::{self, VariantStatus},
         },
         Item, Object, OneOfEnum, Reference, Scalar, UnknownReference,
     },
@@ -22,10 +29,10 @@ fn wrap_err<E: Into<anyhow::Error>>(err: E) -> Error {
     Error::CreateResponse(err.into())
 }
 
-fn status_code(code: &StatusCode) -> Option<http::StatusCode> {
+fn variant_status(code: &StatusCode) -> Option<VariantStatus> {
     match code {
-        StatusCode::Code(n) => http::StatusCode::from_u16(*n).ok(),
-        StatusCode::Range(_) => None,
+        StatusCode::Code(n) => http::StatusCode::from_u16(*n).ok().map(VariantStatus::Code),
+        StatusCode::Range(r) => Some(VariantStatus::Range(*r)),
     }
 }
 
@@ -50,16 +57,16 @@ fn status_name(code: &StatusCode) -> String {
 
 fn named_response_items(
     responses: &Responses,
-) -> impl '_ + Iterator<Item = (String, &ReferenceOr<Response>, Option<http::StatusCode>)> {
+) -> impl '_ + Iterator<Item = (String, &ReferenceOr<Response>, Option<VariantStatus>)> {
     let enumerated_responses = responses
         .responses
         .iter()
-        .map(|(code, response_ref)| (status_name(code), response_ref, status_code(code)));
+        .map(|(code, response_ref)| (status_name(code), response_ref, variant_status(code)));
 
     let default_response = responses
         .default
         .iter()
-        .map(|response_ref| ("Default".into(), response_ref, None));
+        .map(|response_ref| ("Default".into(), response_ref, Some(VariantStatus::Default)));
 
     enumerated_responses.chain(default_response)
 }
@@ -109,6 +116,8 @@ pub(crate) struct ResponseVariant<Ref = Reference> {
     /// Depending on the nature of the response, this might be a bare object for the content type,
     /// or it might be a container object with fields for each of the headers, as well as the response body.
     definition: Ref,
+    /// Named examples of the content of this variant.
+    examples: IndexMap<String, serde_json::Value>,
 }
 
 impl ResponseVariant<Ref> {
@@ -119,6 +128,7 @@ impl ResponseVariant<Ref> {
         let ResponseVariant {
             spec_name,
             definition,
+            examples,
         } = self;
 
         let definition = resolver(&definition)?;
@@ -126,6 +136,7 @@ impl ResponseVariant<Ref> {
         Ok(ResponseVariant {
             spec_name,
             definition,
+            examples,
         })
     }
 }
@@ -133,6 +144,35 @@ impl ResponseVariant<Ref> {
 /// These response variants are the variants associated with a particular resposne.
 pub(crate) type ResponseVariants<Ref = Reference> = Vec<ResponseVariant<Ref>>;
 
+/// Collect the named examples of a media type.
+///
+/// A lone `example` is named `example`. When the media type declares no examples, the example of its schema is
+/// used instead. Examples which only have an `externalValue` are skipped.
+fn media_type_examples(
+    spec: &OpenAPI,
+    media_type: &MediaType,
+) -> Result<IndexMap<String, serde_json::Value>, Error> {
+    let mut examples = IndexMap::new();
+    if let Some(example) = &media_type.example {
+        examples.insert("example".to_owned(), example.clone());
+    }
+    for (name, example_ref) in &media_type.examples {
+        let example = Resolve::resolve(example_ref, spec).map_err(wrap_err)?;
+        if let Some(value) = &example.value {
+            examples.insert(name.clone(), value.clone());
+        }
+    }
+    if examples.is_empty() {
+        if let Some(schema_ref) = media_type.schema.as_ref().filter(|ref_| !is_external(ref_)) {
+            let schema = Resolve::resolve(schema_ref, spec).map_err(wrap_err)?;
+            if let Some(example) = &schema.schema_data.example {
+                examples.insert("example".to_owned(), example.clone());
+            }
+        }
+    }
+    Ok(examples)
+}
+
 /// Make a response object which has fields for each header, and a field for the body.
 fn make_response_object_with_headers_and_body<'a>(
     spec: &OpenAPI,
@@ -212,6 +252,10 @@ pub(crate) fn create_response_variants(
 
         let (content_type, maybe_schema_ref) = maybe_content_and_schema.unzip();
         let content_type = content_type.map(ToOwned::to_owned);
+        let examples = match content_type.as_ref() {
+            Some(content_type) => media_type_examples(spec, &response.content[content_type])?,
+            None => IndexMap::new(),
+        };
 
         let content = match maybe_schema_ref {
             None => {
@@ -267,13 +311,14 @@ pub(crate) fn create_response_variants(
         variants.push(ResponseVariant {
             spec_name: status_name,
             definition,
+            examples,
         });
     }
 
     Ok(variants)
 }
 
-/// Create a responses enum.
+/// Create a responses enum, along with the links which its variants provide.
 ///
 /// This will always produce an enum, no matter how many responses are included.
 pub(crate) fn create_responses(
@@ -281,13 +326,14 @@ pub(crate) fn create_responses(
     model: &mut ApiModel<Ref>,
     spec_name: &str,
     responses: &Responses,
-) -> Result<Ref, Error> {
+) -> Result<(Ref, Vec<Link>), Error> {
     let mut out = OneOfEnum {
         discriminant: Some("status".into()),
         ..Default::default()
     };
+    let mut links = Vec::new();
 
-    for (status_name, response_ref, maybe_status_code) in named_response_items(responses) {
+    for (status_name, response_ref, maybe_status) in named_response_items(responses) {
         // we only need this owned binding in one branch of the following match,
         // but in that case, we need it here for the lifetime
         let variants_owned;
@@ -306,21 +352,34 @@ pub(crate) fn create_responses(
             }
         };
 
+        let first_variant = out.variants.len();
         for ResponseVariant {
             spec_name,
             definition,
+            examples,
         } in variants
         {
             let definition = definition.clone();
             let mapping_name = Some(spec_name.clone());
             let mut variant = one_of_enum::Variant::new(definition, mapping_name);
-            variant.status_code = maybe_status_code;
+            variant.status = maybe_status;
+            variant.examples = examples.clone();
             out.variants.push(variant);
         }
+
+        let response = Resolve::resolve(response_ref, spec).map_err(wrap_err)?;
+        collect_links(
+            spec,
+            model,
+            spec_name,
+            response,
+            first_variant..out.variants.len(),
+            &mut links,
+        )?;
     }
 
     let rust_name = spec_name.to_upper_camel_case();
-    model
+    let ref_ = model
         .add_item(
             Item {
                 spec_name: spec_name.to_owned(),
@@ -330,7 +389,8 @@ pub(crate) fn create_responses(
             },
             None,
         )
-        .map_err(wrap_err)
+        .map_err(wrap_err)?;
+    Ok((ref_, links))
 }
 
 /// `true` if the operation includes any responses distinguished only by content type
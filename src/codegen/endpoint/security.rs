@@ -0,0 +1,308 @@
+use heck::{ToShoutySnakeCase, ToUpperCamelCase};
+use indexmap::IndexMap;
+use openapiv3::{APIKeyLocation, OpenAPI, Operation, SecurityRequirement};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{make_ident, Ref},
+    resolve_trait::Resolve,
+    ApiModel,
+};
+
+use super::{compute_uncased_item_name, Endpoint, Error};
+
+/// Where an API key is presented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyLocation {
+    Header,
+    Query,
+    Cookie,
+}
+
+/// How the credentials of a security scheme are presented.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemeKind {
+    /// An API key in a header, query parameter, or cookie.
+    ApiKey { location: KeyLocation, name: String },
+    /// HTTP `Basic` authentication.
+    Basic,
+    /// HTTP `Bearer` authentication, which also carries OAuth2 and OpenID Connect access tokens.
+    Bearer,
+    /// Any other HTTP authentication scheme, whose credentials are not interpreted.
+    Http { scheme: String },
+}
+
+/// A security scheme declared in `components.securitySchemes`.
+///
+/// Each scheme is emitted as a credential type, which axum support extracts from requests.
+#[derive(Debug, Clone)]
+pub struct SecurityScheme {
+    /// Name of the scheme in the specification.
+    pub name: String,
+    /// Name of the credential type.
+    pub rust_name: String,
+    pub description: Option<String>,
+    pub kind: SchemeKind,
+}
+
+/// The security requirements of an operation.
+#[derive(Debug, Clone)]
+pub struct Security {
+    /// Name of the type of credentials passed to the operation.
+    ///
+    /// When the operation requires exactly one scheme, this is that scheme's credential type. Otherwise, it is
+    /// an enum with one variant per requirement.
+    pub credentials: String,
+    /// `true` when there is a requirement with no schemes, such that credentials are optional.
+    pub anonymous: bool,
+    /// Requirements of the operation, excluding the empty requirement.
+    ///
+    /// Any one of these must be satisfied; within each, every scheme must be satisfied.
+    pub requirements: Vec<SecurityRequirement>,
+}
+
+impl Security {
+    /// `true` when an enum of alternatives must be emitted for these requirements.
+    pub(crate) fn has_credentials_enum(&self) -> bool {
+        !(self.requirements.len() == 1 && self.requirements[0].len() == 1)
+    }
+}
+
+/// Name of the variant of a credentials enum which holds the credentials for a requirement.
+pub(crate) fn requirement_variant_name(
+    model: &ApiModel,
+    requirement: &SecurityRequirement,
+) -> String {
+    requirement
+        .keys()
+        .map(|name| model.security_schemes[name].rust_name.as_str())
+        .collect::<Vec<_>>()
+        .join("And")
+}
+
+/// Collect the security schemes of the specification into the model.
+pub(crate) fn insert_security_schemes(
+    spec: &OpenAPI,
+    model: &mut ApiModel<Ref>,
+) -> Result<(), Error> {
+    let schemes = spec
+        .components
+        .iter()
+        .flat_map(|components| components.security_schemes.iter());
+    for (name, scheme_ref) in schemes {
+        let scheme = Resolve::resolve(scheme_ref, spec).map_err(Error::CreateSecurityScheme)?;
+        let (description, kind) = match scheme {
+            openapiv3::SecurityScheme::APIKey {
+                location,
+                name,
+                description,
+                ..
+            } => {
+                let location = match location {
+                    APIKeyLocation::Header => KeyLocation::Header,
+                    APIKeyLocation::Query => KeyLocation::Query,
+                    APIKeyLocation::Cookie => KeyLocation::Cookie,
+                };
+                let name = name.clone();
+                (description, SchemeKind::ApiKey { location, name })
+            }
+            openapiv3::SecurityScheme::HTTP {
+                scheme,
+                description,
+                ..
+            } => {
+                let kind = match scheme.to_lowercase().as_str() {
+                    "basic" => SchemeKind::Basic,
+                    "bearer" => SchemeKind::Bearer,
+                    _ => SchemeKind::Http {
+                        scheme: scheme.clone(),
+                    },
+                };
+                (description, kind)
+            }
+            openapiv3::SecurityScheme::OAuth2 { description, .. }
+            | openapiv3::SecurityScheme::OpenIDConnect { description, .. } => {
+                (description, SchemeKind::Bearer)
+            }
+        };
+
+        let mut rust_name = name.to_upper_camel_case();
+        model.deconflict_ident(&mut rust_name);
+        while model
+            .security_schemes
+            .values()
+            .any(|scheme| scheme.rust_name == rust_name)
+        {
+            rust_name.push('_');
+        }
+
+        model.security_schemes.insert(
+            name.clone(),
+            SecurityScheme {
+                name: name.clone(),
+                rust_name,
+                description: description.clone(),
+                kind,
+            },
+        );
+    }
+    Ok(())
+}
+
+/// Compute the security requirements of an operation.
+///
+/// The operation's own requirements take precedence over those of the specification. Operations without
+/// any non-empty requirement are not secured.
+pub(crate) fn create_security(
+    spec: &OpenAPI,
+    model: &ApiModel<Ref>,
+    endpoint: &Endpoint<Ref>,
+    operation: &Operation,
+) -> Result<Option<Security>, Error> {
+    let Some(requirements) = operation.security.as_ref().or(spec.security.as_ref()) else {
+        return Ok(None);
+    };
+
+    for name in requirements.iter().flat_map(IndexMap::keys) {
+        if !model.security_schemes.contains_key(name) {
+            return Err(Error::UnknownSecurityScheme(name.clone()));
+        }
+    }
+
+    let anonymous = requirements.iter().any(IndexMap::is_empty);
+    let requirements = requirements
+        .iter()
+        .filter(|requirement| !requirement.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    if requirements.is_empty() {
+        return Ok(None);
+    }
+
+    let mut security = Security {
+        credentials: String::new(),
+        anonymous,
+        requirements,
+    };
+    security.credentials = if security.has_credentials_enum() {
+        let uncased_item_name = compute_uncased_item_name(
+            endpoint.operation_id.as_deref(),
+            endpoint.verb,
+            &endpoint.path,
+            None,
+        );
+        let mut rust_name = format!("{}Credentials", uncased_item_name.to_upper_camel_case());
+        model.deconflict_ident(&mut rust_name);
+        rust_name
+    } else {
+        let name = security.requirements[0]
+            .keys()
+            .next()
+            .expect("requirement is not empty");
+        model.security_schemes[name].rust_name.clone()
+    };
+
+    Ok(Some(security))
+}
+
+impl<R> Endpoint<R> {
+    /// Name of the constant which holds the security requirements of this endpoint.
+    pub(crate) fn security_const_name(&self) -> String {
+        format!(
+            "{}_SECURITY",
+            self.function_name(None).to_shouty_snake_case()
+        )
+    }
+
+    /// The type of the credentials parameter of this endpoint, if it is secured.
+    pub(crate) fn credentials_type(&self) -> Option<TokenStream> {
+        let security = self.security.as_ref()?;
+        let credentials = make_ident(&security.credentials);
+        Some(if security.anonymous {
+            quote!(Option<#credentials>)
+        } else {
+            quote!(#credentials)
+        })
+    }
+}
+
+fn emit_scheme(scheme: &SecurityScheme) -> TokenStream {
+    let mut docs = format!("Credentials of security scheme `{}`.", scheme.name);
+    if let Some(description) = &scheme.description {
+        docs.push_str("\n\n");
+        docs.push_str(description);
+    }
+    let ident = make_ident(&scheme.rust_name);
+    let credentials = match &scheme.kind {
+        SchemeKind::ApiKey { .. } | SchemeKind::Http { .. } => quote!(String),
+        SchemeKind::Basic => quote!(openapi_gen::security::Basic),
+        SchemeKind::Bearer => quote!(openapi_gen::security::Bearer),
+    };
+
+    quote! {
+        #[doc = #docs]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct #ident(pub #credentials);
+    }
+}
+
+fn emit_endpoint_security(model: &ApiModel, endpoint: &Endpoint) -> TokenStream {
+    let Some(security) = &endpoint.security else {
+        return TokenStream::default();
+    };
+    let operation = endpoint
+        .operation_id
+        .clone()
+        .unwrap_or_else(|| format!("{} {}", endpoint.verb, endpoint.path));
+
+    let credentials_enum = security.has_credentials_enum().then(|| {
+        let docs = format!("Credentials which satisfy a security requirement of `{operation}`.");
+        let ident = make_ident(&security.credentials);
+        let variants = security.requirements.iter().map(|requirement| {
+            let variant = make_ident(&requirement_variant_name(model, requirement));
+            let fields = requirement
+                .keys()
+                .map(|name| make_ident(&model.security_schemes[name].rust_name));
+            quote!(#variant( #( #fields ),* ))
+        });
+        quote! {
+            #[doc = #docs]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub enum #ident {
+                #( #variants, )*
+            }
+        }
+    });
+
+    let docs = format!(
+        "Security requirements of `{operation}`.\n\nEach requirement lists security schemes, and the OAuth2 scopes which each must grant."
+    );
+    let const_ident = make_ident(&endpoint.security_const_name());
+    let requirements = security.requirements.iter().map(|requirement| {
+        let schemes = requirement
+            .iter()
+            .map(|(name, scopes)| quote!((#name, &[ #( #scopes ),* ])));
+        quote!(&[ #( #schemes ),* ])
+    });
+
+    quote! {
+        #credentials_enum
+        #[doc = #docs]
+        pub const #const_ident: &[openapi_gen::security::Requirement] = &[ #( #requirements ),* ];
+    }
+}
+
+/// Emit the credential types of all security schemes, and the security requirements of all endpoints.
+pub(crate) fn emit_security(model: &ApiModel) -> TokenStream {
+    let schemes = model.security_schemes.values().map(emit_scheme);
+    let endpoints = model
+        .endpoints
+        .iter()
+        .map(|endpoint| emit_endpoint_security(model, endpoint));
+
+    quote! {
+        #( #schemes )*
+        #( #endpoints )*
+    }
+}
@@ -0,0 +1,170 @@
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use openapiv3::OpenAPI;
+
+use crate::{
+    codegen::{Ref, Reference},
+    ApiModel,
+};
+
+use super::Endpoint;
+
+/// Name of the trait which collects untagged operations, when emitting one trait per tag.
+const DEFAULT_TRAIT: &str = "DefaultApi";
+/// Name of the function which builds a router for untagged operations.
+const DEFAULT_ROUTER: &str = "build_default_router";
+
+/// An operation tag.
+///
+/// When emitting one trait per tag, each tag produces a trait holding the operations whose first tag it is.
+#[derive(Debug, Clone)]
+pub struct Tag {
+    /// Name of the tag in the specification.
+    pub name: String,
+    pub description: Option<String>,
+    /// Name of the trait which holds the operations of this tag.
+    pub trait_name: String,
+    /// Name of the function which builds a router for the trait.
+    pub router: String,
+    /// Name of the `tower::Service` which serves the trait.
+    pub service: String,
+}
+
+impl Tag {
+    /// Derive the names of the items of a tag from `base`, the snake-case form of its name.
+    fn new(name: String, description: Option<String>, base: &str) -> Self {
+        let trait_name = format!("{}Api", base.to_upper_camel_case());
+        let router = format!("build_{base}_router");
+        let service = format!("{trait_name}Service");
+        Self {
+            name,
+            description,
+            trait_name,
+            router,
+            service,
+        }
+    }
+
+    /// `true` when any name derived from this tag is already taken: by an item, by the items of the default
+    /// trait or the combined router, or by another tag.
+    fn conflicts(&self, model: &ApiModel<Ref>) -> bool {
+        let Self {
+            trait_name,
+            router,
+            service,
+            ..
+        } = self;
+        [trait_name, router, service]
+            .into_iter()
+            .any(|name| model.ident_exists(name))
+            || trait_name == DEFAULT_TRAIT
+            || router == DEFAULT_ROUTER
+            || router == "build_router"
+            || model.tags.iter().any(|tag| {
+                &tag.trait_name == trait_name || &tag.router == router || &tag.service == service
+            })
+    }
+}
+
+/// Collect the tags of the specification into the model.
+///
+/// Tags declared at the top level come first, in their declared order. Tags which are used by operations
+/// without being declared follow, in order of first use.
+///
+/// Tags whose names differ only in case or punctuation, or which would otherwise produce names which are
+/// already taken, are distinguished by a numeric suffix.
+pub(crate) fn insert_tags(spec: &OpenAPI, model: &mut ApiModel<Ref>) {
+    let declared = spec
+        .tags
+        .iter()
+        .map(|tag| (tag.name.clone(), tag.description.clone()));
+    let used = model
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| endpoint.tag.clone())
+        .map(|name| (name, None))
+        .collect::<Vec<_>>();
+
+    for (name, description) in declared.chain(used) {
+        if model.tags.iter().any(|tag| tag.name == name) {
+            continue;
+        }
+        let mut base = name.to_snake_case();
+        if !base.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            base.insert_str(0, "tag_");
+        }
+        let mut tag = Tag::new(name.clone(), description.clone(), &base);
+        let mut suffix = 1;
+        while tag.conflicts(model) {
+            suffix += 1;
+            tag = Tag::new(
+                name.clone(),
+                description.clone(),
+                &format!("{base}{suffix}"),
+            );
+        }
+        model.tags.push(tag);
+    }
+}
+
+/// A trait which holds a group of endpoints.
+pub(crate) struct ApiTrait<'a> {
+    /// Name of the trait.
+    pub name: &'a str,
+    /// Name of the function which builds a router for the trait.
+    pub router: &'a str,
+    /// Name of the `tower::Service` which serves the trait.
+    pub service: &'a str,
+    pub docs: Option<&'a str>,
+    pub endpoints: Vec<&'a Endpoint<Reference>>,
+}
+
+impl ApiModel {
+    /// Group the endpoints into the traits which hold them.
+    ///
+    /// Normally this is just `trait Api`. When emitting one trait per tag, it is the trait of each tag which
+    /// has endpoints, followed by `trait DefaultApi` if there are untagged endpoints.
+    pub(crate) fn api_traits(&self) -> Vec<ApiTrait<'_>> {
+        if !self.options.trait_per_tag {
+            return vec![ApiTrait {
+                name: "Api",
+                router: "build_router",
+                service: "ApiService",
+                docs: None,
+                endpoints: self.endpoints.iter().collect(),
+            }];
+        }
+
+        let mut traits = self
+            .tags
+            .iter()
+            .map(|tag| ApiTrait {
+                name: &tag.trait_name,
+                router: &tag.router,
+                service: &tag.service,
+                docs: tag.description.as_deref(),
+                endpoints: self
+                    .endpoints
+                    .iter()
+                    .filter(|endpoint| endpoint.tag.as_ref() == Some(&tag.name))
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        let default = ApiTrait {
+            name: DEFAULT_TRAIT,
+            router: DEFAULT_ROUTER,
+            service: "DefaultApiService",
+            docs: Some("Operations without a tag."),
+            endpoints: self
+                .endpoints
+                .iter()
+                .filter(|endpoint| endpoint.tag.is_none())
+                .collect(),
+        };
+        traits.retain(|api_trait| !api_trait.endpoints.is_empty());
+        // there is always at least one trait, even if it is empty
+        if traits.is_empty() || !default.endpoints.is_empty() {
+            traits.push(default);
+        }
+        traits
+    }
+}
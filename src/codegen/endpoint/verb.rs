@@ -1,4 +1,4 @@
-#[cfg(feature = "axum-support")]
+#[cfg(any(feature = "axum-support", feature = "actix-support"))]
 use proc_macro2::TokenStream;
 
 /// A HTTP Verb
@@ -34,4 +34,17 @@ impl Verb {
 
         quote!(openapi_gen::reexport::axum::routing::#method_ident)
     }
+
+    #[cfg(feature = "actix-support")]
+    pub(crate) fn emit_actix(&self) -> TokenStream {
+        use quote::quote;
+
+        use crate::codegen::make_ident;
+
+        let method_ident = make_ident(&self.to_string());
+
+        quote!(openapi_gen::reexport::actix_web::web::method(
+            openapi_gen::reexport::actix_web::http::Method::#method_ident
+        ))
+    }
 }
@@ -0,0 +1,608 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{json, Value as Json};
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+    codegen::{
+        endpoint::request_body::resolve_concrete,
+        make_ident,
+        value::{
+            object::BODY_IDENT,
+            one_of_enum::{Variant, VariantStatus},
+        },
+        Endpoint, Object, Reference, Scalar, UnknownReference, Value,
+    },
+    serialization_helpers::date_as_string::YMD_FORMAT,
+    ApiModel,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    UnknownReference(#[from] UnknownReference),
+    #[error("value at \"{pointer}\" {reason}")]
+    Mismatch { pointer: String, reason: String },
+    #[error("example \"{example}\" of {function_name} does not match its schema")]
+    Example {
+        function_name: String,
+        example: String,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Nesting depth beyond which synthesized lists are left empty, so that recursive schemas terminate.
+const MAX_DEPTH: usize = 8;
+
+/// Synthesize a JSON value which matches the schema of an item.
+///
+/// Optional members of objects are omitted; everything else gets the simplest value of its type.
+fn synthesize(model: &ApiModel, ref_: Reference, depth: usize) -> Result<Json, UnknownReference> {
+    let item = resolve_concrete(model, ref_)?;
+    let value = match &item.value {
+        Value::Scalar(scalar) => synthesize_scalar(*scalar),
+        Value::StringEnum(string_enum) => string_enum
+            .variants
+            .first()
+            .map_or_else(|| json!("string"), |variant| json!(variant)),
+        Value::OneOfEnum(one_of_enum) => match one_of_enum.variants.first() {
+            None => Json::Null,
+            Some(variant) => {
+                let mut value = synthesize(model, variant.definition, depth + 1)?;
+                if let (Some(discriminant), Json::Object(members)) =
+                    (&one_of_enum.discriminant, &mut value)
+                {
+                    let tag = variant
+                        .mapping_name
+                        .as_deref()
+                        .or_else(|| variant.computed_name())
+                        .unwrap_or_default();
+                    members.insert(discriminant.clone(), json!(tag));
+                }
+                value
+            }
+        },
+        Value::Set(set) if depth < MAX_DEPTH => json!([synthesize(model, set.item, depth + 1)?]),
+        Value::List(list) if depth < MAX_DEPTH => json!([synthesize(model, list.item, depth + 1)?]),
+        Value::Set(_) | Value::List(_) => json!([]),
+        Value::Object(object) => {
+            let mut members = serde_json::Map::new();
+            for (name, member) in &object.members {
+                if !member.inline_option {
+                    members.insert(
+                        name.clone(),
+                        synthesize(model, member.definition, depth + 1)?,
+                    );
+                }
+            }
+            Json::Object(members)
+        }
+        Value::Map(_) => json!({}),
+        Value::PropertyOverride(property_override) => {
+            synthesize(model, property_override.ref_, depth)?
+        }
+        Value::Ref(_) => unreachable!("concrete items are never references"),
+    };
+    Ok(value)
+}
+
+fn synthesize_scalar(scalar: Scalar) -> Json {
+    match scalar {
+        Scalar::Unit | Scalar::Any => Json::Null,
+        Scalar::F64 | Scalar::F32 => json!(0.0),
+        Scalar::I64 | Scalar::I32 | Scalar::U64 | Scalar::U32 => json!(0),
+        Scalar::String => json!("string"),
+        Scalar::Binary | Scalar::OctetStream => json!(""),
+        #[cfg(feature = "bytes")]
+        Scalar::Bytes => json!(""),
+        Scalar::Date => json!("1970-01-01"),
+        Scalar::DateTime => json!("1970-01-01T00:00:00Z"),
+        Scalar::IpAddr | Scalar::Ipv4Addr => json!("127.0.0.1"),
+        Scalar::Ipv6Addr => json!("::1"),
+        #[cfg(feature = "uuid")]
+        Scalar::Uuid => json!("00000000-0000-0000-0000-000000000000"),
+        Scalar::Bool => json!(false),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedI32(min, max) => json!(min.max(0).min(max)),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedI64(min, max) => json!(min.max(0).min(max)),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedU32(min, _max) => json!(min),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedU64(min, _max) => json!(min),
+        #[cfg(feature = "api-problem")]
+        Scalar::ApiProblem => json!({}),
+        Scalar::Mime => json!("application/octet-stream"),
+        Scalar::AcceptHeader => json!("*/*"),
+    }
+}
+
+/// Check a declared example of an endpoint's response, naming the example if it does not match.
+fn check_example(
+    model: &ApiModel,
+    function_name: &str,
+    example_name: &str,
+    ref_: Reference,
+    example: &Json,
+) -> Result<(), Error> {
+    check(model, ref_, example, "").map_err(|source| Error::Example {
+        function_name: function_name.to_owned(),
+        example: example_name.to_owned(),
+        source: Box::new(source),
+    })
+}
+
+/// Check that a declared example matches the schema of an item, so that decoding it cannot fail at runtime.
+///
+/// This mirrors what deserialization requires: the JSON type of each value, the variants of string enums, the
+/// required members of objects, and a matching variant of each `oneOf`. `pointer` locates `example` within the
+/// whole example.
+fn check(model: &ApiModel, ref_: Reference, example: &Json, pointer: &str) -> Result<(), Error> {
+    let item = model.resolve(ref_)?;
+    let concrete = resolve_concrete(model, ref_)?;
+    if example.is_null() && (item.nullable || concrete.nullable) {
+        return Ok(());
+    }
+    let mismatch = |reason: String| Error::Mismatch {
+        pointer: pointer.to_owned(),
+        reason,
+    };
+
+    match &concrete.value {
+        Value::Scalar(scalar) => check_scalar(*scalar, example).map_err(mismatch),
+        Value::StringEnum(string_enum) => match example.as_str() {
+            Some(variant)
+                if string_enum.extensible || string_enum.variants.iter().any(|v| v == variant) =>
+            {
+                Ok(())
+            }
+            _ => Err(mismatch(format!(
+                "is not one of {}",
+                string_enum.variants.join(", ")
+            ))),
+        },
+        Value::OneOfEnum(one_of_enum) => {
+            if let Some(discriminant) = &one_of_enum.discriminant {
+                let tag = example
+                    .get(discriminant)
+                    .and_then(Json::as_str)
+                    .ok_or_else(|| mismatch(format!("has no discriminant \"{discriminant}\"")))?;
+                let variant = one_of_enum
+                    .variants
+                    .iter()
+                    .find(|variant| {
+                        variant
+                            .mapping_name
+                            .as_deref()
+                            .or_else(|| variant.computed_name())
+                            == Some(tag)
+                    })
+                    .ok_or_else(|| mismatch(format!("names unknown variant \"{tag}\"")))?;
+                check(model, variant.definition, example, pointer)
+            } else if one_of_enum
+                .variants
+                .iter()
+                .any(|variant| check(model, variant.definition, example, pointer).is_ok())
+            {
+                Ok(())
+            } else {
+                Err(mismatch("matches no variant".to_owned()))
+            }
+        }
+        Value::Set(_) | Value::List(_) => {
+            let item = match &concrete.value {
+                Value::Set(set) => set.item,
+                Value::List(list) => list.item,
+                _ => unreachable!(),
+            };
+            let items = example
+                .as_array()
+                .ok_or_else(|| mismatch("is not an array".to_owned()))?;
+            for (idx, value) in items.iter().enumerate() {
+                check(model, item, value, &format!("{pointer}/{idx}"))?;
+            }
+            Ok(())
+        }
+        Value::Object(object) => {
+            let members = example
+                .as_object()
+                .ok_or_else(|| mismatch("is not an object".to_owned()))?;
+            for (name, member) in &object.members {
+                let member_pointer = format!("{pointer}/{name}");
+                match members.get(name) {
+                    Some(Json::Null) if member.inline_option => {}
+                    Some(value) => check(model, member.definition, value, &member_pointer)?,
+                    None if member.inline_option => {}
+                    // serde accepts a missing member which is nullable, unless it is validated
+                    None if model.resolve(member.definition)?.nullable
+                        && !model.options.validate => {}
+                    None => return Err(mismatch(format!("has no required member \"{name}\""))),
+                }
+            }
+            Ok(())
+        }
+        Value::Map(map) => {
+            let members = example
+                .as_object()
+                .ok_or_else(|| mismatch("is not an object".to_owned()))?;
+            if let Some(value_type) = map.value_type {
+                for (name, value) in members {
+                    check(model, value_type, value, &format!("{pointer}/{name}"))?;
+                }
+            }
+            Ok(())
+        }
+        Value::PropertyOverride(property_override) => {
+            check(model, property_override.ref_, example, pointer)
+        }
+        Value::Ref(_) => unreachable!("concrete items are never references"),
+    }
+}
+
+/// Check that an example matches a scalar type, returning the reason if it does not.
+fn check_scalar(scalar: Scalar, example: &Json) -> Result<(), String> {
+    fn parses<T: std::str::FromStr>(example: &Json) -> bool {
+        example
+            .as_str()
+            .is_some_and(|text| text.parse::<T>().is_ok())
+    }
+
+    let (matches, expected) = match scalar {
+        Scalar::Unit => (example.is_null(), "null"),
+        // raw bytes are never decoded from JSON
+        Scalar::Any | Scalar::Binary | Scalar::OctetStream => (true, ""),
+        #[cfg(feature = "bytes")]
+        Scalar::Bytes => (true, ""),
+        Scalar::F64 | Scalar::F32 => (example.is_number(), "a number"),
+        Scalar::I64 | Scalar::I32 => (example.is_i64(), "an integer"),
+        Scalar::U64 | Scalar::U32 => (example.is_u64(), "a non-negative integer"),
+        Scalar::String | Scalar::AcceptHeader => (example.is_string(), "a string"),
+        Scalar::Date => (
+            example
+                .as_str()
+                .is_some_and(|text| time::Date::parse(text, YMD_FORMAT).is_ok()),
+            "a date",
+        ),
+        Scalar::DateTime => (
+            example
+                .as_str()
+                .is_some_and(|text| time::OffsetDateTime::parse(text, &Rfc3339).is_ok()),
+            "an RFC 3339 date-time",
+        ),
+        Scalar::IpAddr => (parses::<std::net::IpAddr>(example), "an IP address"),
+        Scalar::Ipv4Addr => (parses::<std::net::Ipv4Addr>(example), "an IPv4 address"),
+        Scalar::Ipv6Addr => (parses::<std::net::Ipv6Addr>(example), "an IPv6 address"),
+        #[cfg(feature = "uuid")]
+        Scalar::Uuid => (parses::<uuid::Uuid>(example), "a UUID"),
+        Scalar::Bool => (example.is_boolean(), "a boolean"),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedI32(min, max) => (
+            example
+                .as_i64()
+                .is_some_and(|n| (i64::from(min)..=i64::from(max)).contains(&n)),
+            "an integer within its bounds",
+        ),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedI64(min, max) => (
+            example.as_i64().is_some_and(|n| (min..=max).contains(&n)),
+            "an integer within its bounds",
+        ),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedU32(min, max) => (
+            example
+                .as_u64()
+                .is_some_and(|n| (u64::from(min)..=u64::from(max)).contains(&n)),
+            "an integer within its bounds",
+        ),
+        #[cfg(feature = "integer-restrictions")]
+        Scalar::BoundedU64(min, max) => (
+            example.as_u64().is_some_and(|n| (min..=max).contains(&n)),
+            "an integer within its bounds",
+        ),
+        #[cfg(feature = "api-problem")]
+        Scalar::ApiProblem => (example.is_object(), "an object"),
+        Scalar::Mime => (parses::<mime::Mime>(example), "a media type"),
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(format!("is not {expected}"))
+    }
+}
+
+/// Emit an expression which produces the body of a response from an example.
+fn emit_body(
+    model: &ApiModel,
+    function_name: &str,
+    example_name: &str,
+    body: Reference,
+    example: &Json,
+) -> Result<TokenStream, Error> {
+    let item = model.resolve(body)?;
+    let concrete = resolve_concrete(model, body)?;
+    let expression = match &concrete.value {
+        Value::Scalar(Scalar::Unit) => quote!(()),
+        Value::Scalar(Scalar::Binary | Scalar::OctetStream) if !item.nullable => {
+            let text = match example {
+                Json::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            quote!(openapi_gen::example::bytes(#text))
+        }
+        _ => {
+            let json = example.to_string();
+            quote!(openapi_gen::example::decode(#function_name, #example_name, #json))
+        }
+    };
+    Ok(expression)
+}
+
+/// Emit an expression which produces a variant of a response enum from an example.
+fn emit_variant(
+    model: &ApiModel,
+    function_name: &str,
+    response: &TokenStream,
+    variant_name: &str,
+    variant: &Variant,
+    example_name: &str,
+    example: Option<&Json>,
+) -> Result<TokenStream, Error> {
+    let definition = variant.definition;
+    let item = model.resolve(definition)?;
+    let content = match &item.value {
+        Value::Object(Object {
+            is_generated_body_and_headers: true,
+            members,
+        }) => {
+            let mut fields = Vec::with_capacity(members.len());
+            for (member_name, member) in members {
+                let member_ident = make_ident(member_name);
+                let value = if member_name == BODY_IDENT {
+                    let example = match example {
+                        Some(example) => {
+                            check_example(
+                                model,
+                                function_name,
+                                example_name,
+                                member.definition,
+                                example,
+                            )?;
+                            example.clone()
+                        }
+                        None => synthesize(model, member.definition, 0)?,
+                    };
+                    emit_body(
+                        model,
+                        function_name,
+                        example_name,
+                        member.definition,
+                        &example,
+                    )?
+                } else {
+                    let json = synthesize(model, member.definition, 0)?.to_string();
+                    quote!(openapi_gen::example::decode(#function_name, #member_name, #json))
+                };
+                fields.push(quote!(#member_ident: #value));
+            }
+            let item_ident = make_ident(&item.rust_name);
+            quote!(#item_ident { #( #fields ),* })
+        }
+        _ => {
+            let example = match example {
+                Some(example) => {
+                    check_example(model, function_name, example_name, definition, example)?;
+                    example.clone()
+                }
+                None => synthesize(model, definition, 0)?,
+            };
+            emit_body(model, function_name, example_name, definition, &example)?
+        }
+    };
+
+    let content = match variant.status {
+        Some(VariantStatus::Range(class)) => {
+            let status = class * 100;
+            quote!(openapi_gen::example::with_status(#status, #content))
+        }
+        Some(VariantStatus::Default) => quote!(openapi_gen::example::with_status(200, #content)),
+        Some(VariantStatus::Code(_)) | None => content,
+    };
+
+    let variant_ident = make_ident(variant_name);
+    Ok(quote!(#response::#variant_ident(#content)))
+}
+
+/// The status code with which a variant is sent by default, for choosing the default example.
+fn default_status(status: Option<VariantStatus>) -> u16 {
+    match status {
+        Some(VariantStatus::Code(status)) => status.as_u16(),
+        Some(VariantStatus::Range(class)) => class * 100,
+        Some(VariantStatus::Default) | None => 200,
+    }
+}
+
+/// Implement a single endpoint for `ExampleApi`.
+fn example_endpoint<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    endpoint: &Endpoint,
+) -> Result<TokenStream, Error> {
+    let function_name = endpoint.function_name(None);
+    let ident = make_ident(&function_name);
+
+    let mut parameters = Vec::new();
+    if let Some(type_name) = endpoint.credentials_type() {
+        parameters.push(quote!(credentials: #type_name));
+    }
+    for (name, ref_, required) in endpoint.function_parameters(model)? {
+        let param_name = make_ident(&name);
+        let mut type_name = model.definition(ref_, &name_resolver)?;
+        if !required {
+            type_name = quote!(Option< #type_name >);
+        }
+        parameters.push(quote!(#param_name: #type_name));
+    }
+    if let Some(ref_) = endpoint.request_body {
+        let type_name = model.definition(ref_, &name_resolver)?;
+        parameters.push(quote!(request_body: #type_name));
+    }
+
+    let response = model.definition(endpoint.response, &name_resolver)?;
+    let response_item = model.resolve(endpoint.response)?;
+    let Value::OneOfEnum(one_of_enum) = &response_item.value else {
+        return Err(UnknownReference(format!("response of {function_name} is not an enum")).into());
+    };
+
+    // the lowest success status, else the first declared variant
+    let default_variant = one_of_enum
+        .variants
+        .iter()
+        .enumerate()
+        .filter(|(_, variant)| (200..300).contains(&default_status(variant.status)))
+        .min_by_key(|(idx, variant)| (default_status(variant.status), *idx))
+        .or_else(|| one_of_enum.variants.iter().enumerate().next())
+        .map(|(idx, _)| idx);
+
+    let mut arms = Vec::new();
+    let mut seen = Vec::new();
+    let mut default = None;
+    for (idx, variant) in one_of_enum.variants.iter().enumerate() {
+        let variant_name = variant.computed_name().ok_or_else(|| {
+            UnknownReference(format!(
+                "computed name of a variant of {}",
+                response_item.rust_name
+            ))
+        })?;
+        let emit = |example_name: &str, example: Option<&Json>| {
+            emit_variant(
+                model,
+                &function_name,
+                &response,
+                variant_name,
+                variant,
+                example_name,
+                example,
+            )
+            // examples never fail
+            .map(|expression| {
+                if model.options.is_fallible() {
+                    quote!(Ok(#expression))
+                } else {
+                    expression
+                }
+            })
+        };
+
+        for (example_name, example) in &variant.examples {
+            if seen.contains(&example_name) {
+                continue;
+            }
+            seen.push(example_name);
+            let expression = emit(example_name, Some(example))?;
+            arms.push(quote!(Some(#example_name) => #expression,));
+        }
+
+        if Some(idx) == default_variant {
+            default = Some(match variant.examples.first() {
+                Some((example_name, example)) => emit(example_name, Some(example))?,
+                None => emit("synthesized", None)?,
+            });
+        }
+    }
+    let default =
+        default.unwrap_or_else(|| quote!(unreachable!("{} has no responses", #function_name)));
+
+    let body = if arms.is_empty() {
+        default
+    } else {
+        quote! {
+            match openapi_gen::example::preferred().as_deref() {
+                #( #arms )*
+                _ => #default,
+            }
+        }
+    };
+
+    let context = model
+        .options
+        .context
+        .then(|| quote!(context: Self::Context,));
+    let response = if model.options.is_fallible() {
+        quote!(Result<#response, Self::Error>)
+    } else {
+        response
+    };
+
+    Ok(quote! {
+        async fn #ident(&self, #context #( #parameters, )*) -> #response {
+            #body
+        }
+    })
+}
+
+/// Emit `struct ExampleApi`, which implements every API trait by responding with examples.
+///
+/// Emits nothing unless the `examples` option is set.
+pub(crate) fn emit_example_api<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    if !model.options.examples {
+        return Ok(TokenStream::default());
+    }
+
+    let api_traits = model.api_traits();
+    let mut trait_impls = Vec::with_capacity(api_traits.len());
+    for api_trait in &api_traits {
+        let implementations = api_trait
+            .endpoints
+            .iter()
+            .map(|endpoint| example_endpoint(model, &name_resolver, endpoint))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let trait_ident = make_ident(api_trait.name);
+        let context = model.options.context.then(|| {
+            quote!(
+                type Context = ();
+            )
+        });
+        let error = model.options.is_fallible().then(|| {
+            quote!(
+                type Error = ::std::convert::Infallible;
+            )
+        });
+        let async_trait = model.async_trait_attribute();
+        trait_impls.push(quote! {
+            #async_trait
+            #[allow(unused_variables)]
+            impl #trait_ident for ExampleApi {
+                #context
+                #error
+                #( #implementations )*
+            }
+        });
+    }
+
+    let subject = if model.options.trait_per_tag {
+        "every API trait".to_owned()
+    } else {
+        let name = api_traits.first().map_or("Api", |api_trait| api_trait.name);
+        format!("[`trait {name}`][{name}]")
+    };
+    let docs = format!(
+        " An implementation of {subject} which responds with the examples of the specification."
+    );
+
+    Ok(quote! {
+        #[doc = #docs]
+        ///
+        /// Each operation responds with the first example of its lowest success response, or with a value
+        /// synthesized from its schema when it declares no examples. Requests select another example by name
+        /// with the header `Prefer: example=name`; see [`openapi_gen::example`].
+        #[derive(Debug, Default, Clone, Copy)]
+        pub struct ExampleApi;
+
+        #( #trait_impls )*
+    })
+}
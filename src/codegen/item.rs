@@ -1,6 +1,7 @@
 use std::fmt;
 
 use heck::ToUpperCamelCase;
+use indexmap::IndexMap;
 use openapiv3::{ObjectType, OpenAPI, Schema, SchemaKind, Type};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -8,13 +9,13 @@ use serde::Deserialize;
 
 use crate::{
     codegen::{
-        make_ident, ApiModel, PropertyOverride, Ref, Reference, Scalar, UnknownReference, Value,
-        ValueConversionError,
+        make_ident, ApiModel, Constraints, PropertyOverride, Ref, Reference, Scalar,
+        UnknownReference, Value, ValueConversionError,
     },
     resolve_trait::Resolve,
 };
 
-use super::{api_model::AsBackref, OneOfEnum, StringEnum};
+use super::{api_model::AsBackref, OneOfEnum, PropertyEncoding, StringEnum};
 
 // note: openapiv3 intentionally ignores extensions whose name does not start with "x-".
 fn get_extension_value<'a>(schema: &'a Schema, key: &str) -> Option<&'a serde_json::Value> {
@@ -143,8 +144,15 @@ pub struct Item<Ref = Reference> {
     /// For items defined in `components/schemas` and defined inline within other item definitions,
     /// this should be unset.
     pub content_type: Option<String>,
+    /// Per-property encodings of this item, as declared by the `encoding` object of its media type.
+    ///
+    /// This is only ever non-empty for request bodies with a `multipart/form-data` or
+    /// `application/x-www-form-urlencoded` content type.
+    pub encoding: IndexMap<String, PropertyEncoding>,
     /// When true, we should `impl headers::Header` for this item.
     pub impl_header: bool,
+    /// Constraints of the schema which are not encoded in the Rust type.
+    pub constraints: Constraints,
 }
 
 impl<R> Default for Item<R> {
@@ -159,7 +167,9 @@ impl<R> Default for Item<R> {
             nullable: Default::default(),
             value: Default::default(),
             content_type: Default::default(),
+            encoding: Default::default(),
             impl_header: Default::default(),
+            constraints: Default::default(),
         }
     }
 }
@@ -177,6 +187,63 @@ where
     }
 }
 
+impl<R> Item<R> {
+    /// `true` when the item is probably json
+    #[allow(dead_code)]
+    pub(crate) fn is_json(&self) -> bool {
+        // cast to bytes in case it's not ascii, so we don't have an indexing panic
+        let content_type = self.content_type.as_deref().unwrap_or("json").as_bytes();
+        // re-subslice the string to get the trailing four bytes
+        let content_type = &content_type[content_type.len().checked_sub(4).unwrap_or_default()..];
+        content_type.eq_ignore_ascii_case(b"json")
+    }
+
+    /// `true` when the item's content type, without parameters, is `essence`
+    fn has_content_type(&self, essence: &str) -> bool {
+        self.content_type
+            .as_deref()
+            .and_then(|content_type| content_type.split(';').next())
+            .map(|content_type| content_type.trim().eq_ignore_ascii_case(essence))
+            .unwrap_or_default()
+    }
+
+    /// `true` when the item is a `multipart/form-data` body
+    pub(crate) fn is_multipart_form_data(&self) -> bool {
+        self.has_content_type("multipart/form-data")
+    }
+
+    /// `true` when the item is an `application/x-www-form-urlencoded` body
+    pub(crate) fn is_form_urlencoded(&self) -> bool {
+        self.has_content_type("application/x-www-form-urlencoded")
+    }
+
+    /// `true` when the item is an `application/octet-stream` body
+    pub(crate) fn is_octet_stream(&self) -> bool {
+        self.has_content_type("application/octet-stream")
+    }
+
+    /// `true` when the item is a `text/*` body
+    pub(crate) fn is_text(&self) -> bool {
+        self.content_type
+            .as_deref()
+            .and_then(|content_type| content_type.split(';').next())
+            .and_then(|content_type| content_type.trim().get(..5))
+            .map(|prefix| prefix.eq_ignore_ascii_case("text/"))
+            .unwrap_or_default()
+    }
+
+    /// Represent binary `application/octet-stream` bodies as raw bytes.
+    ///
+    /// This should be called after the item's content type is set.
+    pub(crate) fn adapt_to_octet_stream(&mut self) {
+        if self.is_octet_stream()
+            && matches!(self.value, Value::Scalar(Scalar::Binary | Scalar::Any))
+        {
+            self.value = Value::Scalar(Scalar::OctetStream);
+        }
+    }
+}
+
 impl Item<Ref> {
     pub(crate) fn resolve_refs(
         self,
@@ -192,7 +259,9 @@ impl Item<Ref> {
             nullable,
             value,
             content_type,
+            encoding,
             impl_header,
+            constraints,
         } = self;
         let value = value.resolve_refs(resolver)?;
         Ok(Item {
@@ -205,7 +274,9 @@ impl Item<Ref> {
             nullable,
             value,
             content_type,
+            encoding,
             impl_header,
+            constraints,
         })
     }
 
@@ -296,7 +367,7 @@ impl Item<Ref> {
             (rust_name, None)
         };
 
-        Ok(Self {
+        let mut item = Self {
             docs,
             spec_name,
             rust_name,
@@ -306,8 +377,12 @@ impl Item<Ref> {
             nullable,
             value,
             content_type,
+            encoding: IndexMap::new(),
             impl_header: false,
-        })
+            constraints: Constraints::from_schema_kind(&schema.schema_kind)?,
+        };
+        item.adapt_to_octet_stream();
+        Ok(item)
     }
 }
 
@@ -328,14 +403,24 @@ impl Item {
             }
     }
 
-    /// `true` when the item is probably json
-    #[allow(dead_code)]
-    pub(crate) fn is_json(&self) -> bool {
-        // cast to bytes in case it's not ascii, so we don't have an indexing panic
-        let content_type = self.content_type.as_deref().unwrap_or("json").as_bytes();
-        // re-subslice the string to get the trailing four bytes
-        let content_type = &content_type[content_type.len().checked_sub(4).unwrap_or_default()..];
-        content_type.eq_ignore_ascii_case(b"json")
+    /// `true` when the item is a `text/*` body whose value can be represented by its canonical form
+    pub(crate) fn is_canonical_text(&self, model: &ApiModel) -> bool {
+        self.is_text() && !self.nullable && self.is_canonical_scalar(model)
+    }
+
+    /// `true` when the item's value, disregarding the item's own nullability, is a scalar with a canonical form
+    pub(crate) fn is_canonical_scalar(&self, model: &ApiModel) -> bool {
+        let mut item = self;
+        loop {
+            match &item.value {
+                Value::Scalar(scalar) => return scalar.impls_canonical_form(),
+                Value::Ref(ref_) => match model.resolve(*ref_) {
+                    Ok(next) if !next.nullable => item = next,
+                    _ => return false,
+                },
+                _ => return false,
+            }
+        }
     }
 
     /// Is this item public?
@@ -539,6 +624,13 @@ pub enum ParseItemError {
     ExternalDocumentation(#[source] reqwest::Error),
     #[error("failed to construct `allOf` singleton")]
     AllOfSingleton(#[source] anyhow::Error),
+    #[cfg(feature = "string-pattern")]
+    #[error("`pattern` is not a valid regular expression: {pattern}")]
+    Pattern {
+        pattern: String,
+        #[source]
+        source: regress::Error,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,255 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{make_ident, Endpoint, Reference, UnknownReference},
+    ApiModel,
+};
+
+/// The mock of a single endpoint: a field of `MockApi`, and the methods which use it.
+fn mock_endpoint<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    api_trait: &str,
+    endpoint: &Endpoint,
+) -> Result<(TokenStream, TokenStream, TokenStream, TokenStream), UnknownReference> {
+    let function_name = endpoint.function_name(None);
+    let ident = make_ident(&function_name);
+
+    let mut parameters = Vec::new();
+    let mut types = Vec::new();
+    let mut arguments = Vec::new();
+
+    if let Some(type_name) = endpoint.credentials_type() {
+        parameters.push(quote!(credentials: #type_name));
+        types.push(type_name);
+        arguments.push(make_ident("credentials"));
+    }
+
+    for (name, ref_, required) in endpoint.function_parameters(model)? {
+        let param_name = make_ident(&name);
+        let mut type_name = model.definition(ref_, &name_resolver)?;
+        if !required {
+            type_name = quote!(Option< #type_name >);
+        }
+        parameters.push(quote!(#param_name: #type_name));
+        types.push(type_name);
+        arguments.push(param_name);
+    }
+
+    if let Some(ref_) = endpoint.request_body {
+        let type_name = model.definition(ref_, &name_resolver)?;
+        parameters.push(quote!(request_body: #type_name));
+        types.push(type_name);
+        arguments.push(make_ident("request_body"));
+    }
+
+    let response = model.definition(endpoint.response, &name_resolver)?;
+    // fallible mocks are programmed with the whole result, so that they can fail
+    let output = if model.options.is_fallible() {
+        quote!(Result<#response, E>)
+    } else {
+        response.clone()
+    };
+    let mock = quote!(openapi_gen::mock::Mock<( #( #types, )* ), #output>);
+    let expectation = quote!(openapi_gen::mock::Expectation<( #( #types, )* ), #output>);
+    let calls = quote!(openapi_gen::mock::Calls<'_, ( #( #types, )* ), #output>);
+
+    let field = quote!(#ident: #mock,);
+    let init = quote!(#ident: openapi_gen::mock::Mock::new(#function_name),);
+
+    let expect_ident = make_ident(&format!("expect_{function_name}"));
+    let calls_ident = make_ident(&format!("{function_name}_calls"));
+    let expect_docs = format!(" Expect a call to [`{api_trait}::{function_name}`].");
+    let calls_docs = format!(" The calls to [`{api_trait}::{function_name}`] so far, in order.");
+    let methods = quote! {
+        #[doc = #expect_docs]
+        pub fn #expect_ident(&mut self) -> &mut #expectation {
+            self.#ident.expect()
+        }
+
+        #[doc = #calls_docs]
+        pub fn #calls_ident(&self) -> #calls {
+            self.#ident.calls()
+        }
+    };
+
+    // the mock has no context of its own, so it is not recorded
+    let context = model
+        .options
+        .context
+        .then(|| quote!(_context: Self::Context,));
+    let call = quote!(self.#ident.call(( #( #arguments, )* )));
+    let implementation = if model.options.is_fallible() {
+        quote! {
+            async fn #ident(&self, #context #( #parameters, )*) -> Result<#response, Self::Error> {
+                #call
+            }
+        }
+    } else {
+        quote! {
+            async fn #ident(&self, #context #( #parameters, )*) -> #response {
+                #call
+            }
+        }
+    };
+
+    Ok((field, init, methods, implementation))
+}
+
+/// Emit `struct MockApi`, a programmable implementation of every API trait.
+///
+/// Emits nothing unless the `mock` option is set.
+pub(crate) fn emit_mock<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, UnknownReference> {
+    if !model.options.mock {
+        return Ok(TokenStream::default());
+    }
+
+    let api_traits = model.api_traits();
+    let mut fields = Vec::with_capacity(model.endpoints.len());
+    let mut inits = Vec::with_capacity(model.endpoints.len());
+    let mut methods = Vec::with_capacity(model.endpoints.len());
+    let mut trait_impls = Vec::with_capacity(api_traits.len());
+    let mut checkpoints = Vec::with_capacity(model.endpoints.len());
+
+    for api_trait in &api_traits {
+        let mut implementations = Vec::with_capacity(api_trait.endpoints.len());
+        for endpoint in &api_trait.endpoints {
+            let (field, init, endpoint_methods, implementation) =
+                mock_endpoint(model, &name_resolver, api_trait.name, endpoint)?;
+            fields.push(field);
+            inits.push(init);
+            methods.push(endpoint_methods);
+            implementations.push(implementation);
+
+            let ident = make_ident(&endpoint.function_name(None));
+            checkpoints.push(quote!(self.#ident.checkpoint();));
+        }
+
+        let trait_ident = make_ident(api_trait.name);
+        let context = model.options.context.then(|| {
+            quote!(
+                type Context = ();
+            )
+        });
+        let async_trait = model.async_trait_attribute();
+        if model.options.is_fallible() {
+            trait_impls.push(quote! {
+                #async_trait
+                impl<E> #trait_ident for MockApi<E>
+                where
+                    E: Send + 'static,
+                {
+                    #context
+                    type Error = E;
+                    #( #implementations )*
+                }
+            });
+        } else {
+            trait_impls.push(quote! {
+                #async_trait
+                impl #trait_ident for MockApi {
+                    #context
+                    #( #implementations )*
+                }
+            });
+        }
+    }
+
+    let subject = if model.options.trait_per_tag {
+        "every API trait".to_owned()
+    } else {
+        let name = api_traits.first().map_or("Api", |api_trait| api_trait.name);
+        format!("[`trait {name}`][{name}]")
+    };
+    let docs = format!(" A programmable mock implementation of {subject}.");
+
+    let checkpoint = quote! {
+        /// Verify that every expectation received its expected number of calls, then remove all expectations.
+        ///
+        /// # Panics
+        ///
+        /// If any expectation is not satisfied.
+        pub fn checkpoint(&mut self) {
+            #( #checkpoints )*
+        }
+    };
+
+    if model.options.is_fallible() {
+        return Ok(quote! {
+            #[doc = #docs]
+            ///
+            /// Set up the calls which each operation expects with its `expect_` method. Each call is answered by the
+            /// first expectation which matches it, and recorded; calls which match no expectation panic. Expected call
+            /// counts are verified by [`MockApi::checkpoint`], and when the mock is dropped.
+            ///
+            /// Expectations produce the whole result of their operation, so they can also fail with `E`.
+            #[derive(Debug)]
+            pub struct MockApi<E = ::std::convert::Infallible> {
+                #( #fields )*
+            }
+
+            impl<E> Default for MockApi<E> {
+                fn default() -> Self {
+                    Self {
+                        #( #inits )*
+                    }
+                }
+            }
+
+            impl MockApi {
+                /// Create a mock with no expectations, which fails with [`Infallible`][::std::convert::Infallible].
+                ///
+                /// To stub failures, create the mock with another error instead, for example with
+                /// `MockApi::<MyError>::default()`.
+                pub fn new() -> Self {
+                    Self::default()
+                }
+            }
+
+            impl<E> MockApi<E> {
+                #( #methods )*
+
+                #checkpoint
+            }
+
+            #( #trait_impls )*
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #docs]
+        ///
+        /// Set up the calls which each operation expects with its `expect_` method. Each call is answered by the
+        /// first expectation which matches it, and recorded; calls which match no expectation panic. Expected call
+        /// counts are verified by [`MockApi::checkpoint`], and when the mock is dropped.
+        #[derive(Debug)]
+        pub struct MockApi {
+            #( #fields )*
+        }
+
+        impl Default for MockApi {
+            fn default() -> Self {
+                Self {
+                    #( #inits )*
+                }
+            }
+        }
+
+        impl MockApi {
+            /// Create a mock with no expectations.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #( #methods )*
+
+            #checkpoint
+        }
+
+        #( #trait_impls )*
+    })
+}
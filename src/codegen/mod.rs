@@ -3,17 +3,27 @@
 //! maps to our output types. This module contains the definitions for that model.
 
 pub(crate) mod api_model;
+pub(crate) mod constraints;
 pub(crate) mod endpoint;
+pub(crate) mod example;
 pub(crate) mod item;
+pub(crate) mod mock;
+pub(crate) mod module_tree;
+pub(crate) mod options;
 pub(crate) mod rust_keywords;
+pub(crate) mod server;
+pub(crate) mod validation;
 pub(crate) mod value;
 pub(crate) mod well_known_types;
 
 pub(crate) use api_model::Ref;
 pub use {
     api_model::{ApiModel, Error, Reference, UnknownReference},
-    endpoint::Endpoint,
+    constraints::Constraints,
+    endpoint::{request_body::PropertyEncoding, Endpoint},
     item::Item,
+    module_tree::ModuleFile,
+    options::Options,
     value::{
         list::List, map::Map, object::Object, one_of_enum::OneOfEnum,
         property_override::PropertyOverride, scalar::Scalar, set::Set, string_enum::StringEnum,
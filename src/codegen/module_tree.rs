@@ -0,0 +1,379 @@
+//! Emit generated code as a tree of modules instead of a single file.
+//!
+//! Models, parameters, request bodies, and responses are directories with one module per model, or per
+//! operation. Every module re-exports the public items of its children, so the root module exposes the same
+//! names as the single-file output. Each leaf module imports the items it uses from the root module.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use heck::ToSnakeCase;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+use crate::{
+    codegen::{
+        api_model::{parse_file, pretty_print, AsBackref, Sections},
+        make_ident, Endpoint,
+    },
+    ApiModel, Error,
+};
+
+/// Module which holds untagged endpoint items, when grouping per tag.
+const DEFAULT_GROUP: &str = "default";
+
+/// A file of generated code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleFile {
+    /// Path of the file, relative to the root of the module tree.
+    pub path: PathBuf,
+    /// Formatted Rust code.
+    pub contents: String,
+}
+
+/// The kind of endpoint item, which determines its module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Parameters,
+    Requests,
+    Responses,
+}
+
+impl Section {
+    fn module(self) -> &'static str {
+        match self {
+            Section::Parameters => "parameters",
+            Section::Requests => "requests",
+            Section::Responses => "responses",
+        }
+    }
+}
+
+/// A module which contains generated items, and no submodules.
+struct Leaf {
+    /// Path of the module from the root: `["parameters", "pets"]`.
+    path: Vec<String>,
+    tokens: TokenStream,
+}
+
+/// A leaf module after its items have been parsed.
+struct ParsedLeaf {
+    path: Vec<String>,
+    file: syn::File,
+    /// Items defined by this module, and whether each is public.
+    exports: Vec<(String, bool)>,
+    /// Every identifier which appears in this module.
+    idents: HashSet<String>,
+}
+
+/// Collect every identifier which appears in a token stream.
+fn collect_idents(tokens: TokenStream, idents: &mut HashSet<String>) {
+    for tree in tokens {
+        match tree {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
+
+/// Get the name and visibility of a top-level item, if it can be imported.
+fn item_name_and_vis(item: &mut syn::Item) -> Option<(&syn::Ident, &mut syn::Visibility)> {
+    match item {
+        syn::Item::Const(item) => Some((&item.ident, &mut item.vis)),
+        syn::Item::Enum(item) => Some((&item.ident, &mut item.vis)),
+        syn::Item::Fn(item) => Some((&item.sig.ident, &mut item.vis)),
+        syn::Item::Static(item) => Some((&item.ident, &mut item.vis)),
+        syn::Item::Struct(item) => Some((&item.ident, &mut item.vis)),
+        syn::Item::Trait(item) => Some((&item.ident, &mut item.vis)),
+        syn::Item::Type(item) => Some((&item.ident, &mut item.vis)),
+        syn::Item::Union(item) => Some((&item.ident, &mut item.vis)),
+        _ => None,
+    }
+}
+
+impl ParsedLeaf {
+    fn new(leaf: Leaf) -> Result<Self, Error> {
+        let mut idents = HashSet::new();
+        collect_idents(leaf.tokens.clone(), &mut idents);
+        let mut file = parse_file(leaf.tokens)?;
+
+        // private items must be visible to sibling modules
+        let mut exports = Vec::new();
+        for item in &mut file.items {
+            let Some((ident, vis)) = item_name_and_vis(item) else {
+                continue;
+            };
+            let is_pub = matches!(vis, syn::Visibility::Public(_));
+            if matches!(vis, syn::Visibility::Inherited) {
+                *vis = syn::parse_quote!(pub(crate));
+            }
+            exports.push((ident.to_string(), is_pub));
+        }
+
+        Ok(Self {
+            path: leaf.path,
+            file,
+            exports,
+            idents,
+        })
+    }
+}
+
+/// Emit `use` declarations which re-export the items of `child`.
+fn emit_reexports(child: &str, exports: &[(String, bool)]) -> TokenStream {
+    let child = make_ident(child);
+    let public = exports
+        .iter()
+        .filter(|(_, is_pub)| *is_pub)
+        .map(|(name, _)| make_ident(name))
+        .collect::<Vec<_>>();
+    let crate_visible = exports
+        .iter()
+        .filter(|(_, is_pub)| !*is_pub)
+        .map(|(name, _)| make_ident(name))
+        .collect::<Vec<_>>();
+
+    let public = (!public.is_empty()).then(|| quote!(pub use self::#child::{ #( #public ),* };));
+    let crate_visible = (!crate_visible.is_empty())
+        .then(|| quote!(pub(crate) use self::#child::{ #( #crate_visible ),* };));
+    quote! {
+        pub mod #child;
+        #public
+        #crate_visible
+    }
+}
+
+/// Name of the module for endpoints of a tag.
+fn group_module_name(model: &ApiModel, tag: Option<&str>) -> String {
+    let Some(tag) = tag else {
+        return DEFAULT_GROUP.into();
+    };
+    let mut name = tag.to_snake_case();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "tag_");
+    }
+    model.deconflict_member_or_variant_ident(&mut name);
+    name
+}
+
+/// Name of the module for a model, unique among the modules in `taken`.
+fn model_module_name(model: &ApiModel, rust_name: &str, taken: &mut HashSet<String>) -> String {
+    let mut name = rust_name.to_snake_case();
+    model.deconflict_member_or_variant_ident(&mut name);
+    let mut proposed = name.clone();
+    let mut suffix = 2;
+    while taken.contains(&proposed) {
+        proposed = format!("{name}_{suffix}");
+        suffix += 1;
+    }
+    taken.insert(proposed.clone());
+    proposed
+}
+
+impl ApiModel {
+    /// Assign the items which are specific to an endpoint to the section and group where they belong.
+    ///
+    /// Items defined in the components of the specification are not assigned; they remain models.
+    fn assign_endpoint_items(
+        &self,
+        endpoint: &Endpoint,
+        group: &str,
+        assignments: &mut HashMap<usize, (Section, String)>,
+    ) {
+        let parameters = endpoint
+            .query_parameters
+            .iter()
+            .chain(&endpoint.path_parameters)
+            .chain(
+                endpoint
+                    .headers
+                    .values()
+                    .map(|parameter| &parameter.item_ref),
+            )
+            .map(|ref_| (Section::Parameters, ref_));
+        let request = endpoint
+            .request_body
+            .iter()
+            .map(|ref_| (Section::Requests, ref_));
+        let response = std::iter::once((Section::Responses, &endpoint.response));
+
+        for (section, ref_) in parameters.chain(request).chain(response) {
+            let Some(idx) = ref_.as_backref() else {
+                continue;
+            };
+            if self.definitions[idx].pub_typedef {
+                continue;
+            }
+            assignments
+                .entry(idx)
+                .or_insert_with(|| (section, group.to_owned()));
+        }
+    }
+
+    /// Split the generated code into leaf modules.
+    fn leaves(&self) -> Result<Vec<Leaf>, Error> {
+        let Sections {
+            items,
+            api,
+            validation,
+            axum,
+            actix,
+            tower,
+            reqwest,
+            mock,
+            examples,
+        } = self.emit_sections()?;
+
+        let per_tag = self.options.trait_per_tag;
+        let mut assignments = HashMap::new();
+        for endpoint in self
+            .endpoints
+            .iter()
+            .chain(self.callbacks.iter().map(|callback| &callback.endpoint))
+        {
+            let group = if per_tag {
+                group_module_name(self, endpoint.tag.as_deref())
+            } else {
+                let mut name = endpoint.function_name(None);
+                self.deconflict_member_or_variant_ident(&mut name);
+                name
+            };
+            self.assign_endpoint_items(endpoint, &group, &mut assignments);
+        }
+
+        let mut leaves = Vec::<Leaf>::new();
+        let mut model_modules = HashSet::new();
+        for (idx, item) in items.into_iter().enumerate() {
+            if item.is_empty() {
+                continue;
+            }
+            let path = match assignments.get(&idx) {
+                None => vec![
+                    "models".to_owned(),
+                    model_module_name(self, &self.definitions[idx].rust_name, &mut model_modules),
+                ],
+                Some((section, group)) => vec![section.module().to_owned(), group.clone()],
+            };
+            match leaves.iter_mut().find(|leaf| leaf.path == path) {
+                Some(leaf) => leaf.tokens.extend(item),
+                None => leaves.push(Leaf { path, tokens: item }),
+            }
+        }
+
+        leaves.extend(
+            [
+                ("api", api),
+                ("validation", validation),
+                ("axum", axum),
+                ("actix", actix),
+                ("tower", tower),
+                ("reqwest", reqwest),
+                ("mock", mock),
+                ("examples", examples),
+            ]
+            .into_iter()
+            .map(|(name, tokens)| Leaf {
+                path: vec![name.into()],
+                tokens,
+            }),
+        );
+        leaves.retain(|leaf| !leaf.tokens.is_empty());
+        Ok(leaves)
+    }
+
+    /// Emit the items defined by this model as a tree of modules.
+    ///
+    /// Items defined in the components of the specification go to `models`, one submodule per item. Items which
+    /// belong to a particular endpoint go to `parameters`, `requests`, or `responses`, one submodule per
+    /// endpoint; when emitting one trait per tag, one submodule per tag instead. Traits and their supporting
+    /// items go to `api`, and the
+    /// integrations with `axum`, `actix`, `tower`, and `reqwest` to modules of those names. The mock
+    /// implementation of the API goes to `mock`, the example implementation to `examples`, and the
+    /// implementations of `Validate` to `validation`.
+    ///
+    /// The root of the tree is `mod.rs`. It re-exports every public item, so the tree can be used exactly like
+    /// the output of [`Self::emit_items`].
+    pub fn emit_module_tree(&self, emit_docs: bool) -> Result<Vec<ModuleFile>, Error> {
+        let leaves = self
+            .leaves()?
+            .into_iter()
+            .map(ParsedLeaf::new)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut files = Vec::with_capacity(leaves.len() + 1);
+        for leaf in &leaves {
+            let imports = leaves
+                .iter()
+                .filter(|other| other.path != leaf.path)
+                .flat_map(|other| &other.exports)
+                .filter(|(name, _)| leaf.idents.contains(name))
+                .map(|(name, _)| make_ident(name))
+                .collect::<Vec<_>>();
+
+            let mut file = leaf.file.clone();
+            let mut prelude = Vec::<syn::Item>::new();
+            if !imports.is_empty() {
+                let supers = leaf.path.iter().map(|_| quote!(super));
+                prelude.push(syn::parse_quote! {
+                    #[allow(unused_imports)]
+                    use #( #supers:: )* { #( #imports ),* };
+                });
+            }
+            file.items.splice(0..0, prelude);
+            file.attrs
+                .insert(0, syn::parse_quote!(#![allow(non_camel_case_types)]));
+
+            files.push(ModuleFile {
+                path: leaf.path.iter().collect::<PathBuf>().with_extension("rs"),
+                contents: pretty_print(&file)?,
+            });
+        }
+
+        // intermediate modules, which only hold submodules
+        let mut parents = Vec::<(&str, TokenStream)>::new();
+        for leaf in leaves.iter().filter(|leaf| leaf.path.len() > 1) {
+            let reexports = emit_reexports(&leaf.path[1], &leaf.exports);
+            match parents.iter_mut().find(|(name, _)| *name == leaf.path[0]) {
+                Some((_, tokens)) => tokens.extend(reexports),
+                None => parents.push((leaf.path[0].as_str(), reexports)),
+            }
+        }
+        for (name, tokens) in &parents {
+            let file = parse_file(quote! {
+                #![allow(non_camel_case_types)]
+                #tokens
+            })?;
+            files.push(ModuleFile {
+                path: [*name, "mod.rs"].iter().collect(),
+                contents: pretty_print(&file)?,
+            });
+        }
+
+        // root module
+        let mut root = self.emit_header(emit_docs);
+        let mut seen = HashSet::new();
+        for leaf in &leaves {
+            if !seen.insert(&leaf.path[0]) {
+                continue;
+            }
+            let exports = leaves
+                .iter()
+                .filter(|other| other.path[0] == leaf.path[0])
+                .flat_map(|other| other.exports.iter().cloned())
+                .collect::<Vec<_>>();
+            root.extend(emit_reexports(&leaf.path[0], &exports));
+        }
+        let file = parse_file(root)?;
+        files.push(ModuleFile {
+            path: "mod.rs".into(),
+            contents: pretty_print(&file)?,
+        });
+
+        Ok(files)
+    }
+}
@@ -0,0 +1,73 @@
+use openapiv3::OpenAPI;
+use serde::Deserialize;
+
+/// Code generation is configured by this extension at the top level of the specification.
+const OPTIONS_EXTENSION: &str = "x-openapi-gen";
+
+/// Options which control code generation.
+///
+/// These are read from the `x-openapi-gen` extension at the top level of the specification,
+/// and can be overridden with [`ApiModel::options_mut`][crate::ApiModel::options_mut]. Unknown options are
+/// rejected.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Options {
+    /// When true, emit one trait per operation tag instead of a single `trait Api`.
+    ///
+    /// Operations are assigned to the trait of their first tag. Untagged operations go to `trait DefaultApi`.
+    pub trait_per_tag: bool,
+    /// When true, emit a `Client` which implements the API traits by sending requests with `reqwest`.
+    ///
+    /// This implies `fallible`, so that the methods of the API traits can report failure to send a request. The
+    /// client itself has no effect unless feature `reqwest-support` is enabled.
+    pub client: bool,
+    /// When true, emit an `actix-web` integration alongside the `axum` integration.
+    ///
+    /// This has no effect unless feature `actix-support` is enabled.
+    pub actix: bool,
+    /// When true, emit a framework-agnostic `tower::Service` alongside the `axum` integration.
+    ///
+    /// This has no effect unless feature `tower-support` is enabled.
+    pub tower: bool,
+    /// When true, emit `struct MockApi`, a programmable mock implementation of the API traits for use in tests.
+    pub mock: bool,
+    /// When true, emit `struct ExampleApi`, a stub implementation of the API traits which responds with the
+    /// examples declared in the specification.
+    pub examples: bool,
+    /// When true, the generated router checks every parameter and request body against the constraints of its
+    /// schema before calling the API, and rejects requests which violate them.
+    pub validate: bool,
+    /// When true, each API trait declares an associated `type Context`, which is passed as the first argument of
+    /// every method.
+    ///
+    /// The generated router extracts the context from each request with the extractor trait of its framework.
+    pub context: bool,
+    /// When true, each API trait declares an associated `type Error`, and every method returns
+    /// `Result<Response, Self::Error>`.
+    ///
+    /// The generated router responds with the error when a method fails. This is implied by `client`.
+    pub fallible: bool,
+    /// When true, the methods of the generated traits return `impl Future + Send` natively, instead of boxed
+    /// futures via `async_trait`.
+    ///
+    /// Traits with native async methods cannot be made into trait objects, so leave this unset where dynamic
+    /// dispatch is required.
+    pub native_async: bool,
+}
+
+impl Options {
+    /// `true` when the methods of the API traits return `Result`: with `fallible`, or with `client`, which
+    /// implies it.
+    pub(crate) fn is_fallible(&self) -> bool {
+        self.fallible || self.client
+    }
+
+    /// Read the options declared by a specification.
+    pub(crate) fn from_spec(spec: &OpenAPI) -> Result<Self, serde_json::Error> {
+        spec.extensions
+            .get(OPTIONS_EXTENSION)
+            .map(|value| Self::deserialize(value))
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::HashSet;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use openapiv3::OpenAPI;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{codegen::make_ident, ApiModel};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("default value \"{default}\" of server variable \"{variable}\" is not one of its permitted values")]
+    DefaultNotPermitted { variable: String, default: String },
+}
+
+/// A variable which is substituted into a server URL.
+#[derive(Debug, Clone)]
+pub struct ServerVariable {
+    /// Name of the variable in the URL template.
+    pub name: String,
+    pub description: Option<String>,
+    pub default: String,
+    /// Permitted values. When not empty, an enum is emitted for this variable.
+    pub values: Vec<String>,
+    /// Name of the enum of permitted values.
+    pub enum_name: String,
+    /// Name of the field of the server's variant which holds this variable.
+    pub field_name: String,
+    /// Names of the enum variants, in the order of `values`.
+    pub variant_names: Vec<String>,
+}
+
+/// A segment of a server URL template.
+enum UrlSegment<'a> {
+    Literal(&'a str),
+    Variable(&'a ServerVariable),
+}
+
+/// A server which hosts the API.
+///
+/// Servers are emitted as variants of `enum Server`, which renders their base URLs.
+#[derive(Debug, Clone)]
+pub struct Server {
+    /// Name of the variant of `enum Server`.
+    pub rust_name: String,
+    pub description: Option<String>,
+    /// URL template, with variables in `{name}` notation.
+    pub url: String,
+    pub variables: Vec<ServerVariable>,
+}
+
+impl Server {
+    /// Render the URL of this server with the default value of each variable.
+    fn default_url(&self) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.clone(), |url, variable| {
+                url.replace(&format!("{{{}}}", variable.name), &variable.default)
+            })
+    }
+
+    /// The path component of the URL of this server with the default value of each variable.
+    ///
+    /// Relative URLs are taken to be paths. The path never ends with `/`, unless it is the root.
+    pub(crate) fn default_base_path(&self) -> String {
+        let url = self.default_url();
+        let path = match url.split_once("://") {
+            Some((_scheme, rest)) => rest.find('/').map(|idx| &rest[idx..]).unwrap_or_default(),
+            None => url.as_str(),
+        };
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        format!("/{}", path.trim_matches('/'))
+    }
+
+    /// Split the URL template into literal text and declared variables.
+    ///
+    /// Braces which do not enclose the name of a declared variable are literal text.
+    fn url_segments(&self) -> Vec<UrlSegment<'_>> {
+        let mut segments = Vec::new();
+        let mut literal_start = 0;
+        let mut rest_start = 0;
+        while let Some(open) = self.url[rest_start..].find('{').map(|idx| rest_start + idx) {
+            let Some(close) = self.url[open..].find('}').map(|idx| open + idx) else {
+                break;
+            };
+            let name = &self.url[open + 1..close];
+            match self.variables.iter().find(|variable| variable.name == name) {
+                Some(variable) => {
+                    if literal_start < open {
+                        segments.push(UrlSegment::Literal(&self.url[literal_start..open]));
+                    }
+                    segments.push(UrlSegment::Variable(variable));
+                    literal_start = close + 1;
+                    rest_start = close + 1;
+                }
+                None => rest_start = open + 1,
+            }
+        }
+        if literal_start < self.url.len() {
+            segments.push(UrlSegment::Literal(&self.url[literal_start..]));
+        }
+        segments
+    }
+}
+
+/// Append `_` to `name` until it is not among `taken`, then take it.
+fn deconflict(taken: &mut HashSet<String>, mut name: String) -> String {
+    while !taken.insert(name.clone()) {
+        name.push('_');
+    }
+    name
+}
+
+/// Collect the servers of the specification into the model.
+///
+/// The default value of a variable with permitted values must be one of them.
+pub(crate) fn insert_servers<R>(spec: &OpenAPI, model: &mut ApiModel<R>) -> Result<(), Error> {
+    for (idx, server) in spec.servers.iter().enumerate() {
+        let mut rust_name = server
+            .description
+            .as_deref()
+            .map(ToUpperCamelCase::to_upper_camel_case)
+            .filter(|name| name.starts_with(|c: char| c.is_ascii_alphabetic()))
+            .unwrap_or_else(|| format!("Server{}", idx + 1));
+        while model
+            .servers
+            .iter()
+            .any(|server| server.rust_name == rust_name)
+        {
+            rust_name.push('_');
+        }
+
+        // `url` is the local variable in which `Server::url` renders the URL
+        let mut field_names = HashSet::from(["url".to_owned()]);
+        let mut variables = Vec::new();
+        for (name, variable) in server.variables.iter().flatten() {
+            if !variable.enumeration.is_empty() && !variable.enumeration.contains(&variable.default)
+            {
+                return Err(Error::DefaultNotPermitted {
+                    variable: name.clone(),
+                    default: variable.default.clone(),
+                });
+            }
+
+            let mut enum_name = format!("{rust_name}{}", name.to_upper_camel_case());
+            model.deconflict_ident(&mut enum_name);
+            let mut field_name = name.to_snake_case();
+            model.deconflict_member_or_variant_ident(&mut field_name);
+            let field_name = deconflict(&mut field_names, field_name);
+            let mut variant_names = HashSet::new();
+            let variant_names = variable
+                .enumeration
+                .iter()
+                .map(|value| {
+                    let mut variant_name = value_variant_name(value);
+                    model.deconflict_member_or_variant_ident(&mut variant_name);
+                    deconflict(&mut variant_names, variant_name)
+                })
+                .collect();
+
+            variables.push(ServerVariable {
+                name: name.clone(),
+                description: variable.description.clone(),
+                default: variable.default.clone(),
+                values: variable.enumeration.clone(),
+                enum_name,
+                field_name,
+                variant_names,
+            });
+        }
+
+        model.servers.push(Server {
+            rust_name,
+            description: server.description.clone(),
+            url: server.url.clone(),
+            variables,
+        });
+    }
+    Ok(())
+}
+
+/// Name of the enum variant for a permitted value of a server variable.
+fn value_variant_name(value: &str) -> String {
+    let name = value.to_upper_camel_case();
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("V{name}")
+    }
+}
+
+fn emit_variable_enum(variable: &ServerVariable) -> Option<TokenStream> {
+    if variable.values.is_empty() {
+        return None;
+    }
+
+    let docs = variable
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("Permitted values of server variable `{}`.", variable.name));
+    let ident = make_ident(&variable.enum_name);
+    let variants = variable
+        .variant_names
+        .iter()
+        .map(String::as_str)
+        .map(make_ident)
+        .collect::<Vec<_>>();
+    let default_attr = variable
+        .values
+        .iter()
+        .map(|value| (value == &variable.default).then(|| quote!(#[default])));
+    let values = &variable.values;
+
+    Some(quote! {
+        #[doc = #docs]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub enum #ident {
+            #( #default_attr #variants, )*
+        }
+
+        impl #ident {
+            /// The value which is substituted into the server URL.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #( Self::#variants => #values, )*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    })
+}
+
+/// Emit `enum Server` and its supporting items.
+///
+/// Emits nothing if the specification declares no servers.
+pub(crate) fn emit_servers(model: &ApiModel) -> TokenStream {
+    let Some(default_server) = model.servers.first() else {
+        return TokenStream::default();
+    };
+
+    let variable_enums = model
+        .servers
+        .iter()
+        .flat_map(|server| server.variables.iter())
+        .filter_map(emit_variable_enum);
+
+    let mut variants = Vec::with_capacity(model.servers.len());
+    let mut constructors = Vec::with_capacity(model.servers.len());
+    let mut url_arms = Vec::with_capacity(model.servers.len());
+
+    for server in &model.servers {
+        let variant = make_ident(&server.rust_name);
+        let variant_docs = server
+            .description
+            .as_ref()
+            .map(|docs| quote!(#[doc = #docs]));
+        let fields = server
+            .variables
+            .iter()
+            .map(|variable| make_ident(&variable.field_name))
+            .collect::<Vec<_>>();
+
+        let field_definitions = server.variables.iter().map(|variable| {
+            let field = make_ident(&variable.field_name);
+            let docs = variable
+                .description
+                .as_ref()
+                .map(|docs| quote!(#[doc = #docs]));
+            let type_ = if variable.values.is_empty() {
+                quote!(String)
+            } else {
+                let ident = make_ident(&variable.enum_name);
+                quote!(#ident)
+            };
+            quote!(#docs #field: #type_)
+        });
+        variants.push(if fields.is_empty() {
+            quote!(#variant_docs #variant)
+        } else {
+            quote!(#variant_docs #variant { #( #field_definitions ),* })
+        });
+
+        let constructor_docs = format!(
+            "The server at `{}`, with the default value of each variable.",
+            server.url
+        );
+        let constructor = make_ident(&server.rust_name.to_snake_case());
+        let defaults = server.variables.iter().map(|variable| {
+            if variable.values.is_empty() {
+                let default = &variable.default;
+                quote!(#default.to_owned())
+            } else {
+                let ident = make_ident(&variable.enum_name);
+                quote!(#ident::default())
+            }
+        });
+        constructors.push(if fields.is_empty() {
+            quote! {
+                #[doc = #constructor_docs]
+                pub fn #constructor() -> Self {
+                    Self::#variant
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #constructor_docs]
+                pub fn #constructor() -> Self {
+                    Self::#variant { #( #fields: #defaults ),* }
+                }
+            }
+        });
+
+        let pushes = server
+            .url_segments()
+            .into_iter()
+            .map(|segment| match segment {
+                UrlSegment::Literal(literal) => quote!(url.push_str(#literal);),
+                UrlSegment::Variable(variable) => {
+                    let field = make_ident(&variable.field_name);
+                    if variable.values.is_empty() {
+                        quote!(url.push_str(#field);)
+                    } else {
+                        quote!(url.push_str(#field.as_str());)
+                    }
+                }
+            });
+        let pattern = if fields.is_empty() {
+            quote!(Self::#variant)
+        } else {
+            quote!(Self::#variant { #( #fields ),* })
+        };
+        url_arms.push(quote!(#pattern => { #( #pushes )* }));
+    }
+
+    let default_constructor = make_ident(&default_server.rust_name.to_snake_case());
+    let base_path = default_server.default_base_path();
+
+    quote! {
+        #( #variable_enums )*
+
+        /// Servers which host this API.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum Server {
+            #( #variants, )*
+        }
+
+        impl Server {
+            #( #constructors )*
+
+            /// Render the base URL of this server.
+            pub fn url(&self) -> String {
+                let mut url = String::new();
+                match self {
+                    #( #url_arms )*
+                }
+                url
+            }
+        }
+
+        impl Default for Server {
+            fn default() -> Self {
+                Self::#default_constructor()
+            }
+        }
+
+        /// Path at which the API is mounted on the default server.
+        pub const BASE_PATH: &str = #base_path;
+    }
+}
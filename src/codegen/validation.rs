@@ -0,0 +1,263 @@
+use heck::AsSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        make_ident, Item, Object, OneOfEnum, PropertyOverride, Reference, UnknownReference, Value,
+    },
+    ApiModel,
+};
+
+/// Emit statements which validate `value`, a reference to a value of the item at `ref_`.
+///
+/// `value` must be a plain identifier, so that it can be used as the receiver of method calls.
+///
+/// Structs, enums, and newtypes are validated by their own `impl Validate`; the contents of typedefs are
+/// validated inline. The statements record violations in `violations`; they are empty if nothing needs to be
+/// checked.
+pub(crate) fn emit_checks(
+    model: &ApiModel,
+    ref_: Reference,
+    value: TokenStream,
+) -> Result<TokenStream, UnknownReference> {
+    let item = model.resolve(ref_)?;
+    if !item.nullable {
+        return emit_item_checks(model, item, value);
+    }
+    let checks = emit_item_checks(model, item, quote!(value))?;
+    if checks.is_empty() {
+        return Ok(checks);
+    }
+    Ok(quote! {
+        if let Some(value) = #value {
+            #checks
+        }
+    })
+}
+
+/// Emit statements which validate `value`, disregarding the nullability of the item.
+fn emit_item_checks(
+    model: &ApiModel,
+    item: &Item,
+    value: TokenStream,
+) -> Result<TokenStream, UnknownReference> {
+    if item.is_typedef() {
+        emit_contents_checks(model, item, value)
+    } else if has_validate_impl(item) {
+        Ok(quote!(openapi_gen::validation::Validate::validate(#value, violations);))
+    } else {
+        Ok(TokenStream::new())
+    }
+}
+
+/// Emit statements which validate the contents of an item: its own constraints, and everything it contains.
+///
+/// `value` must be an identifier bound to a reference to a value of the type which the item wraps or aliases.
+fn emit_contents_checks(
+    model: &ApiModel,
+    item: &Item,
+    value: TokenStream,
+) -> Result<TokenStream, UnknownReference> {
+    let constraints = item.constraints.emit_checks(&item.value, &value);
+
+    let contents = match &item.value {
+        Value::Scalar(scalar) if scalar.impls_canonical_form() => {
+            quote!(openapi_gen::validation::check_constraints(#value, violations);)
+        }
+        Value::Ref(ref_) | Value::PropertyOverride(PropertyOverride { ref_, .. }) => {
+            emit_checks(model, *ref_, value)?
+        }
+        Value::List(list) => emit_element_checks(model, list.item, &value)?,
+        Value::Set(set) => emit_element_checks(model, set.item, &value)?,
+        Value::Map(map) => match map.value_type {
+            None => TokenStream::new(),
+            Some(value_type) => {
+                let checks = emit_checks(model, value_type, quote!(item))?;
+                if checks.is_empty() {
+                    checks
+                } else {
+                    quote! {
+                        for (key, item) in #value {
+                            violations.at(key, |violations| {
+                                #checks
+                            });
+                        }
+                    }
+                }
+            }
+        },
+        _ => TokenStream::new(),
+    };
+
+    Ok(quote! {
+        #constraints
+        #contents
+    })
+}
+
+/// Emit statements which validate each element of a list or set.
+fn emit_element_checks(
+    model: &ApiModel,
+    element: Reference,
+    value: &TokenStream,
+) -> Result<TokenStream, UnknownReference> {
+    let checks = emit_checks(model, element, quote!(item))?;
+    if checks.is_empty() {
+        return Ok(checks);
+    }
+    Ok(quote! {
+        for (idx, item) in #value.iter().enumerate() {
+            violations.at(idx, |violations| {
+                #checks
+            });
+        }
+    })
+}
+
+/// `true` when `impl Validate` is emitted for this item.
+///
+/// Response enums and the generated objects which carry response headers are never validated.
+fn has_validate_impl(item: &Item) -> bool {
+    if item.newtype.is_some() {
+        return true;
+    }
+    match &item.value {
+        Value::Object(object) => !object.is_generated_body_and_headers,
+        Value::OneOfEnum(one_of_enum) => one_of_enum
+            .variants
+            .iter()
+            .all(|variant| variant.status.is_none()),
+        _ => false,
+    }
+}
+
+fn validate_object(model: &ApiModel, object: &Object) -> Result<TokenStream, UnknownReference> {
+    let mut checks = Vec::with_capacity(object.members.len());
+    for (member_name, member) in &object.members {
+        let read_only = member.read_only
+            || model
+                .resolve(member.definition)?
+                .value
+                .as_property_override()
+                .is_some_and(|property_override| property_override.read_only);
+        if read_only {
+            // read-only members are never deserialized
+            continue;
+        }
+
+        let mut field_name = format!("{}", AsSnakeCase(member_name));
+        model.deconflict_member_or_variant_ident(&mut field_name);
+        let field = make_ident(&field_name);
+
+        let member_checks = if member.inline_option {
+            let member_checks = emit_checks(model, member.definition, quote!(value))?;
+            (!member_checks.is_empty()).then(|| {
+                quote! {
+                    if let Some(value) = &self.#field {
+                        #member_checks
+                    }
+                }
+            })
+        } else {
+            let member_checks = emit_checks(model, member.definition, quote!(value))?;
+            (!member_checks.is_empty()).then(|| {
+                quote! {
+                    let value = &self.#field;
+                    #member_checks
+                }
+            })
+        };
+
+        if let Some(member_checks) = member_checks {
+            checks.push(quote! {
+                violations.at(#member_name, |violations| {
+                    #member_checks
+                });
+            });
+        }
+    }
+    Ok(quote!(#( #checks )*))
+}
+
+fn validate_one_of_enum(
+    model: &ApiModel,
+    one_of_enum: &OneOfEnum,
+) -> Result<TokenStream, UnknownReference> {
+    let mut arms = Vec::with_capacity(one_of_enum.variants.len());
+    let mut any_checks = false;
+    for variant in &one_of_enum.variants {
+        let variant_name = variant
+            .computed_name()
+            .ok_or_else(|| UnknownReference("computed name of a one-of variant".into()))?;
+        let variant_ident = make_ident(variant_name);
+        let checks = emit_checks(model, variant.definition, quote!(value))?;
+        if checks.is_empty() {
+            arms.push(quote!(Self::#variant_ident(_) => {}));
+        } else {
+            any_checks = true;
+            arms.push(quote!(Self::#variant_ident(value) => { #checks }));
+        }
+    }
+    if !any_checks {
+        return Ok(TokenStream::new());
+    }
+    Ok(quote! {
+        match self {
+            #( #arms )*
+        }
+    })
+}
+
+/// Emit `impl Validate` for every struct, enum, and newtype.
+///
+/// Emits nothing unless the `validate` option is set.
+pub(crate) fn emit_validate_impls(model: &ApiModel) -> Result<TokenStream, UnknownReference> {
+    if !model.options.validate {
+        return Ok(TokenStream::default());
+    }
+
+    let mut impls = Vec::new();
+    for item in &model.definitions {
+        if item.is_typedef()
+            || !has_validate_impl(item)
+            || item.trivial_definition(model)?.is_some()
+        {
+            continue;
+        }
+
+        let checks = if item.newtype.is_some() {
+            let checks = emit_contents_checks(model, item, quote!(value))?;
+            if checks.is_empty() {
+                checks
+            } else {
+                quote! {
+                    let value = &self.0;
+                    #checks
+                }
+            }
+        } else {
+            match &item.value {
+                Value::Object(object) => validate_object(model, object)?,
+                Value::OneOfEnum(one_of_enum) => validate_one_of_enum(model, one_of_enum)?,
+                _ => TokenStream::new(),
+            }
+        };
+
+        let ident = make_ident(item.inner_name.as_deref().unwrap_or(&item.rust_name));
+        let violations = if checks.is_empty() {
+            quote!(_violations)
+        } else {
+            quote!(violations)
+        };
+        impls.push(quote! {
+            impl openapi_gen::validation::Validate for #ident {
+                fn validate(&self, #violations: &mut openapi_gen::validation::Violations) {
+                    #checks
+                }
+            }
+        });
+    }
+
+    Ok(quote!(#( #impls )*))
+}
@@ -112,6 +112,17 @@ impl ObjectMember {
             serde_attributes.push(quote!(skip_serializing_if = "Option::is_none"));
         }
 
+        // serde would otherwise accept a missing member which is required but nullable
+        let required_nullable = !self.inline_option
+            && !read_only
+            && serde_as.is_none()
+            && item.is_some_and(|item| item.nullable);
+        if model.options.validate && required_nullable {
+            serde_attributes.push(quote!(
+                deserialize_with = "openapi_gen::validation::required"
+            ));
+        }
+
         let serde_attributes = (!serde_attributes.is_empty()).then(|| {
             quote! {
                 #[serde(#( #serde_attributes ),*)]
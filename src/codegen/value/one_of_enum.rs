@@ -9,17 +9,47 @@ use crate::{
 };
 
 use heck::AsUpperCamelCase;
+use indexmap::IndexMap;
 use openapiv3::{OpenAPI, ReferenceOr, Schema};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 use super::ValueConversionError;
 
+/// The status codes with which a response variant may be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantStatus {
+    /// A single status code, known when the code is generated.
+    Code(http::StatusCode),
+    /// Any status code within a class, such as `5XX`.
+    Range(u16),
+    /// Any status code not otherwise declared.
+    Default,
+}
+
+impl VariantStatus {
+    /// Wrap the type of a variant's content so that it carries its status code, if that is only known at runtime.
+    pub(crate) fn wrap_runtime(self, referent: TokenStream) -> TokenStream {
+        match self {
+            VariantStatus::Code(_) => referent,
+            VariantStatus::Range(class) => {
+                let class = Literal::u16_unsuffixed(class);
+                quote!(openapi_gen::WithStatus<#referent, #class>)
+            }
+            VariantStatus::Default => quote!(openapi_gen::WithStatus<#referent>),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Variant<Ref = Reference> {
     pub definition: Ref,
     pub mapping_name: Option<String>,
-    pub status_code: Option<http::StatusCode>,
+    pub status: Option<VariantStatus>,
+    /// Named examples of this variant's content, in order of declaration.
+    ///
+    /// Only response variants have examples.
+    pub examples: IndexMap<String, serde_json::Value>,
     computed_name: OnceCell<String>,
 }
 
@@ -28,7 +58,8 @@ impl<R> Variant<R> {
         Self {
             definition,
             mapping_name,
-            status_code: None,
+            status: None,
+            examples: IndexMap::new(),
             computed_name: OnceCell::new(),
         }
     }
@@ -56,14 +87,16 @@ impl Variant<Ref> {
         let Self {
             definition,
             mapping_name,
-            status_code,
+            status,
+            examples,
             computed_name,
         } = self;
         let definition = resolver(&definition)?;
         Ok(Variant {
             definition,
             mapping_name,
-            status_code,
+            status,
+            examples,
             computed_name,
         })
     }
@@ -224,7 +257,10 @@ impl OneOfEnum {
             .map(|(idx, variant)| {
                 let variant_name = variant.compute_variant_name(idx, &name_resolver);
                 let ident = make_ident(variant_name);
-                let referent = model.definition(variant.definition, &name_resolver)?;
+                let mut referent = model.definition(variant.definition, &name_resolver)?;
+                if let Some(status) = variant.status {
+                    referent = status.wrap_runtime(referent);
+                }
                 let attributes = variant.serde_attributes(variant_name);
                 let attributes =
                     (!attributes.is_empty()).then(|| quote!(#[serde( #( #attributes)* )]));
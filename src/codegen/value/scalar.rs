@@ -16,6 +16,8 @@ pub enum Scalar {
     U32,
     String,
     Binary,
+    /// Raw binary data of an `application/octet-stream` body, which is never copied into a `Vec<u8>`.
+    OctetStream,
     #[cfg(feature = "bytes")]
     Bytes,
     Date,
@@ -53,6 +55,7 @@ impl Scalar {
             | Scalar::U64
             | Scalar::String
             | Scalar::Binary
+            | Scalar::OctetStream
             | Scalar::Date
             | Scalar::DateTime
             | Scalar::IpAddr
@@ -91,9 +94,12 @@ impl Scalar {
             | Scalar::Ipv4Addr
             | Scalar::Ipv6Addr
             | Scalar::Bool => true,
-            Scalar::String | Scalar::Binary | Scalar::Any | Scalar::Mime | Scalar::AcceptHeader => {
-                false
-            }
+            Scalar::String
+            | Scalar::Binary
+            | Scalar::OctetStream
+            | Scalar::Any
+            | Scalar::Mime
+            | Scalar::AcceptHeader => false,
             #[cfg(feature = "bytes")]
             Scalar::Bytes => false,
             #[cfg(feature = "uuid")]
@@ -118,6 +124,7 @@ impl Scalar {
             | Scalar::U64
             | Scalar::String
             | Scalar::Binary
+            | Scalar::OctetStream
             | Scalar::Date
             | Scalar::DateTime
             | Scalar::IpAddr
@@ -140,6 +147,41 @@ impl Scalar {
         }
     }
 
+    /// Does this scalar implement `CanonicalForm` such that it can be parsed from text?
+    pub fn impls_canonical_form(self) -> bool {
+        match self {
+            Scalar::F64
+            | Scalar::F32
+            | Scalar::I64
+            | Scalar::I32
+            | Scalar::U32
+            | Scalar::U64
+            | Scalar::String
+            | Scalar::Date
+            | Scalar::DateTime
+            | Scalar::IpAddr
+            | Scalar::Ipv4Addr
+            | Scalar::Ipv6Addr
+            | Scalar::Bool => true,
+            Scalar::Unit
+            | Scalar::Binary
+            | Scalar::OctetStream
+            | Scalar::Any
+            | Scalar::Mime
+            | Scalar::AcceptHeader => false,
+            #[cfg(feature = "bytes")]
+            Scalar::Bytes => true,
+            #[cfg(feature = "uuid")]
+            Scalar::Uuid => true,
+            #[cfg(feature = "integer-restrictions")]
+            Scalar::BoundedI32(_, _) | Scalar::BoundedI64(_, _) => true,
+            #[cfg(feature = "integer-restrictions")]
+            Scalar::BoundedU32(_, _) | Scalar::BoundedU64(_, _) => false,
+            #[cfg(feature = "api-problem")]
+            Scalar::ApiProblem => false,
+        }
+    }
+
     /// Should we use `serde_as::DisplayFromStr` or similar for serialization for this type?
     ///
     /// Most primitives implement `serde::Serialize` and `serde::Deserialize`. However, that is not universally true:
@@ -166,6 +208,7 @@ impl Scalar {
             | Scalar::U32
             | Scalar::String
             | Scalar::Binary
+            | Scalar::OctetStream
             | Scalar::IpAddr
             | Scalar::Ipv4Addr
             | Scalar::Ipv6Addr
@@ -200,6 +243,7 @@ impl Scalar {
             Scalar::U64 => quote!(u64),
             Scalar::String => quote!(String),
             Scalar::Binary => quote!(Vec<u8>),
+            Scalar::OctetStream => quote!(openapi_gen::reexport::bytes::Bytes),
             Scalar::Date => quote!(openapi_gen::reexport::time::Date),
             Scalar::DateTime => quote!(openapi_gen::reexport::time::OffsetDateTime),
             Scalar::IpAddr => quote!(std::net::IpAddr),
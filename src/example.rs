@@ -0,0 +1,152 @@
+//! Support for example-driven stub servers.
+//!
+//! With the `examples` option, generated code includes `struct ExampleApi`, which implements the API traits by
+//! responding with the examples declared in the specification. By default, each operation responds with the
+//! first example of its lowest `2xx` response, or with a value synthesized from the schema of that response
+//! when it has no examples.
+//!
+//! A request can select another example by name with the header `Prefer: example=name`. The preference must be
+//! in [`scope`] while the operation runs; [`middleware`] does that for `axum`. Unknown names fall back to the
+//! default example.
+//!
+//! ```ignore
+//! let router = build_router(ExampleApi)
+//!     .layer(axum::middleware::from_fn(openapi_gen::example::middleware));
+//! ```
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use http::{HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::WithStatus;
+
+thread_local! {
+    static PREFERRED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Get the name of the example preferred by a request, from its `Prefer` headers.
+///
+/// Preferences are comma-separated, and each may carry parameters after a `;`. This finds the first
+/// `example=name` preference; the name may be quoted.
+pub fn preferred_example(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(http::header::HeaderName::from_static("prefer"))
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split([',', ';']))
+        .find_map(|preference| {
+            let (token, value) = preference.split_once('=')?;
+            token
+                .trim()
+                .eq_ignore_ascii_case("example")
+                .then(|| value.trim().trim_matches('"').to_owned())
+        })
+        .filter(|name| !name.is_empty())
+}
+
+/// Get the name of the example preferred by the current request, if any.
+///
+/// This is only ever `Some` while an operation runs in [`scope`].
+pub fn preferred() -> Option<String> {
+    PREFERRED.with(|preferred| preferred.borrow().clone())
+}
+
+/// Run `future` with `preferred` as the name of the preferred example.
+pub fn scope<F>(preferred: Option<String>, future: F) -> Scoped<F>
+where
+    F: Future,
+{
+    Scoped {
+        preferred,
+        future: Box::pin(future),
+    }
+}
+
+/// A future which runs with a preferred example in scope.
+///
+/// Returned by [`scope`].
+pub struct Scoped<F> {
+    preferred: Option<String>,
+    future: Pin<Box<F>>,
+}
+
+/// Restores the previous preference when dropped, even if polling panics.
+struct Restore<'a> {
+    preferred: &'a mut Option<String>,
+}
+
+impl Drop for Restore<'_> {
+    fn drop(&mut self) {
+        PREFERRED.with(|preferred| std::mem::swap(&mut *preferred.borrow_mut(), self.preferred));
+    }
+}
+
+impl<F> Future for Scoped<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        PREFERRED
+            .with(|preferred| std::mem::swap(&mut *preferred.borrow_mut(), &mut this.preferred));
+        let _restore = Restore {
+            preferred: &mut this.preferred,
+        };
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// `axum` middleware which puts the example preferred by each request in [`scope`].
+///
+/// Use it with [`axum::middleware::from_fn`].
+#[cfg(feature = "axum-support")]
+pub async fn middleware(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let preferred = preferred_example(request.headers());
+    scope(preferred, next.run(request)).await
+}
+
+/// Decode an example of a response.
+///
+/// # Panics
+///
+/// If the example does not match the type of the response. Code generation rejects declared examples which do
+/// not match their schema, so this indicates a bug in the generator.
+pub fn decode<T>(operation: &str, example: &str, json: &str) -> T
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(json).unwrap_or_else(|err| {
+        panic!("{operation}: example `{example}` does not match its schema: {err}")
+    })
+}
+
+/// Convert an example of a binary response into its body.
+pub fn bytes<T>(example: &str) -> T
+where
+    T: From<Vec<u8>>,
+{
+    example.as_bytes().to_vec().into()
+}
+
+/// Attach a status to an example of a response whose status is only known at runtime.
+///
+/// # Panics
+///
+/// If `status` is outside the class `CLASS`.
+pub fn with_status<T, const CLASS: u16>(status: u16, body: T) -> WithStatus<T, CLASS> {
+    StatusCode::from_u16(status)
+        .ok()
+        .and_then(|status| WithStatus::new(status, body).ok())
+        .unwrap_or_else(|| panic!("status {status} is not in class {CLASS}"))
+}
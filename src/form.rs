@@ -0,0 +1,513 @@
+//! Encoding and decoding of `application/x-www-form-urlencoded` bodies.
+//!
+//! Generated code implements [`FormEncoding`] for each object used as a form-encoded request body.
+//! This records the `style` and `explode` settings of the body's array properties, which determine how
+//! the property's items appear in the encoded form:
+//!
+//! - exploded properties repeat their key once per item: `tag=a&tag=b`
+//! - unexploded properties join all items into a single value: `tag=a,b`
+//!
+//! An empty unexploded property keeps its key, with an empty value: `tag=`.
+//!
+//! Servers decode form bodies with [`FormEncoding::from_form_urlencoded`]; clients encode them with
+//! [`FormEncoding::to_form_urlencoded`].
+
+use serde::{
+    de::{
+        self,
+        value::{MapDeserializer, SeqDeserializer},
+        DeserializeOwned, Deserializer, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any, Serialize,
+};
+
+/// Serialization style of an array property within a form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// Items are separated by commas when not exploded.
+    #[default]
+    Form,
+    /// Items are separated by spaces when not exploded.
+    SpaceDelimited,
+    /// Items are separated by pipes when not exploded.
+    PipeDelimited,
+}
+
+impl Style {
+    /// The delimiter between items of an unexploded property.
+    pub fn delimiter(self) -> char {
+        match self {
+            Style::Form => ',',
+            Style::SpaceDelimited => ' ',
+            Style::PipeDelimited => '|',
+        }
+    }
+}
+
+/// Encoding of a single array property within a form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropertyStyle {
+    /// Name of the property, as it appears in the form.
+    pub name: &'static str,
+    pub style: Style,
+    pub explode: bool,
+}
+
+impl PropertyStyle {
+    fn delimiter_for(properties: &[PropertyStyle], name: &str) -> Option<char> {
+        properties
+            .iter()
+            .find(|property| !property.explode && property.name == name)
+            .map(|property| property.style.delimiter())
+    }
+}
+
+/// Types which can be encoded as and decoded from `application/x-www-form-urlencoded` bodies.
+pub trait FormEncoding: Sized {
+    /// Encodings of array properties of this type.
+    ///
+    /// Properties which do not appear here are exploded.
+    const PROPERTIES: &'static [PropertyStyle];
+
+    /// Encode this value as a form.
+    fn to_form_urlencoded(&self) -> Result<String, FormError>
+    where
+        Self: Serialize,
+    {
+        to_string(self)
+    }
+
+    /// Decode a value of this type from a form.
+    fn from_form_urlencoded(input: &[u8]) -> Result<Self, FormError>
+    where
+        Self: DeserializeOwned,
+    {
+        from_bytes(input)
+    }
+}
+
+/// Encode a value as a form, respecting the encodings of its array properties.
+pub fn to_string<T>(value: &T) -> Result<String, FormError>
+where
+    T: Serialize + FormEncoding,
+{
+    let exploded = serde_html_form::to_string(value)?;
+
+    // gather the items of unexploded properties, keeping each property in the position of its first item
+    let mut pairs = Vec::<(String, String)>::new();
+    for (key, value) in form_urlencoded::parse(exploded.as_bytes()) {
+        match PropertyStyle::delimiter_for(T::PROPERTIES, &key) {
+            Some(delimiter) => match pairs.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, joined)) => {
+                    joined.push(delimiter);
+                    joined.push_str(&value);
+                }
+                None => pairs.push((key.into_owned(), value.into_owned())),
+            },
+            None => pairs.push((key.into_owned(), value.into_owned())),
+        }
+    }
+
+    // empty properties serialize to no pairs at all, but unexploded ones keep their key
+    if T::PROPERTIES.iter().any(|property| !property.explode) {
+        let value = serde_json::to_value(value)?;
+        for property in T::PROPERTIES.iter().filter(|property| !property.explode) {
+            let is_empty = value
+                .get(property.name)
+                .and_then(serde_json::Value::as_array)
+                .is_some_and(Vec::is_empty);
+            if is_empty && !pairs.iter().any(|(key, _)| key == property.name) {
+                pairs.push((property.name.to_owned(), String::new()));
+            }
+        }
+    }
+
+    Ok(form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish())
+}
+
+/// Decode a value from a form, respecting the encodings of its array properties.
+pub fn from_bytes<T>(input: &[u8]) -> Result<T, FormError>
+where
+    T: DeserializeOwned + FormEncoding,
+{
+    from_pairs(form_urlencoded::parse(input).into_owned())
+}
+
+/// Decode a value from the key-value pairs of a form, respecting the encodings of its array properties.
+pub fn from_pairs<T>(pairs: impl IntoIterator<Item = (String, String)>) -> Result<T, FormError>
+where
+    T: DeserializeOwned + FormEncoding,
+{
+    // gather the items of each key, keeping the keys in the order of their first appearance
+    let mut fields = Vec::<(String, Vec<String>)>::new();
+    for (key, value) in pairs {
+        let items = match PropertyStyle::delimiter_for(T::PROPERTIES, &key) {
+            // an empty unexploded property has no items at all
+            Some(_) if value.is_empty() => Vec::new(),
+            Some(delimiter) => value.split(delimiter).map(ToOwned::to_owned).collect(),
+            None => vec![value],
+        };
+        match fields.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, existing)) => existing.extend(items),
+            None => fields.push((key, items)),
+        }
+    }
+
+    let fields = fields.into_iter().map(|(key, items)| (key, Items(items)));
+    T::deserialize(MapDeserializer::new(fields)).map_err(FormError::Decode)
+}
+
+/// Parse a single value of a form into `visit`'s type via `FromStr`.
+macro_rules! parse_part {
+    ($($deserialize:ident => $visit:ident,)*) => {
+        $(
+            fn $deserialize<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(err) => Err(de::Error::custom(format!("invalid value \"{}\": {err}", self.0))),
+                }
+            }
+        )*
+    };
+}
+
+/// Deserialize the only value of a key.
+macro_rules! forward_to_part {
+    ($($deserialize:ident($($arg:ident: $type_:ty),*),)*) => {
+        $(
+            fn $deserialize<V>(self, $($arg: $type_,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.part()?.$deserialize($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+/// A single value of a form.
+struct Part(String);
+
+impl<'de> IntoDeserializer<'de, de::value::Error> for Part {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Part {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.0)
+    }
+
+    parse_part! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    /// Empty values are absent.
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(std::iter::once(self)))
+    }
+
+    /// Values name unit variants.
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let variant: de::value::StringDeserializer<Self::Error> = self.0.into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// The values of a single key of a form, after splitting unexploded properties into their items.
+struct Items(Vec<String>);
+
+impl Items {
+    fn part(self) -> Result<Part, de::value::Error> {
+        match <[String; 1]>::try_from(self.0) {
+            Ok([value]) => Ok(Part(value)),
+            Err(values) => Err(de::Error::custom(format!(
+                "expected a single value, found {}",
+                values.len()
+            ))),
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, de::value::Error> for Items {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Items {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.0.len() == 1 {
+            self.part()?.deserialize_any(visitor)
+        } else {
+            self.deserialize_seq(visitor)
+        }
+    }
+
+    /// A key which is present has a value, unless its only value is empty.
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0.as_slice() {
+            [value] if value.is_empty() => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer::new(self.0.into_iter().map(Part)))
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_part! {
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FormError {
+    #[error("failed to encode form")]
+    Serialize(#[from] serde_html_form::ser::Error),
+    #[error("failed to encode form")]
+    Inspect(#[from] serde_json::Error),
+    #[error("failed to decode form")]
+    Deserialize(#[from] serde_html_form::de::Error),
+    #[error("failed to decode form")]
+    Decode(#[source] de::value::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Search {
+        exploded: Vec<String>,
+        form: Vec<String>,
+        space: Vec<u32>,
+        pipe: Option<Vec<String>>,
+        limit: Option<u32>,
+    }
+
+    impl FormEncoding for Search {
+        const PROPERTIES: &'static [PropertyStyle] = &[
+            PropertyStyle {
+                name: "form",
+                style: Style::Form,
+                explode: false,
+            },
+            PropertyStyle {
+                name: "space",
+                style: Style::SpaceDelimited,
+                explode: false,
+            },
+            PropertyStyle {
+                name: "pipe",
+                style: Style::PipeDelimited,
+                explode: false,
+            },
+        ];
+    }
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| (*item).to_owned()).collect()
+    }
+
+    #[test]
+    fn each_style_joins_its_items_with_its_delimiter() {
+        let search = Search {
+            exploded: strings(&["a", "b"]),
+            form: strings(&["c", "d"]),
+            space: vec![1, 2],
+            pipe: Some(strings(&["e", "f"])),
+            limit: Some(3),
+        };
+        let encoded = search.to_form_urlencoded().unwrap();
+        assert_eq!(
+            encoded,
+            "exploded=a&exploded=b&form=c%2Cd&space=1+2&pipe=e%7Cf&limit=3"
+        );
+        assert_eq!(
+            Search::from_form_urlencoded(encoded.as_bytes()).unwrap(),
+            search
+        );
+    }
+
+    #[test]
+    fn only_unexploded_properties_are_split() {
+        let search = Search::from_form_urlencoded(b"exploded=a,b&form=a,b&space=1").unwrap();
+        assert_eq!(search.exploded, strings(&["a,b"]));
+        assert_eq!(search.form, strings(&["a", "b"]));
+        assert_eq!(search.space, vec![1]);
+    }
+
+    #[test]
+    fn empty_unexploded_properties_keep_their_key() {
+        let search = Search {
+            exploded: strings(&["x"]),
+            form: Vec::new(),
+            space: Vec::new(),
+            pipe: Some(Vec::new()),
+            limit: None,
+        };
+        let encoded = search.to_form_urlencoded().unwrap();
+        assert_eq!(encoded, "exploded=x&form=&space=&pipe=");
+        assert_eq!(
+            Search::from_form_urlencoded(encoded.as_bytes()).unwrap(),
+            search
+        );
+    }
+
+    #[test]
+    fn absent_properties_are_none() {
+        let search = Search::from_form_urlencoded(b"exploded=x&form=&space=").unwrap();
+        assert_eq!(search.pipe, None);
+        assert_eq!(search.limit, None);
+    }
+
+    #[test]
+    fn invalid_items_are_rejected() {
+        assert!(Search::from_form_urlencoded(b"exploded=x&form=&space=1+two").is_err());
+        assert!(Search::from_form_urlencoded(b"exploded=x&form=&space=&limit=1&limit=2").is_err());
+    }
+}
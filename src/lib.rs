@@ -4,26 +4,52 @@ pub(crate) mod openapi_compat;
 pub(crate) mod resolve_trait;
 pub(crate) mod well_known_types;
 
+pub mod callback;
+pub mod example;
 pub mod fix_block_comments;
+pub mod form;
+pub mod link;
+pub mod mock;
+#[cfg(feature = "api-problem")]
+pub mod problem;
+pub mod security;
 pub mod serialization_helpers;
+pub mod status;
+pub mod text;
+pub mod validation;
 
 pub use canonical_form::{
     CanonicalForm, CanonicalizeError, ConstraintViolation, Reason, ValidationError,
 };
 
-pub use codegen::{ApiModel, Error};
+pub use codegen::{ApiModel, Error, ModuleFile, Options};
+
+pub use openapi_compat::deserialize_spec;
+
+pub use status::{AsStatusCode, WithStatus};
 
 #[cfg(feature = "bytes")]
 pub use well_known_types::Bytes;
 
+#[cfg(feature = "actix-support")]
+pub mod actix_compat;
+
 #[cfg(feature = "axum-support")]
 pub mod axum_compat;
 
+#[cfg(feature = "reqwest-support")]
+pub mod reqwest_compat;
+
+#[cfg(feature = "tower-support")]
+pub mod tower_compat;
+
 /// Reexport crates used by generated code.
 ///
 /// This makes it much easier to keep the types in sync between the generated code and your own types.
 pub mod reexport {
     pub use accept_header;
+    #[cfg(feature = "actix-support")]
+    pub use actix_web;
     pub use async_trait;
     #[cfg(feature = "axum-support")]
     pub use axum;
@@ -31,21 +57,68 @@ pub mod reexport {
     pub use axum_extra;
     #[cfg(feature = "integer-restrictions")]
     pub use bounded_integer;
+    pub use bytes;
     pub use derive_more;
-    #[cfg(feature = "axum-support")]
+    #[cfg(any(feature = "axum-support", feature = "tower-support"))]
     pub use headers;
     pub use heck;
     pub use http;
     #[cfg(feature = "api-problem")]
     pub use http_api_problem;
+    #[cfg(feature = "tower-support")]
+    pub use http_body;
+    #[cfg(feature = "tower-support")]
+    pub use http_body_util;
     pub use mime;
     #[cfg(feature = "string-pattern")]
     pub use regress;
+    #[cfg(feature = "reqwest-support")]
+    pub use reqwest;
     pub use serde;
     pub use serde_enum_str;
     pub use serde_json;
     pub use serde_with;
     pub use time;
+    #[cfg(feature = "tower-support")]
+    pub use tower_service;
     #[cfg(feature = "uuid")]
     pub use uuid;
 }
+
+/// Embed code generated by `openapi-gen-build` into the current module.
+///
+/// The argument is the name of the generated code: by default, the file name of the specification up to its first
+/// `.`.
+///
+/// ```ignore
+/// mod openapi {
+///     openapi_gen::include!("service");
+/// }
+/// ```
+#[macro_export]
+macro_rules! include {
+    ($name:literal) => {
+        ::core::include!(::core::concat!(::core::env!("OUT_DIR"), "/", $name, ".rs"));
+    };
+}
+
+/// Expand to the code generated from a specification, at compile time.
+///
+/// The path to the specification is relative to the directory containing the manifest of the invoking crate. It may
+/// be followed by the names of options to enable, as they are spelled in [`Options`]. Errors in the specification
+/// are reported as compile errors.
+///
+/// ```ignore
+/// mod openapi {
+///     openapi_gen::generate!("openapi/service.openapi.yaml", client, mock);
+/// }
+/// ```
+///
+/// The macro is implemented by the `openapi-gen-macros` crate, which must also be a dependency of the invoking crate:
+/// it links this crate to generate the code, so this crate cannot depend on it in turn.
+#[macro_export]
+macro_rules! generate {
+    ($($input:tt)*) => {
+        ::openapi_gen_macros::generate! { $($input)* }
+    };
+}
@@ -0,0 +1,47 @@
+//! Computation of link parameters from responses.
+//!
+//! A response may link to a follow-up operation, computing some of that operation's parameters from itself with
+//! runtime expressions such as `$response.body#/id`. Generated code translates each expression into a call to one
+//! of the functions in this module.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Convert part of a response into a parameter of the linked operation.
+pub fn convert<T, V>(value: &V) -> Result<T, LinkError>
+where
+    T: DeserializeOwned,
+    V: Serialize + ?Sized,
+{
+    let value = serde_json::to_value(value)?;
+    serde_json::from_value(value).map_err(Into::into)
+}
+
+/// Select part of a response body with a JSON pointer, and convert it into a parameter of the linked operation.
+pub fn pointer<T, V>(value: &V, pointer: &'static str) -> Result<T, LinkError>
+where
+    T: DeserializeOwned,
+    V: Serialize + ?Sized,
+{
+    let mut value = serde_json::to_value(value)?;
+    let value = value
+        .pointer_mut(pointer)
+        .map(serde_json::Value::take)
+        .ok_or(LinkError::Missing(pointer))?;
+    serde_json::from_value(value).map_err(Into::into)
+}
+
+/// Parse a constant parameter of the linked operation.
+pub fn constant<T>(json: &'static str) -> Result<T, LinkError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(json).map_err(Into::into)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LinkError {
+    #[error("failed to convert link parameter")]
+    Json(#[from] serde_json::Error),
+    #[error("response body has no value at \"{0}\"")]
+    Missing(&'static str),
+}
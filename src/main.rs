@@ -1,15 +1,39 @@
-use std::path::PathBuf;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
 
-use anyhow::{Context, Result};
-use clap::Parser;
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use similar::TextDiff;
 
 use openapi_gen::{ApiModel, Error};
 use openapiv3::OpenAPI;
 
 #[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: Args,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// check that committed generated code is up to date with its spec
+    ///
+    /// the code is regenerated in memory and compared with the committed output, ignoring the run data
+    /// in the module header. when they differ, a unified diff is printed and the exit status is non-zero.
+    Check(CheckArgs),
+}
+
+#[derive(Debug, clap::Args)]
 struct Args {
     /// path to openapi specification file
-    path: PathBuf,
+    #[arg(required = true)]
+    path: Option<PathBuf>,
 
     /// emit debug information about the spec
     ///
@@ -32,6 +56,15 @@ struct Args {
     #[arg(long)]
     emit_rust: bool,
 
+    #[command(flatten)]
+    options: OptionArgs,
+
+    /// write the generated code as a tree of modules into this directory
+    ///
+    /// the root of the tree is `mod.rs`. when unset, the generated code is printed as a single module.
+    #[arg(long)]
+    out_dir: Option<PathBuf>,
+
     /// skip emitting module documentation header
     ///
     /// this is most useful when generating test cases
@@ -39,37 +72,409 @@ struct Args {
     no_emit_docs: bool,
 }
 
+/// Code generation options, which override those set in the spec.
+#[derive(Debug, clap::Args)]
+struct OptionArgs {
+    /// emit one trait per operation tag instead of a single `trait Api`
+    ///
+    /// this can also be set in the spec with the `x-openapi-gen: { trait-per-tag: true }` extension.
+    #[arg(long)]
+    trait_per_tag: bool,
+
+    /// emit a `Client` which implements `trait Api` by sending requests with `reqwest`; implies `--fallible`
+    ///
+    /// this requires the `reqwest-support` feature of `openapi-gen`. it can also be set in the spec with
+    /// the `x-openapi-gen: { client: true }` extension.
+    #[arg(long)]
+    client: bool,
+
+    /// emit a function which configures an `actix-web` service, and `Responder` impls for the responses
+    ///
+    /// this requires the `actix-support` feature of `openapi-gen`. it can also be set in the spec with
+    /// the `x-openapi-gen: { actix: true }` extension.
+    #[arg(long)]
+    actix: bool,
+
+    /// emit a `tower::Service` which serves the api, and `IntoHttpResponse` impls for the responses
+    ///
+    /// this requires the `tower-support` feature of `openapi-gen`. it can also be set in the spec with
+    /// the `x-openapi-gen: { tower: true }` extension.
+    #[arg(long)]
+    tower: bool,
+
+    /// emit `struct MockApi`, a programmable mock implementation of the api for use in tests
+    ///
+    /// it can also be set in the spec with the `x-openapi-gen: { mock: true }` extension.
+    #[arg(long)]
+    mock: bool,
+
+    /// emit `struct ExampleApi`, a stub implementation of the api which responds with the examples of the spec
+    ///
+    /// it can also be set in the spec with the `x-openapi-gen: { examples: true }` extension.
+    #[arg(long)]
+    examples: bool,
+
+    /// validate parameters and request bodies against the constraints of their schemas before calling the api
+    ///
+    /// it can also be set in the spec with the `x-openapi-gen: { validate: true }` extension.
+    #[arg(long)]
+    validate: bool,
+
+    /// pass a request context, extracted by the router, as the first argument of every api method
+    ///
+    /// it can also be set in the spec with the `x-openapi-gen: { context: true }` extension.
+    #[arg(long)]
+    context: bool,
+
+    /// let api methods fail with an associated error type, which the router converts into a response
+    ///
+    /// it can also be set in the spec with the `x-openapi-gen: { fallible: true }` extension.
+    #[arg(long)]
+    fallible: bool,
+
+    /// emit native async trait methods returning `impl Future + Send` instead of using `async_trait`
+    ///
+    /// it can also be set in the spec with the `x-openapi-gen: { native-async: true }` extension.
+    #[arg(long)]
+    native_async: bool,
+}
+
+impl OptionArgs {
+    fn apply(&self, model: &mut ApiModel) {
+        if self.trait_per_tag {
+            model.options_mut().trait_per_tag = true;
+        }
+        if self.client {
+            model.options_mut().client = true;
+        }
+        if self.actix {
+            model.options_mut().actix = true;
+        }
+        if self.tower {
+            model.options_mut().tower = true;
+        }
+        if self.mock {
+            model.options_mut().mock = true;
+        }
+        if self.examples {
+            model.options_mut().examples = true;
+        }
+        if self.validate {
+            model.options_mut().validate = true;
+        }
+        if self.context {
+            model.options_mut().context = true;
+        }
+        if self.fallible {
+            model.options_mut().fallible = true;
+        }
+        if self.native_async {
+            model.options_mut().native_async = true;
+        }
+    }
+}
+
+#[derive(Debug, clap::Args)]
+struct CheckArgs {
+    /// path to openapi specification file
+    path: PathBuf,
+
+    /// the committed generated code: a single file, or the root directory of a module tree
+    committed: PathBuf,
+
+    #[command(flatten)]
+    options: OptionArgs,
+
+    /// expect committed code without the module documentation header
+    #[arg(long, hide = true)]
+    no_emit_docs: bool,
+}
+
+/// Items of the run data in the module header, which change on every run and are ignored when checking committed
+/// code.
+const RUN_DATA_PREFIXES: &[&str] = &[
+    "- timestamp:",
+    "- `openapi-gen` version:",
+    "- `openapi-gen` git sha:",
+    "- input file:",
+];
+
 fn main() -> Result<()> {
-    let args = Args::parse();
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::Check(args)) => check(args),
+        None => generate(cli.args),
+    }
+}
 
+fn read_spec(path: &Path) -> Result<OpenAPI> {
     let reader = {
-        let file = std::fs::File::open(&args.path).context("reading file")?;
+        let file = std::fs::File::open(path).context("reading file")?;
         std::io::BufReader::new(file)
     };
-    let spec: OpenAPI = serde_yaml::from_reader(reader).context("parsing yaml")?;
+    openapi_gen::deserialize_spec(serde_yaml::Deserializer::from_reader(reader))
+        .context("parsing yaml")
+}
+
+fn generate(args: Args) -> Result<()> {
+    let path = args.path.expect("path is required without a subcommand");
+    let spec = read_spec(&path)?;
     if args.debug_spec {
         dbg!(&spec);
     }
 
-    let model = ApiModel::new(&spec, Some(&args.path)).context("converting to api model")?;
+    let mut model = ApiModel::new(&spec, Some(&path)).context("converting to api model")?;
+    args.options.apply(&mut model);
     if args.debug_model {
         dbg!(&model);
     }
 
-    let pretty = model
-        .emit_items(!args.no_emit_docs)
-        .map_err(|err| {
-            if let Error::CodegenParse { buffer, .. } = &err {
-                eprintln!("==== invalid rust code follows ====");
-                eprintln!("{buffer}");
-                eprintln!("==== invalid rust code precedes ====");
+    if !args.emit_rust && (args.debug_spec || args.debug_model) {
+        return Ok(());
+    }
+
+    if let Some(out_dir) = &args.out_dir {
+        let files = model
+            .emit_module_tree(!args.no_emit_docs)
+            .map_err(print_invalid_code)
+            .context("emitting rust code")?;
+        for file in files {
+            let path = out_dir.join(&file.path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).context("creating output directory")?;
             }
-            err
-        })
-        .context("emitting rust code")?;
-    if args.emit_rust || !(args.debug_spec || args.debug_model) {
+            std::fs::write(&path, file.contents)
+                .with_context(|| format!("writing {}", path.display()))?;
+        }
+    } else {
+        let pretty = model
+            .emit_items(!args.no_emit_docs)
+            .map_err(print_invalid_code)
+            .context("emitting rust code")?;
         println!("{pretty}");
     }
 
     Ok(())
 }
+
+fn check(args: CheckArgs) -> Result<()> {
+    let spec = read_spec(&args.path)?;
+    let mut model = ApiModel::new(&spec, Some(&args.path)).context("converting to api model")?;
+    args.options.apply(&mut model);
+    let emit_docs = !args.no_emit_docs;
+
+    let mut stale = false;
+    if args.committed.is_dir() {
+        let files = model
+            .emit_module_tree(emit_docs)
+            .map_err(print_invalid_code)
+            .context("emitting rust code")?;
+        let mut committed = BTreeSet::new();
+        find_rust_files(&args.committed, Path::new(""), &mut committed)?;
+        for file in files {
+            committed.remove(&file.path);
+            let path = args.committed.join(&file.path);
+            stale |= print_diff(&path, &read_committed(&path)?, &file.contents);
+        }
+        // files which are no longer generated are stale too
+        for path in committed {
+            let path = args.committed.join(path);
+            stale |= print_diff(&path, &read_committed(&path)?, "");
+        }
+    } else {
+        let pretty = model
+            .emit_items(emit_docs)
+            .map_err(print_invalid_code)
+            .context("emitting rust code")?;
+        stale |= print_diff(&args.committed, &read_committed(&args.committed)?, &pretty);
+    }
+
+    if stale {
+        bail!(
+            "generated code is out of date with {}; regenerate it",
+            args.path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Read a committed file, which is empty if it does not exist.
+fn read_committed(path: &Path) -> Result<String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(err).with_context(|| format!("reading {}", path.display())),
+    }
+}
+
+/// Collect the paths of the Rust files below `root.join(relative)`, relative to `root`.
+fn find_rust_files(root: &Path, relative: &Path, found: &mut BTreeSet<PathBuf>) -> Result<()> {
+    let dir = root.join(relative);
+    let entries =
+        std::fs::read_dir(&dir).with_context(|| format!("reading directory {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry.with_context(|| format!("reading directory {}", dir.display()))?;
+        let path = relative.join(entry.file_name());
+        if entry.path().is_dir() {
+            find_rust_files(root, &path, found)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            found.insert(path);
+        }
+    }
+    Ok(())
+}
+
+/// Remove the run data from generated code, and normalize its trailing whitespace.
+///
+/// The run data is part of the module documentation, which precedes the first inner attribute.
+fn normalize(code: &str) -> String {
+    let mut in_header = true;
+    let mut normalized = code
+        .lines()
+        .filter(|line| {
+            in_header &= !line.trim_start().starts_with("#![");
+            !(in_header && is_run_data(line))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    normalized.truncate(normalized.trim_end().len());
+    normalized.push('\n');
+    normalized
+}
+
+/// `true` for an item of the run data, whether the module documentation is a block comment or line comments.
+fn is_run_data(line: &str) -> bool {
+    let line = line.trim_start();
+    let line = line.strip_prefix("//!").unwrap_or(line).trim_start();
+    RUN_DATA_PREFIXES
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+}
+
+/// Print a unified diff from the committed code at `path` to the generated code.
+///
+/// Returns `true` when they differ.
+fn print_diff(path: &Path, committed: &str, generated: &str) -> bool {
+    let committed = normalize(committed);
+    let generated = normalize(generated);
+    if committed == generated {
+        return false;
+    }
+
+    let name = path.display().to_string();
+    print!(
+        "{}",
+        TextDiff::from_lines(&committed, &generated)
+            .unified_diff()
+            .header(
+                &format!("{name} (committed)"),
+                &format!("{name} (generated)")
+            )
+    );
+    true
+}
+
+fn print_invalid_code(err: Error) -> Error {
+    if let Error::CodegenParse { buffer, .. } = &err {
+        eprintln!("==== invalid rust code follows ====");
+        eprintln!("{buffer}");
+        eprintln!("==== invalid rust code precedes ====");
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"
+openapi: "3.0.3"
+info:
+  title: "Pets"
+  version: "0.1.0"
+paths:
+  "/pets":
+    get:
+      operationId: listPets
+      responses:
+        "200":
+          description: the pets
+          content:
+            "application/json":
+              schema:
+                $ref: "#/components/schemas/Pet"
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name:
+          type: string
+"##;
+
+    fn check_in(dir: &Path) -> Result<()> {
+        let cli = Cli::try_parse_from([
+            Path::new("openapi-gen"),
+            Path::new("check"),
+            dir.join("pets.yaml").as_path(),
+            dir.join("pets.rs").as_path(),
+        ])?;
+        match cli.command {
+            Some(Command::Check(args)) => check(args),
+            None => panic!("expected the check subcommand"),
+        }
+    }
+
+    #[test]
+    fn check_detects_edits_to_the_specification() {
+        let dir = std::env::temp_dir().join(format!("openapi-gen-check-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let spec_path = dir.join("pets.yaml");
+        std::fs::write(&spec_path, SPEC).unwrap();
+
+        let spec = read_spec(&spec_path).unwrap();
+        let model = ApiModel::new(&spec, Some(&spec_path)).unwrap();
+        std::fs::write(dir.join("pets.rs"), model.emit_items(true).unwrap()).unwrap();
+
+        // the run data differs between runs, but is ignored
+        assert!(check_in(&dir).is_ok());
+
+        let edited = SPEC.replace(
+            "          type: string\n",
+            "          type: string\n        age:\n          type: integer\n",
+        );
+        assert_ne!(edited, SPEC);
+        std::fs::write(&spec_path, edited).unwrap();
+        assert!(check_in(&dir).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn run_data_is_ignored_only_in_the_header() {
+        let code = "\
+/*!
+Generated.
+
+- timestamp: 2024-01-01T00:00:00Z
+*/
+#![allow(non_camel_case_types)]
+/// - timestamp: kept
+pub struct Pet;
+";
+        let expect = "\
+/*!
+Generated.
+
+*/
+#![allow(non_camel_case_types)]
+/// - timestamp: kept
+pub struct Pet;
+";
+        assert_eq!(normalize(code), expect);
+        assert_eq!(
+            normalize("//! - `openapi-gen` git sha: abc\n#![allow(non_camel_case_types)]\n"),
+            "#![allow(non_camel_case_types)]\n"
+        );
+    }
+}
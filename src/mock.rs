@@ -0,0 +1,317 @@
+//! Programmable mock implementations of API traits.
+//!
+//! With the `mock` option, generated code includes `struct MockApi`, which implements the API traits by
+//! delegating each operation to a [`Mock`]. Tests set up an [`Expectation`] for each call they expect, and the
+//! mock answers each call with the first expectation which matches it:
+//!
+//! ```ignore
+//! let mut api = MockApi::new();
+//! api.expect_get_pet()
+//!     .with((PetId(7),))
+//!     .times(1)
+//!     .returning(|(pet_id,)| GetPetResponse::Ok(Pet::new(*pet_id, "Rex".into())));
+//! ```
+//!
+//! Calls which match no expectation panic. Expected call counts are verified when the mock is dropped.
+//!
+//! With the `fallible` option, `MockApi` is generic over the error of the API traits, and each `Output` is the
+//! whole `Result` of its operation, so expectations can also fail.
+
+use std::{
+    fmt,
+    ops::Deref,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// The mock of a single operation.
+///
+/// `Args` is the tuple of the arguments of the operation, and `Output` is its response.
+pub struct Mock<Args, Output> {
+    name: &'static str,
+    state: Mutex<State<Args, Output>>,
+}
+
+struct State<Args, Output> {
+    expectations: Vec<Expectation<Args, Output>>,
+    calls: Vec<Args>,
+}
+
+impl<Args, Output> Mock<Args, Output> {
+    /// Create a mock with no expectations.
+    ///
+    /// `name` identifies the operation in the messages of panics.
+    pub fn new(name: &'static str) -> Self {
+        let state = Mutex::new(State {
+            expectations: Vec::new(),
+            calls: Vec::new(),
+        });
+        Self { name, state }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State<Args, Output>> {
+        // a panic while the lock was held was already reported; the state is still coherent
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Add an expectation.
+    ///
+    /// Expectations are matched in the order in which they were added.
+    pub fn expect(&mut self) -> &mut Expectation<Args, Output> {
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+        state.expectations.push(Expectation::default());
+        state
+            .expectations
+            .last_mut()
+            .expect("an expectation was just added")
+    }
+
+    /// Answer a call with the first expectation which matches it, and record it.
+    ///
+    /// # Panics
+    ///
+    /// If no expectation matches the call, or the matching expectation has no return value.
+    pub fn call(&self, args: Args) -> Output {
+        let mut state = self.state();
+        let Some(expectation) = state
+            .expectations
+            .iter_mut()
+            .find(|expectation| !expectation.is_saturated() && expectation.matches(&args))
+        else {
+            drop(state);
+            panic!("{}: no expectation matches this call", self.name);
+        };
+        let Some(returning) = expectation.returning.as_mut() else {
+            drop(state);
+            panic!(
+                "{}: the matching expectation has no return value",
+                self.name
+            );
+        };
+
+        let output = returning(&args);
+        expectation.calls += 1;
+        state.calls.push(args);
+        output
+    }
+
+    /// The calls received so far, in order.
+    pub fn calls(&self) -> Calls<'_, Args, Output> {
+        Calls(self.state())
+    }
+
+    /// Verify that every expectation received its expected number of calls, then remove all expectations.
+    ///
+    /// Recorded calls are kept.
+    ///
+    /// # Panics
+    ///
+    /// If any expectation is not satisfied.
+    pub fn checkpoint(&mut self) {
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+        let unsatisfied = state
+            .expectations
+            .drain(..)
+            .enumerate()
+            .filter(|(_idx, expectation)| !expectation.is_satisfied())
+            .map(|(idx, expectation)| {
+                format!(
+                    "expectation {idx} expected {} calls, but received {}",
+                    expectation.times, expectation.calls
+                )
+            })
+            .collect::<Vec<_>>();
+        if !unsatisfied.is_empty() {
+            panic!("{}: {}", self.name, unsatisfied.join("; "));
+        }
+    }
+}
+
+/// Expectations are verified on drop, unless the thread is already panicking.
+impl<Args, Output> Drop for Mock<Args, Output> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.checkpoint();
+        }
+    }
+}
+
+impl<Args, Output> fmt::Debug for Mock<Args, Output> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("Mock")
+            .field("name", &self.name)
+            .field("expectations", &state.expectations.len())
+            .field("calls", &state.calls.len())
+            .finish()
+    }
+}
+
+/// The calls received by a [`Mock`], in order.
+///
+/// The mock cannot be called while this is held.
+pub struct Calls<'a, Args, Output>(MutexGuard<'a, State<Args, Output>>);
+
+impl<Args, Output> Deref for Calls<'_, Args, Output> {
+    type Target = [Args];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.calls
+    }
+}
+
+/// How many calls an [`Expectation`] expects.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Times {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl fmt::Display for Times {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "exactly {max}"),
+            Some(max) => write!(f, "between {} and {max}", self.min),
+            None => write!(f, "at least {}", self.min),
+        }
+    }
+}
+
+/// An expected call of a [`Mock`], and its response.
+///
+/// By default, an expectation matches any call, any number of times.
+pub struct Expectation<Args, Output> {
+    matcher: Option<Box<dyn Fn(&Args) -> bool + Send>>,
+    returning: Option<Box<dyn FnMut(&Args) -> Output + Send>>,
+    times: Times,
+    calls: usize,
+}
+
+impl<Args, Output> Default for Expectation<Args, Output> {
+    fn default() -> Self {
+        Self {
+            matcher: None,
+            returning: None,
+            times: Times::default(),
+            calls: 0,
+        }
+    }
+}
+
+impl<Args, Output> Expectation<Args, Output> {
+    fn matches(&self, args: &Args) -> bool {
+        self.matcher.as_ref().is_none_or(|matcher| matcher(args))
+    }
+
+    fn is_saturated(&self) -> bool {
+        self.times.max.is_some_and(|max| self.calls >= max)
+    }
+
+    fn is_satisfied(&self) -> bool {
+        self.calls >= self.times.min
+    }
+
+    /// Match only calls whose arguments equal `args`.
+    pub fn with(&mut self, args: Args) -> &mut Self
+    where
+        Args: 'static + PartialEq + Send,
+    {
+        self.withf(move |actual| *actual == args)
+    }
+
+    /// Match only calls whose arguments satisfy `predicate`.
+    pub fn withf<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: 'static + Fn(&Args) -> bool + Send,
+    {
+        self.matcher = Some(Box::new(predicate));
+        self
+    }
+
+    /// Compute the response to each matching call from its arguments.
+    pub fn returning<F>(&mut self, returning: F) -> &mut Self
+    where
+        F: 'static + FnMut(&Args) -> Output + Send,
+    {
+        self.returning = Some(Box::new(returning));
+        self
+    }
+
+    /// Respond to each matching call with a clone of `output`.
+    pub fn return_const(&mut self, output: Output) -> &mut Self
+    where
+        Output: 'static + Clone + Send,
+    {
+        self.returning(move |_args| output.clone())
+    }
+
+    /// Expect exactly `n` matching calls.
+    ///
+    /// Once it has received them, the expectation no longer matches, so later calls fall through to later
+    /// expectations.
+    pub fn times(&mut self, n: usize) -> &mut Self {
+        self.times = Times {
+            min: n,
+            max: Some(n),
+        };
+        self
+    }
+
+    /// Expect at least `n` matching calls.
+    pub fn at_least(&mut self, n: usize) -> &mut Self {
+        self.times.min = n;
+        self
+    }
+
+    /// Expect at most `n` matching calls.
+    pub fn at_most(&mut self, n: usize) -> &mut Self {
+        self.times.max = Some(n);
+        self
+    }
+
+    /// Expect no matching calls.
+    pub fn never(&mut self) -> &mut Self {
+        self.times(0)
+    }
+}
+
+impl<Args, Output> fmt::Debug for Expectation<Args, Output> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Expectation")
+            .field("times", &self.times)
+            .field("calls", &self.calls)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_expectations_in_order() {
+        let mut mock = Mock::<(u32,), &'static str>::new("op");
+        mock.expect().with((1,)).times(1).return_const("one");
+        mock.expect().returning(|_args| "other");
+
+        assert_eq!(mock.call((1,)), "one");
+        assert_eq!(mock.call((1,)), "other");
+        assert_eq!(mock.call((2,)), "other");
+        assert_eq!(*mock.calls(), [(1,), (1,), (2,)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "op: no expectation matches this call")]
+    fn unexpected_call_panics() {
+        let mut mock = Mock::<(u32,), ()>::new("op");
+        mock.expect().with((1,)).return_const(());
+        mock.call((2,));
+    }
+
+    #[test]
+    #[should_panic(expected = "op: expectation 0 expected exactly 2 calls, but received 1")]
+    fn unsatisfied_expectation_panics_on_drop() {
+        let mut mock = Mock::<(), ()>::new("op");
+        mock.expect().times(2).return_const(());
+        mock.call(());
+    }
+}
@@ -1,6 +1,7 @@
 use openapiv3::{Header, OpenAPI, Parameter, PathItem, ReferenceOr, RequestBody, Response, Schema};
+use serde::{de, Deserialize, Deserializer};
 
-use crate::resolve_trait::Resolve;
+use crate::{codegen::endpoint::callback::WEBHOOKS_EXTENSION, resolve_trait::Resolve};
 
 pub(crate) mod or_scalar;
 pub(crate) use or_scalar::OrScalar;
@@ -19,6 +20,31 @@ fn is_inline_or_external<T>(ref_: &ReferenceOr<T>) -> bool {
     is_inline(ref_) || is_external(ref_)
 }
 
+/// Deserialize a specification.
+///
+/// `OpenAPI` models OpenAPI 3.0, which has no `webhooks` field. The `webhooks` of an OpenAPI 3.1 specification are
+/// moved into the `x-webhooks` extension, from which webhooks are read. Where both declare a webhook of the same
+/// name, the extension wins.
+pub fn deserialize_spec<'de, D>(deserializer: D) -> Result<OpenAPI, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut value = serde_json::Value::deserialize(deserializer)?;
+    if let Some(spec) = value.as_object_mut() {
+        if let Some(serde_json::Value::Object(webhooks)) = spec.remove("webhooks") {
+            let extension = spec
+                .entry(WEBHOOKS_EXTENSION)
+                .or_insert_with(|| serde_json::Value::Object(Default::default()));
+            if let Some(extension) = extension.as_object_mut() {
+                for (name, webhook) in webhooks {
+                    extension.entry(name).or_insert(webhook);
+                }
+            }
+        }
+    }
+    serde_json::from_value(value).map_err(de::Error::custom)
+}
+
 /// Iterate over all path items for an `OpenAPI` struct.
 ///
 /// This ignores any path items not defined inline in the top-level `paths` construct.
@@ -138,3 +164,29 @@ pub(crate) fn component_headers(spec: &OpenAPI) -> impl Iterator<Item = (&str, S
             })
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webhooks_are_kept_as_the_extension() {
+        let spec = serde_json::json!({
+            "openapi": "3.1.0",
+            "info": { "title": "Webhooks", "version": "0.1.0" },
+            "paths": {},
+            "webhooks": {
+                "thingCreated": { "post": { "responses": { "204": { "description": "ok" } } } },
+                "thingDeleted": { "post": { "responses": { "204": { "description": "ok" } } } },
+            },
+            "x-webhooks": {
+                "thingDeleted": { "delete": { "responses": { "204": { "description": "ok" } } } },
+            },
+        });
+        let spec = deserialize_spec(spec).unwrap();
+        let webhooks = spec.extensions[WEBHOOKS_EXTENSION].as_object().unwrap();
+        assert!(webhooks["thingCreated"].get("post").is_some());
+        assert!(webhooks["thingDeleted"].get("delete").is_some());
+        assert!(webhooks["thingDeleted"].get("post").is_none());
+    }
+}
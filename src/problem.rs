@@ -0,0 +1,129 @@
+//! A general-purpose error for fallible API traits.
+//!
+//! With the `fallible` option, each API trait declares the error with which its methods fail. [`Problem`] is a
+//! ready-made choice, which responds with a problem document in every server integration.
+
+use std::fmt;
+
+use http_api_problem::{HttpApiProblem, StatusCode};
+
+/// An error which responds with a problem document.
+///
+/// Every error converts into a `Problem`, so fallible methods can use `?` freely. An [`HttpApiProblem`] responds
+/// as it is; any other error responds with `500 Internal Server Error`. The message of such an error is not sent
+/// to the client, but remains available from [`Problem::source`], for logging.
+#[derive(Debug)]
+pub struct Problem {
+    problem: HttpApiProblem,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl Problem {
+    /// Respond with `problem`.
+    pub fn new(problem: HttpApiProblem) -> Self {
+        Self {
+            problem,
+            source: None,
+        }
+    }
+
+    /// The status with which this problem responds.
+    pub fn status(&self) -> StatusCode {
+        self.problem
+            .status
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    /// The problem document with which this responds.
+    pub fn problem(&self) -> &HttpApiProblem {
+        &self.problem
+    }
+
+    /// The error from which this problem was converted, unless it was an `HttpApiProblem`.
+    pub fn source(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        self.source.as_deref()
+    }
+
+    /// Convert this into the problem document with which it responds.
+    pub fn into_problem(self) -> HttpApiProblem {
+        self.problem
+    }
+}
+
+impl<E> From<E> for Problem
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn from(err: E) -> Self {
+        let err: Box<dyn std::error::Error + Send + Sync> = Box::new(err);
+        match err.downcast::<HttpApiProblem>() {
+            Ok(problem) => Self::new(*problem),
+            Err(source) => Self {
+                problem: HttpApiProblem::with_title(StatusCode::INTERNAL_SERVER_ERROR),
+                source: Some(source),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(source) => fmt::Display::fmt(source, f),
+            None => fmt::Display::fmt(&self.problem, f),
+        }
+    }
+}
+
+#[cfg(feature = "axum-support")]
+impl axum::response::IntoResponse for Problem {
+    fn into_response(self) -> axum::response::Response {
+        axum::response::IntoResponse::into_response(self.problem)
+    }
+}
+
+#[cfg(feature = "tower-support")]
+impl crate::tower_compat::IntoHttpResponse for Problem {
+    fn into_http_response(self) -> crate::tower_compat::Response {
+        use crate::tower_compat::{json, respond, response};
+
+        let status = self.status();
+        respond(|| json(response(status), "application/problem+json", &self.problem))
+    }
+}
+
+#[cfg(feature = "actix-support")]
+impl actix_web::ResponseError for Problem {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        crate::actix_compat::actix_status(self.status())
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        use crate::actix_compat::{json, respond, response};
+
+        respond(|| {
+            json(
+                response(self.status()),
+                "application/problem+json",
+                &self.problem,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn problems_keep_their_status() {
+        let problem = Problem::from(HttpApiProblem::with_title(StatusCode::NOT_FOUND));
+        assert_eq!(problem.status(), StatusCode::NOT_FOUND);
+        assert!(problem.source().is_none());
+
+        let err = "ten".parse::<u8>().unwrap_err();
+        let problem = Problem::from(err.clone());
+        assert_eq!(problem.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(problem.source().unwrap().to_string(), err.to_string());
+    }
+}
@@ -0,0 +1,97 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{endpoint::callback::Callback, make_ident, Reference, UnknownReference},
+    ApiModel,
+};
+
+use super::{request::build_request, response::decode_response, Error};
+
+/// Implement a single method of `trait Callbacks`, which sends the callback and decodes its response.
+fn impl_callback<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    callback: &Callback,
+) -> Result<TokenStream, Error> {
+    let endpoint = &callback.endpoint;
+    let function_name = make_ident(&endpoint.function_name(None));
+
+    let parameters = endpoint
+        .function_parameters(model)
+        .map_err(Error::context("computing function parameters"))?
+        .map(|(name, ref_, required)| {
+            let param_name = make_ident(&name);
+            let mut type_name = model.definition(ref_, &name_resolver)?;
+            if !required {
+                type_name = quote!(Option< #type_name >);
+            }
+            Ok(quote!(#param_name: #type_name))
+        })
+        .collect::<Result<Vec<_>, UnknownReference>>()
+        .map_err(Error::context("getting parameter types"))?;
+
+    let request_body = endpoint
+        .request_body
+        .map(|ref_| {
+            let type_name = model.definition(ref_, &name_resolver)?;
+            Ok(quote!(request_body: #type_name))
+        })
+        .transpose()
+        .map_err(Error::context("getting request body type"))?;
+
+    let response_body = model
+        .definition(endpoint.response, &name_resolver)
+        .map_err(Error::context("getting response type"))?;
+
+    let build_request = build_request(model, &name_resolver, endpoint, quote!(url.to_owned()))?;
+    let decode_response = decode_response(model, &name_resolver, endpoint.response)?;
+
+    Ok(quote! {
+        async fn #function_name (
+            &self,
+            url: &str,
+            #(
+                #parameters,
+            )*
+            #request_body
+        ) -> Result<#response_body, Self::Error> {
+            #build_request
+            let response = request.send().await?;
+            let status = response.status();
+            #decode_response
+        }
+    })
+}
+
+/// Implement `trait Callbacks` for `CallbackClient`.
+pub(crate) fn impl_callbacks<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    if model.callbacks.is_empty() {
+        return Ok(TokenStream::default());
+    }
+
+    let methods = model
+        .callbacks
+        .iter()
+        .map(|callback| {
+            impl_callback(model, &name_resolver, callback).map_err(Error::context(format!(
+                "implementing callback \"{}\"",
+                callback.name
+            )))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let async_trait = model.async_trait_attribute();
+
+    Ok(quote! {
+        #async_trait
+        impl Callbacks for openapi_gen::reqwest_compat::CallbackClient {
+            type Error = openapi_gen::reqwest_compat::ClientError;
+
+            #( #methods )*
+        }
+    })
+}
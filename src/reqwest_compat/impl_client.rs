@@ -0,0 +1,316 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::security::{requirement_variant_name, KeyLocation, SchemeKind, SecurityScheme},
+        make_ident, Endpoint, Reference, UnknownReference,
+    },
+    ApiModel,
+};
+
+use super::{request::build_request, response::decode_response, Error};
+
+/// Implement `Authenticate` for the credential type of a security scheme.
+fn impl_scheme_authenticate(scheme: &SecurityScheme) -> TokenStream {
+    let prefix = quote!(openapi_gen::reqwest_compat::security);
+    let ident = make_ident(&scheme.rust_name);
+
+    let present = match &scheme.kind {
+        SchemeKind::ApiKey { location, name } => {
+            let function = match location {
+                KeyLocation::Header => quote!(api_key_header),
+                KeyLocation::Query => quote!(api_key_query),
+                KeyLocation::Cookie => quote!(api_key_cookie),
+            };
+            quote!(#prefix::#function(request, #name, &self.0))
+        }
+        SchemeKind::Basic => quote!(#prefix::basic(request, &self.0)),
+        SchemeKind::Bearer => quote!(#prefix::bearer(request, &self.0)),
+        SchemeKind::Http { scheme } => quote!(#prefix::credentials(request, #scheme, &self.0)),
+    };
+
+    quote! {
+        impl #prefix::Authenticate for #ident {
+            fn authenticate(
+                &self,
+                request: openapi_gen::reexport::reqwest::RequestBuilder,
+            ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+                #present
+            }
+        }
+    }
+}
+
+/// Implement `Authenticate` for the credentials enum of an endpoint, if it has one.
+fn impl_credentials_authenticate(model: &ApiModel, endpoint: &Endpoint) -> Option<TokenStream> {
+    let security = endpoint
+        .security
+        .as_ref()
+        .filter(|security| security.has_credentials_enum())?;
+    let prefix = quote!(openapi_gen::reqwest_compat::security);
+    let ident = make_ident(&security.credentials);
+
+    let arms = security.requirements.iter().map(|requirement| {
+        let variant = make_ident(&requirement_variant_name(model, requirement));
+        let variables = requirement
+            .keys()
+            .map(|name| make_ident(&model.security_schemes[name].rust_name.to_snake_case()))
+            .collect::<Vec<_>>();
+        quote! {
+            Self::#variant( #( #variables ),* ) => {
+                #( let request = #prefix::Authenticate::authenticate(#variables, request); )*
+                request
+            }
+        }
+    });
+
+    Some(quote! {
+        impl #prefix::Authenticate for #ident {
+            fn authenticate(
+                &self,
+                request: openapi_gen::reexport::reqwest::RequestBuilder,
+            ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+    })
+}
+
+/// An endpoint method of the client: its signature, and the arguments with which it is called.
+struct Method {
+    function_name: syn::Ident,
+    parameters: Vec<TokenStream>,
+    arguments: Vec<syn::Ident>,
+    response: TokenStream,
+}
+
+impl Method {
+    fn new<'a>(
+        model: &ApiModel,
+        name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+        endpoint: &Endpoint,
+    ) -> Result<Self, Error> {
+        let mut parameters = Vec::new();
+        let mut arguments = Vec::new();
+
+        if let Some(type_name) = endpoint.credentials_type() {
+            parameters.push(quote!(credentials: #type_name));
+            arguments.push(make_ident("credentials"));
+        }
+
+        for (name, ref_, required) in endpoint
+            .function_parameters(model)
+            .map_err(Error::context("computing function parameters"))?
+        {
+            let param_name = make_ident(&name);
+            let mut type_name = model
+                .definition(ref_, &name_resolver)
+                .map_err(Error::context("getting parameter type"))?;
+            if !required {
+                type_name = quote!(Option< #type_name >);
+            }
+            parameters.push(quote!(#param_name: #type_name));
+            arguments.push(param_name);
+        }
+
+        if let Some(ref_) = endpoint.request_body {
+            let type_name = model
+                .definition(ref_, &name_resolver)
+                .map_err(Error::context("getting request body type"))?;
+            parameters.push(quote!(request_body: #type_name));
+            arguments.push(make_ident("request_body"));
+        }
+
+        let response = model
+            .definition(endpoint.response, &name_resolver)
+            .map_err(Error::context("getting response type"))?;
+
+        Ok(Self {
+            function_name: make_ident(&endpoint.function_name(None)),
+            parameters,
+            arguments,
+            response,
+        })
+    }
+
+    fn try_function_name(&self) -> syn::Ident {
+        make_ident(&format!("try_{}", self.function_name))
+    }
+}
+
+/// Implement the inherent method of `Client` which sends a request to an endpoint.
+fn impl_try_method<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    endpoint: &Endpoint,
+    method: &Method,
+) -> Result<TokenStream, Error> {
+    let docs = format!(
+        "Send a request to `{} {}`.\n\nFailure to send the request or to decode its response is reported as an error.",
+        endpoint.verb, endpoint.path,
+    );
+    let try_function_name = method.try_function_name();
+    let parameters = &method.parameters;
+    let response = &method.response;
+    let path = &endpoint.path;
+
+    let build_request = build_request(
+        model,
+        &name_resolver,
+        endpoint,
+        quote!(openapi_gen::reqwest_compat::join_url(&self.base_url, #path)),
+    )?;
+    let authenticate = endpoint.security.is_some().then(|| {
+        quote! {
+            let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(&credentials, request);
+        }
+    });
+    let decode_response = decode_response(model, &name_resolver, endpoint.response)?;
+
+    Ok(quote! {
+        #[doc = #docs]
+        pub async fn #try_function_name(
+            &self,
+            #( #parameters, )*
+        ) -> Result<#response, openapi_gen::reqwest_compat::ClientError> {
+            #build_request
+            #authenticate
+            let response = request.send().await?;
+            let status = response.status();
+            #decode_response
+        }
+    })
+}
+
+/// Emit `struct Client`, and implement each API trait for it.
+///
+/// Emits nothing unless the `client` option is set.
+pub(crate) fn impl_client<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    if !model.options.client {
+        return Ok(TokenStream::default());
+    }
+
+    let scheme_impls = model
+        .security_schemes
+        .values()
+        .map(impl_scheme_authenticate);
+    let credentials_impls = model
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| impl_credentials_authenticate(model, endpoint));
+
+    let default_impl = (!model.servers.is_empty()).then(|| {
+        quote! {
+            impl Default for Client {
+                fn default() -> Self {
+                    Self::new(Server::default().url())
+                }
+            }
+        }
+    });
+
+    // requests sent by the client carry no context
+    let context = model
+        .options
+        .context
+        .then(|| quote!(_context: Self::Context,));
+    let context_type = model.options.context.then(|| {
+        quote!(
+            type Context = ();
+        )
+    });
+
+    let mut try_methods = Vec::with_capacity(model.endpoints.len());
+    let mut trait_impls = Vec::new();
+    for api_trait in model.api_traits() {
+        let trait_ident = make_ident(api_trait.name);
+        let mut methods = Vec::with_capacity(api_trait.endpoints.len());
+
+        for endpoint in &api_trait.endpoints {
+            let method =
+                Method::new(model, &name_resolver, endpoint).map_err(Error::context(format!(
+                    "implementing client for \"{} {}\"",
+                    endpoint.verb, endpoint.path
+                )))?;
+            try_methods.push(impl_try_method(model, &name_resolver, endpoint, &method)?);
+
+            let Method {
+                function_name,
+                parameters,
+                arguments,
+                response,
+            } = &method;
+            let try_function_name = method.try_function_name();
+            methods.push(quote! {
+                async fn #function_name(&self, #context #( #parameters, )*) -> Result<#response, Self::Error> {
+                    self.#try_function_name( #( #arguments ),* ).await
+                }
+            });
+        }
+
+        // `client` implies `fallible`, so the methods can fail with the error of the client
+        let async_trait = model.async_trait_attribute();
+        trait_impls.push(quote! {
+            #async_trait
+            impl #trait_ident for Client {
+                #context_type
+                type Error = openapi_gen::reqwest_compat::ClientError;
+                #( #methods )*
+            }
+        });
+    }
+
+    Ok(quote! {
+        #( #scheme_impls )*
+        #( #credentials_impls )*
+
+        /// A client for this API, which sends its requests with `reqwest`.
+        ///
+        /// Each operation is available as a method which reports failure as an error. It also implements the API
+        /// traits, whose methods fail with the same error.
+        #[derive(Debug, Clone)]
+        pub struct Client {
+            client: openapi_gen::reexport::reqwest::Client,
+            base_url: String,
+        }
+
+        impl Client {
+            /// Create a client for the API at `base_url`.
+            pub fn new(base_url: impl Into<String>) -> Self {
+                Self::with_client(Default::default(), base_url)
+            }
+
+            /// Create a client for the API at `base_url`, which sends its requests with the given client.
+            pub fn with_client(
+                client: openapi_gen::reexport::reqwest::Client,
+                base_url: impl Into<String>,
+            ) -> Self {
+                let base_url = base_url.into();
+                Self { client, base_url }
+            }
+
+            /// The client with which requests are sent.
+            pub fn client(&self) -> &openapi_gen::reexport::reqwest::Client {
+                &self.client
+            }
+
+            /// The base URL of the API, to which the path of each endpoint is appended.
+            pub fn base_url(&self) -> &str {
+                &self.base_url
+            }
+
+            #( #try_methods )*
+        }
+
+        #default_impl
+
+        #( #trait_impls )*
+    })
+}
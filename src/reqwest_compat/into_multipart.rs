@@ -0,0 +1,115 @@
+use heck::AsSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{endpoint::request_body::resolve_concrete, make_ident, Item, Object, Scalar, Value},
+    ApiModel,
+};
+
+use super::Error;
+
+/// `true` when values of this item are raw bytes, which should be sent directly as the part data.
+fn is_binary(item: &Item) -> bool {
+    !item.nullable && matches!(item.value, Value::Scalar(Scalar::Binary))
+}
+
+/// Implement `IntoMultipart` for the object underlying a `multipart/form-data` request body.
+///
+/// Binary properties are sent directly as the part data; all other properties are serialized into their part.
+/// Array properties send one part per element, each with the property's name. Read-only properties are not sent.
+pub(crate) fn impl_into_multipart(
+    model: &ApiModel,
+    request_body: &Item,
+    object_name: &str,
+    object: &Object,
+) -> Result<TokenStream, Error> {
+    let object_ident = make_ident(object_name);
+    let prefix = quote!(openapi_gen::reqwest_compat);
+
+    let mut parts = Vec::with_capacity(object.members.len());
+
+    for (member_name, member) in object.members.iter() {
+        if member.read_only {
+            continue;
+        }
+
+        let mut field_name = format!("{}", AsSnakeCase(member_name));
+        model.deconflict_member_or_variant_ident(&mut field_name);
+        let field_ident = make_ident(&field_name);
+
+        let content_type = match request_body
+            .encoding
+            .get(member_name)
+            .and_then(|encoding| encoding.content_type.as_deref())
+        {
+            Some(content_type) => quote!(Some(#content_type)),
+            None => quote!(None),
+        };
+
+        let member_item = resolve_concrete(model, member.definition).map_err(Error::context(
+            format!("resolving multipart member \"{member_name}\""),
+        ))?;
+
+        let add_part = |binary: bool, value: TokenStream| {
+            let function = if binary {
+                quote!(multipart_bytes)
+            } else {
+                quote!(multipart_part)
+            };
+            quote!(form = #prefix::#function(form, #member_name, #content_type, #value)?;)
+        };
+
+        let part = match &member_item.value {
+            Value::List(list) if !member_item.nullable => {
+                let list_item = resolve_concrete(model, list.item).map_err(Error::context(
+                    format!("resolving multipart list item of \"{member_name}\""),
+                ))?;
+                let binary = is_binary(list_item);
+                let values = match (member.inline_option, binary) {
+                    (false, true) => quote!(self.#field_ident),
+                    (false, false) => quote!(&self.#field_ident),
+                    (true, true) => quote!(self.#field_ident.into_iter().flatten()),
+                    (true, false) => quote!(self.#field_ident.iter().flatten()),
+                };
+                let add_part = add_part(binary, quote!(value));
+                quote! {
+                    for value in #values {
+                        #add_part
+                    }
+                }
+            }
+            _ => {
+                let binary = is_binary(member_item);
+                match (member.inline_option, binary) {
+                    (false, true) => add_part(binary, quote!(self.#field_ident)),
+                    (false, false) => add_part(binary, quote!(&self.#field_ident)),
+                    (true, true) => {
+                        let add_part = add_part(binary, quote!(value));
+                        quote!(if let Some(value) = self.#field_ident { #add_part })
+                    }
+                    (true, false) => {
+                        let add_part = add_part(binary, quote!(value));
+                        quote!(if let Some(value) = &self.#field_ident { #add_part })
+                    }
+                }
+            }
+        };
+
+        parts.push(part);
+    }
+
+    Ok(quote! {
+        impl #prefix::IntoMultipart for #object_ident {
+            fn into_form(
+                self,
+            ) -> Result<openapi_gen::reexport::reqwest::multipart::Form, #prefix::ClientError> {
+                // `form` is not reassigned if the object has no members
+                #[allow(unused_mut)]
+                let mut form = openapi_gen::reexport::reqwest::multipart::Form::new();
+                #( #parts )*
+                Ok(form)
+            }
+        }
+    })
+}
@@ -0,0 +1,386 @@
+//! Reqwest compatibility boilerplate generator.
+//!
+//! Generated code implements `trait Callbacks` for [`CallbackClient`], which delivers callbacks and webhooks
+//! over HTTP. Each request is built from the parameters and body of the callback, and each response is decoded
+//! into the appropriate variant of the callback's response enum.
+//!
+//! When the `client` option is set, generated code also includes a `Client` which sends the requests of
+//! `trait Api` in the same way, presenting credentials via [`security::Authenticate`], and implements `trait Api`.
+//! The option implies `fallible`, so that its methods can fail with [`ClientError`].
+//!
+//! The objects underlying `multipart/form-data` request bodies implement [`IntoMultipart`], so that they can be sent.
+//!
+//! The remaining functions in this module are used by the generated code; they are not generally useful on their own.
+
+use http::{header::CONTENT_TYPE, HeaderMap, StatusCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+use reqwest::{
+    multipart::{Form, Part},
+    Body, RequestBuilder, Response,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    codegen::{endpoint::request_body::body_object, Endpoint, Reference, UnknownReference},
+    form::{FormEncoding, FormError},
+    reqwest_compat::{
+        impl_callbacks::impl_callbacks, impl_client::impl_client,
+        into_multipart::impl_into_multipart,
+    },
+    status::StatusOutOfRange,
+    text::{FromText, TextError},
+    ApiModel, CanonicalForm, CanonicalizeError,
+};
+
+mod impl_callbacks;
+mod impl_client;
+mod into_multipart;
+mod request;
+mod response;
+
+pub mod security;
+
+pub(crate) fn reqwest_items<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    let impl_callbacks = impl_callbacks(model, &name_resolver)?;
+    let impl_client = impl_client(model, &name_resolver)?;
+
+    // only the bodies which this crate sends need to be encoded
+    let endpoints: &[Endpoint] = if model.options.client {
+        &model.endpoints
+    } else {
+        &[]
+    };
+    let mut into_multipart_impls = Vec::new();
+    let mut implemented_multipart_objects = Vec::new();
+    for request_body in endpoints
+        .iter()
+        .chain(model.callbacks.iter().map(|callback| &callback.endpoint))
+        .filter_map(|endpoint| endpoint.request_body)
+    {
+        let item = model
+            .resolve(request_body)
+            .map_err(Error::context("getting item for request body"))?;
+        if !item.is_multipart_form_data() {
+            continue;
+        }
+        let (object_ref, object_name, object) = body_object(model, request_body)
+            .map_err(Error::context("resolving multipart request body"))?
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "multipart request body '{}' must be an object",
+                    &item.rust_name
+                ))
+            })?;
+        if implemented_multipart_objects.contains(&object_ref) {
+            continue;
+        }
+        implemented_multipart_objects.push(object_ref);
+
+        into_multipart_impls.push(
+            impl_into_multipart(model, item, object_name, object)
+                .map_err(Error::context("implementing `IntoMultipart`"))?,
+        );
+    }
+
+    Ok(quote! {
+        #( #into_multipart_impls )*
+        #impl_callbacks
+        #impl_client
+    })
+}
+
+/// A request body which can be sent as `multipart/form-data`.
+///
+/// Implemented by generated code for the objects underlying `multipart/form-data` request bodies.
+pub trait IntoMultipart {
+    /// Encode this object as a form, with one part per property.
+    fn into_form(self) -> Result<Form, ClientError>;
+}
+
+/// A client which delivers callbacks and webhooks.
+#[derive(Debug, Clone, Default)]
+pub struct CallbackClient {
+    client: reqwest::Client,
+}
+
+impl CallbackClient {
+    /// Create a callback client which sends its requests with the given client.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    /// The client with which requests are sent.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+/// Join the base URL of an API and the path of one of its endpoints.
+pub fn join_url(base_url: &str, path: &str) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    )
+}
+
+/// Percent-encode a value as a single path segment, via its canonical form.
+pub fn path_segment<T>(value: &T) -> Result<String, ClientError>
+where
+    T: CanonicalForm,
+{
+    let value = value.canonicalize()?.to_string();
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    Ok(out)
+}
+
+/// Add a header to a request, via its canonical form.
+///
+/// Nothing is added when the value is `None`.
+pub fn header<T>(
+    request: RequestBuilder,
+    name: &'static str,
+    value: Option<&T>,
+) -> Result<RequestBuilder, ClientError>
+where
+    T: CanonicalForm,
+{
+    let Some(value) = value else {
+        return Ok(request);
+    };
+    let value = value.canonicalize()?.to_string();
+    Ok(request.header(name, value))
+}
+
+/// Add a header to a request, via its `Display` implementation.
+///
+/// Nothing is added when the value is `None`.
+pub fn header_display<T>(
+    request: RequestBuilder,
+    name: &'static str,
+    value: Option<&T>,
+) -> Result<RequestBuilder, ClientError>
+where
+    T: std::fmt::Display,
+{
+    Ok(match value {
+        Some(value) => request.header(name, value.to_string()),
+        None => request,
+    })
+}
+
+/// Set a JSON request body.
+pub fn json_body<T>(
+    request: RequestBuilder,
+    content_type: &'static str,
+    body: &T,
+) -> Result<RequestBuilder, ClientError>
+where
+    T: Serialize,
+{
+    let body = serde_json::to_vec(body)?;
+    Ok(request.header(CONTENT_TYPE, content_type).body(body))
+}
+
+/// Set an `application/x-www-form-urlencoded` request body.
+pub fn form_body<T>(request: RequestBuilder, body: &T) -> Result<RequestBuilder, ClientError>
+where
+    T: Serialize + FormEncoding,
+{
+    let body = body.to_form_urlencoded()?;
+    Ok(request
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body))
+}
+
+/// Set a `text/*` request body, via its canonical form.
+pub fn text_body<T>(
+    request: RequestBuilder,
+    content_type: &'static str,
+    body: &T,
+) -> Result<RequestBuilder, ClientError>
+where
+    T: CanonicalForm,
+{
+    let (content_type, body) = crate::text::encode(content_type, body)?;
+    Ok(request.header(CONTENT_TYPE, content_type).body(body))
+}
+
+/// Set a binary request body.
+pub fn bytes_body(
+    request: RequestBuilder,
+    content_type: &'static str,
+    body: impl Into<Body>,
+) -> RequestBuilder {
+    request.header(CONTENT_TYPE, content_type).body(body)
+}
+
+/// Set a `multipart/form-data` request body.
+pub fn multipart_body<T>(request: RequestBuilder, body: T) -> Result<RequestBuilder, ClientError>
+where
+    T: IntoMultipart,
+{
+    Ok(request.multipart(body.into_form()?))
+}
+
+/// Apply the content type of a multipart encoding to a part.
+///
+/// An encoding may list several acceptable content types, or use wildcards; only a single concrete content type
+/// is applied.
+fn with_content_type(part: Part, content_type: Option<&'static str>) -> Result<Part, ClientError> {
+    match content_type {
+        Some(content_type) if !content_type.contains([',', '*']) => {
+            part.mime_str(content_type).map_err(Into::into)
+        }
+        _ => Ok(part),
+    }
+}
+
+/// Add a part to a form, serializing its value.
+///
+/// Strings are sent as text; all other values are sent as JSON.
+pub fn multipart_part<T>(
+    form: Form,
+    name: &'static str,
+    content_type: Option<&'static str>,
+    value: &T,
+) -> Result<Form, ClientError>
+where
+    T: Serialize,
+{
+    let part = match serde_json::to_value(value)? {
+        serde_json::Value::String(text) => Part::text(text),
+        value => Part::bytes(serde_json::to_vec(&value)?).mime_str("application/json")?,
+    };
+    Ok(form.part(name, with_content_type(part, content_type)?))
+}
+
+/// Add a binary part to a form.
+///
+/// Without an explicit content type, the part is sent as `application/octet-stream`.
+pub fn multipart_bytes(
+    form: Form,
+    name: &'static str,
+    content_type: Option<&'static str>,
+    value: Vec<u8>,
+) -> Result<Form, ClientError> {
+    let part = Part::bytes(value).mime_str("application/octet-stream")?;
+    Ok(form.part(name, with_content_type(part, content_type)?))
+}
+
+/// The essence of the content type of a response, without any parameters.
+pub fn content_type(response: &Response) -> Option<String> {
+    let content_type = response.headers().get(CONTENT_TYPE)?.to_str().ok()?;
+    let essence = content_type.split(';').next()?;
+    Some(essence.trim().to_ascii_lowercase())
+}
+
+/// Decode a JSON response body.
+pub async fn json<T>(response: Response) -> Result<T, ClientError>
+where
+    T: DeserializeOwned,
+{
+    let body = response.bytes().await?;
+    serde_json::from_slice(&body).map_err(Into::into)
+}
+
+/// Decode a `text/*` response body, via its canonical form.
+pub async fn text<T>(response: Response) -> Result<T, ClientError>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned);
+    let body = response.bytes().await?;
+    crate::text::decode(content_type.as_deref(), &body).map_err(Into::into)
+}
+
+/// Decode a response header, via its canonical form.
+pub fn response_header<T>(headers: &HeaderMap, name: &'static str) -> Result<Option<T>, ClientError>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    headers
+        .get(name)
+        .map(|value| crate::text::decode(None, value.as_bytes()))
+        .transpose()
+        .map_err(|source| ClientError::Header { name, source })
+}
+
+/// Require that a response header is present.
+pub fn required<T>(name: &'static str, value: Option<T>) -> Result<T, ClientError> {
+    value.ok_or(ClientError::MissingHeader(name))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    #[error("failed to encode or decode json")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Form(#[from] FormError),
+    #[error(transparent)]
+    Text(#[from] TextError),
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+    #[error("invalid header \"{name}\"")]
+    Header {
+        name: &'static str,
+        #[source]
+        source: TextError,
+    },
+    #[error("missing required header \"{0}\"")]
+    MissingHeader(&'static str),
+    #[error(transparent)]
+    Status(#[from] StatusOutOfRange),
+    #[error("unexpected response status: {0}")]
+    UnexpectedStatus(StatusCode),
+    #[error("unexpected response content type: {}", .0.as_deref().unwrap_or("none"))]
+    UnexpectedContentType(Option<String>),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{msg}")]
+pub struct Error {
+    msg: String,
+    #[source]
+    inner: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl Error {
+    fn new(msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        let inner = None;
+        Self { msg, inner }
+    }
+
+    fn context<C, E>(context: C) -> impl FnOnce(E) -> Self
+    where
+        C: Into<String>,
+        Box<dyn 'static + std::error::Error + Send + Sync>: From<E>,
+    {
+        move |err| {
+            let msg = context.into();
+            let inner = Some(err.into());
+            Self { msg, inner }
+        }
+    }
+}
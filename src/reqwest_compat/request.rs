@@ -0,0 +1,167 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::request_body::{body_object, resolve_concrete},
+        make_ident, Endpoint, Reference, Scalar, UnknownReference, Value,
+    },
+    ApiModel,
+};
+
+use super::Error;
+
+/// Build the request for an endpoint.
+///
+/// `url` must be an expression of type `String` which holds the URL of the endpoint, with path parameters still
+/// in `{name}` notation. The emitted statements bind `request`, a `reqwest::RequestBuilder` ready to be sent.
+///
+/// Parameters and the request body are expected to be in scope under the same names as in the trait method.
+pub(crate) fn build_request<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    endpoint: &Endpoint,
+    url: TokenStream,
+) -> Result<TokenStream, Error> {
+    let prefix = quote!(openapi_gen::reqwest_compat);
+
+    let mut substitute_path_parameters = Vec::new();
+    if let Some((_ref, _item, object)) =
+        endpoint
+            .path_parameter_object(model)
+            .map_err(Error::context(
+                "attempting to extract path parameter object",
+            ))?
+    {
+        for (name, member) in &object.members {
+            let template = format!("{{{name}}}");
+            let param_ident = make_ident(&name.to_snake_case());
+            let value = if member.inline_option {
+                quote! {
+                    match &#param_ident {
+                        Some(value) => #prefix::path_segment(value)?,
+                        None => String::new(),
+                    }
+                }
+            } else {
+                quote!(#prefix::path_segment(&#param_ident)?)
+            };
+            substitute_path_parameters.push(quote! {
+                let url = url.replace(#template, &#value);
+            });
+        }
+    }
+
+    let verb = make_ident(&endpoint.verb.to_string());
+    let mut build = vec![quote! {
+        let request = self.client().request(openapi_gen::reexport::http::Method::#verb, url);
+    }];
+
+    if let Some((ref_, _item, object)) =
+        endpoint
+            .query_parameter_object(model)
+            .map_err(Error::context(
+                "attempting to extract query parameter object",
+            ))?
+    {
+        let type_ident = model
+            .definition(ref_, &name_resolver)
+            .map_err(Error::context(
+                "getting type ident of query parameter object",
+            ))?;
+        let field_names = object
+            .members
+            .keys()
+            .map(|name| make_ident(&name.to_snake_case()));
+        build.push(quote! {
+            let request = request.query(&#type_ident { #( #field_names ),* });
+        });
+    }
+
+    for (key, param) in &endpoint.headers {
+        let param_ident = make_ident(&key.to_snake_case());
+        let header_name = param.spec_name.to_lowercase();
+        let value = if param.required {
+            quote!(Some(&#param_ident))
+        } else {
+            quote!(#param_ident.as_ref())
+        };
+        // the accept header has no canonical form, but it does know how to display itself
+        let is_accept = matches!(
+            resolve_concrete(model, param.item_ref)
+                .map_err(Error::context(format!("resolving header \"{key}\"")))?
+                .value,
+            Value::Scalar(Scalar::AcceptHeader)
+        );
+        let helper = if is_accept {
+            quote!(#prefix::header_display)
+        } else {
+            quote!(#prefix::header)
+        };
+        build.push(quote! {
+            let request = #helper(request, #header_name, #value)?;
+        });
+    }
+
+    if let Some(ref_) = endpoint.request_body {
+        build.push(set_request_body(model, ref_)?);
+    }
+
+    Ok(quote! {
+        let url = #url;
+        #( #substitute_path_parameters )*
+        #( #build )*
+    })
+}
+
+/// Set the request body, according to its content type.
+fn set_request_body(model: &ApiModel, ref_: Reference) -> Result<TokenStream, Error> {
+    let prefix = quote!(openapi_gen::reqwest_compat);
+    let item = model
+        .resolve(ref_)
+        .map_err(Error::context("getting item for request body"))?;
+    let content_type = item.content_type.as_deref().unwrap_or("application/json");
+    let body = make_ident("request_body");
+
+    let set_body = if item.is_multipart_form_data() {
+        quote!(#prefix::multipart_body(request, #body)?)
+    } else if item.is_form_urlencoded() {
+        body_object(model, ref_)
+            .map_err(Error::context("resolving form request body"))?
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "form request body '{}' must be an object",
+                    &item.rust_name
+                ))
+            })?;
+        quote!(#prefix::form_body(request, &#body)?)
+    } else if item.is_text() && item.is_canonical_scalar(model) {
+        quote!(#prefix::text_body(request, #content_type, &#body)?)
+    } else {
+        let concrete =
+            resolve_concrete(model, ref_).map_err(Error::context("resolving request body"))?;
+        match &concrete.value {
+            Value::Scalar(Scalar::OctetStream | Scalar::Binary) if !item.is_json() => {
+                quote!(#prefix::bytes_body(request, #content_type, #body))
+            }
+            // json bodies express their own nullability
+            _ => {
+                return Ok(
+                    quote!(let request = #prefix::json_body(request, #content_type, &#body)?;),
+                )
+            }
+        }
+    };
+
+    if item.nullable {
+        Ok(quote! {
+            let request = match #body {
+                Some(#body) => #set_body,
+                None => request,
+            };
+        })
+    } else {
+        Ok(quote!(let request = #set_body;))
+    }
+}
@@ -0,0 +1,257 @@
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::request_body::resolve_concrete,
+        make_ident,
+        value::{
+            object::BODY_IDENT,
+            one_of_enum::{Variant, VariantStatus},
+        },
+        Item, Object, Reference, Scalar, UnknownReference, Value,
+    },
+    ApiModel,
+};
+
+use super::Error;
+
+/// Decode the body of a response into an instance of `body`.
+///
+/// `content` is the item which declares the content type of the response.
+fn decode_body<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    content: &Item,
+    body: Reference,
+) -> Result<TokenStream, Error> {
+    let prefix = quote!(openapi_gen::reqwest_compat);
+    let item = model
+        .resolve(body)
+        .map_err(Error::context("getting response body item"))?;
+    let type_ = model
+        .definition(body, &name_resolver)
+        .map_err(Error::context("getting response body type"))?;
+    let concrete =
+        resolve_concrete(model, body).map_err(Error::context("resolving response body"))?;
+
+    let is_text = content.is_text() || item.is_text();
+    let decode = match &concrete.value {
+        Value::Scalar(Scalar::Unit) => quote!(()),
+        _ if is_text && !item.nullable && item.is_canonical_scalar(model) => {
+            quote!(#prefix::text::<#type_>(response).await?)
+        }
+        Value::Scalar(Scalar::OctetStream) => quote!(response.bytes().await?),
+        Value::Scalar(Scalar::Binary) if !content.is_json() => {
+            quote!(response.bytes().await?.to_vec())
+        }
+        _ => quote!(#prefix::json::<#type_>(response).await?),
+    };
+    Ok(decode)
+}
+
+/// Decode a response into the payload of a single variant of the response enum.
+fn decode_variant<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    response_ident: &Ident,
+    variant: &Variant,
+) -> Result<TokenStream, Error> {
+    let prefix = quote!(openapi_gen::reqwest_compat);
+    let variant_name = variant
+        .computed_name()
+        .ok_or_else(|| Error::new("failed to get computed variant name for response variant"))?;
+    let variant_ident = make_ident(variant_name);
+    let item = model
+        .resolve(variant.definition)
+        .map_err(Error::context(format!(
+            "getting item for variant {variant_name}"
+        )))?;
+
+    let payload = if let Value::Object(Object {
+        is_generated_body_and_headers: true,
+        members,
+    }) = &item.value
+    {
+        let item_ident = make_ident(&item.rust_name);
+        let mut fields = Vec::with_capacity(members.len());
+        let mut body = None;
+        for (member_name, member) in members {
+            let member_ident = make_ident(member_name);
+            if member_name == BODY_IDENT {
+                let member_item = model
+                    .resolve(member.definition)
+                    .map_err(Error::context("getting response body item"))?;
+                let content = if item.content_type.is_some() {
+                    item
+                } else {
+                    member_item
+                };
+                let decode = decode_body(model, &name_resolver, content, member.definition)?;
+                body = Some(quote!(let #member_ident = #decode;));
+                continue;
+            }
+
+            let header_item = model
+                .resolve(member.definition)
+                .map_err(Error::context(format!("getting header item {member_name}")))?;
+            let header_name = header_item.spec_name.to_lowercase();
+            let type_ = model
+                .definition(member.definition, &name_resolver)
+                .map_err(Error::context(format!(
+                    "getting type of header {member_name}"
+                )))?;
+            let mut value = quote!(#prefix::response_header::<#type_>(&headers, #header_name)?);
+            if !member.inline_option {
+                value = quote!(#prefix::required(#header_name, #value)?);
+            }
+            fields.push(quote!(let #member_ident = #value;));
+        }
+
+        let member_idents = members.keys().map(|member| make_ident(member));
+        quote! {{
+            let headers = response.headers().clone();
+            #( #fields )*
+            #body
+            #item_ident { #( #member_idents ),* }
+        }}
+    } else {
+        decode_body(model, &name_resolver, item, variant.definition)?
+    };
+
+    let payload = match variant.status {
+        Some(VariantStatus::Range(_) | VariantStatus::Default) => {
+            quote!(openapi_gen::WithStatus::new(status, #payload)?)
+        }
+        _ => payload,
+    };
+
+    Ok(quote!(Ok(#response_ident::#variant_ident(#payload))))
+}
+
+/// The content type of a variant, without parameters, if it declares one.
+fn variant_content_type(model: &ApiModel, variant: &Variant) -> Result<Option<String>, Error> {
+    let mut item = model
+        .resolve(variant.definition)
+        .map_err(Error::context("getting response variant item"))?;
+    if item.content_type.is_none() {
+        if let Value::Object(Object {
+            is_generated_body_and_headers: true,
+            members,
+        }) = &item.value
+        {
+            if let Some(body) = members.get(BODY_IDENT) {
+                item = model
+                    .resolve(body.definition)
+                    .map_err(Error::context("getting response body item"))?;
+            }
+        }
+    }
+    Ok(item.content_type.as_deref().and_then(|content_type| {
+        let essence = content_type.split(';').next()?;
+        Some(essence.trim().to_ascii_lowercase())
+    }))
+}
+
+/// Decode a response into the response enum.
+///
+/// The emitted expression has type `Result<Response, ClientError>`.
+/// It expects `response: reqwest::Response` and `status: http::StatusCode` to be in scope.
+pub(crate) fn decode_response<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    response_enum: Reference,
+) -> Result<TokenStream, Error> {
+    let prefix = quote!(openapi_gen::reqwest_compat);
+    let item = model
+        .resolve(response_enum)
+        .map_err(Error::context("getting response item"))?;
+    let response_ident = make_ident(&item.rust_name);
+    let Value::OneOfEnum(oo_enum) = &item.value else {
+        return Err(Error::new(format!(
+            "expected response '{}' to be OneOfEnum",
+            &item.rust_name
+        )));
+    };
+
+    // group variants by status, preserving the order in which each status first appears
+    let mut groups: Vec<(VariantStatus, Vec<&Variant>)> = Vec::new();
+    for variant in &oo_enum.variants {
+        let status = variant
+            .status
+            .ok_or_else(|| Error::new("failed to get status for response variant"))?;
+        match groups.iter_mut().find(|(group, _)| *group == status) {
+            Some((_, variants)) => variants.push(variant),
+            None => groups.push((status, vec![variant])),
+        }
+    }
+    // specific codes take precedence over ranges, which take precedence over the default
+    groups.sort_by_key(|(status, _)| match status {
+        VariantStatus::Code(_) => 0,
+        VariantStatus::Range(_) => 1,
+        VariantStatus::Default => 2,
+    });
+
+    let mut arms = Vec::with_capacity(groups.len() + 1);
+    for (status, variants) in &groups {
+        let pattern = match status {
+            VariantStatus::Code(code) => {
+                let code = Literal::u16_unsuffixed(code.as_u16());
+                quote!(#code)
+            }
+            VariantStatus::Range(class) => {
+                let low = Literal::u16_unsuffixed(class * 100);
+                let high = Literal::u16_unsuffixed(class * 100 + 99);
+                quote!(#low..=#high)
+            }
+            VariantStatus::Default => quote!(_),
+        };
+
+        let decode = if let [variant] = variants.as_slice() {
+            decode_variant(model, &name_resolver, &response_ident, variant)?
+        } else {
+            let mut content_arms = Vec::with_capacity(variants.len() + 1);
+            let mut has_fallback = false;
+            for variant in variants {
+                let decode = decode_variant(model, &name_resolver, &response_ident, variant)?;
+                match variant_content_type(model, variant)? {
+                    Some(content_type) => {
+                        content_arms.push(quote!(Some(#content_type) => #decode,))
+                    }
+                    None => {
+                        has_fallback = true;
+                        content_arms.push(quote!(_ => #decode,));
+                        break;
+                    }
+                }
+            }
+            if !has_fallback {
+                content_arms.push(quote! {
+                    content_type => Err(#prefix::ClientError::UnexpectedContentType(
+                        content_type.map(ToOwned::to_owned),
+                    )),
+                });
+            }
+            quote! {
+                match #prefix::content_type(&response).as_deref() {
+                    #( #content_arms )*
+                }
+            }
+        };
+
+        arms.push(quote!(#pattern => #decode,));
+    }
+
+    if !groups
+        .iter()
+        .any(|(status, _)| matches!(status, VariantStatus::Default))
+    {
+        arms.push(quote!(_ => Err(#prefix::ClientError::UnexpectedStatus(status)),));
+    }
+
+    Ok(quote! {
+        match status.as_u16() {
+            #( #arms )*
+        }
+    })
+}
@@ -0,0 +1,61 @@
+//! Present credentials on outgoing requests.
+//!
+//! Generated credential types implement [`Authenticate`] in terms of the functions in this module.
+
+use http::header::{AUTHORIZATION, COOKIE};
+use reqwest::RequestBuilder;
+
+use crate::security::{Basic, Bearer};
+
+/// Credentials which can be presented on a request.
+pub trait Authenticate {
+    /// Add these credentials to a request.
+    fn authenticate(&self, request: RequestBuilder) -> RequestBuilder;
+}
+
+/// Optional credentials are presented only when they are present.
+impl<T> Authenticate for Option<T>
+where
+    T: Authenticate,
+{
+    fn authenticate(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            Some(credentials) => credentials.authenticate(request),
+            None => request,
+        }
+    }
+}
+
+/// Present an API key in a header.
+pub fn api_key_header(request: RequestBuilder, name: &'static str, key: &str) -> RequestBuilder {
+    request.header(name, key)
+}
+
+/// Present an API key in a query parameter.
+pub fn api_key_query(request: RequestBuilder, name: &'static str, key: &str) -> RequestBuilder {
+    request.query(&[(name, key)])
+}
+
+/// Present an API key in a cookie.
+pub fn api_key_cookie(request: RequestBuilder, name: &'static str, key: &str) -> RequestBuilder {
+    request.header(COOKIE, format!("{name}={key}"))
+}
+
+/// Present credentials of the HTTP `Basic` authentication scheme.
+pub fn basic(request: RequestBuilder, credentials: &Basic) -> RequestBuilder {
+    request.basic_auth(&credentials.username, Some(&credentials.password))
+}
+
+/// Present credentials of the HTTP `Bearer` authentication scheme.
+pub fn bearer(request: RequestBuilder, credentials: &Bearer) -> RequestBuilder {
+    request.bearer_auth(&credentials.token)
+}
+
+/// Present credentials of any other HTTP authentication scheme.
+pub fn credentials(
+    request: RequestBuilder,
+    auth_scheme: &'static str,
+    credentials: &str,
+) -> RequestBuilder {
+    request.header(AUTHORIZATION, format!("{auth_scheme} {credentials}"))
+}
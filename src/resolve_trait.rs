@@ -164,3 +164,18 @@ fn get_header_name(reference: &str) -> Result<&str> {
     parse_reference(reference, "headers")
 }
 impl_resolve_for!(ReferenceOr<Header>; get_header_name; headers);
+
+fn get_link_name(reference: &str) -> Result<&str> {
+    parse_reference(reference, "links")
+}
+impl_resolve_for!(ReferenceOr<Link>; get_link_name; links);
+
+fn get_security_scheme_name(reference: &str) -> Result<&str> {
+    parse_reference(reference, "securitySchemes")
+}
+impl_resolve_for!(ReferenceOr<SecurityScheme>; get_security_scheme_name; security_schemes);
+
+fn get_example_name(reference: &str) -> Result<&str> {
+    parse_reference(reference, "examples")
+}
+impl_resolve_for!(ReferenceOr<Example>; get_example_name; examples);
@@ -0,0 +1,116 @@
+//! Credentials presented according to the security schemes of an API.
+//!
+//! Each security scheme of the specification produces a credential type wrapping one of the types in this module.
+//! Operations which declare security requirements receive the credentials which satisfied one of them.
+
+use http::StatusCode;
+
+#[cfg(any(
+    feature = "actix-support",
+    feature = "axum-support",
+    feature = "tower-support"
+))]
+pub mod parts;
+
+/// Credentials of the HTTP `Basic` authentication scheme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basic {
+    pub username: String,
+    pub password: String,
+}
+
+/// Credentials of the HTTP `Bearer` authentication scheme, also used by OAuth2 and OpenID Connect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bearer {
+    pub token: String,
+}
+
+/// A single security requirement of an operation.
+///
+/// Each entry is the name of a security scheme, and the OAuth2 scopes which it must grant. Every scheme of the
+/// requirement must be satisfied. An operation is permitted if any one of its requirements is satisfied.
+pub type Requirement = &'static [(&'static str, &'static [&'static str])];
+
+/// Why the credentials of a request were rejected.
+#[derive(Debug, thiserror::Error)]
+pub enum SecurityRejection {
+    #[error("missing credentials for security scheme \"{scheme}\"")]
+    Missing {
+        /// Name of the security scheme.
+        scheme: &'static str,
+        /// HTTP authentication scheme with which the credentials are presented, such as `Bearer`.
+        auth_scheme: &'static str,
+    },
+    #[error("invalid credentials for security scheme \"{scheme}\"")]
+    Invalid {
+        /// Name of the security scheme.
+        scheme: &'static str,
+        /// HTTP authentication scheme with which the credentials are presented, such as `Bearer`.
+        auth_scheme: &'static str,
+    },
+    #[error("credentials do not satisfy any security requirement")]
+    Insufficient,
+}
+
+impl SecurityRejection {
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            SecurityRejection::Missing { .. } | SecurityRejection::Invalid { .. } => {
+                StatusCode::UNAUTHORIZED
+            }
+            SecurityRejection::Insufficient => StatusCode::FORBIDDEN,
+        }
+    }
+
+    /// The value of the `WWW-Authenticate` header which accompanies a `401 Unauthorized` response.
+    ///
+    /// The challenge names the authentication scheme with which credentials should be presented, and the
+    /// security scheme as its realm.
+    pub fn challenge(&self) -> Option<String> {
+        match self {
+            SecurityRejection::Missing {
+                scheme,
+                auth_scheme,
+            }
+            | SecurityRejection::Invalid {
+                scheme,
+                auth_scheme,
+            } => Some(format!("{auth_scheme} realm=\"{scheme}\"")),
+            SecurityRejection::Insufficient => None,
+        }
+    }
+
+    /// The rejection for a security requirement, given the rejection of each of its schemes.
+    ///
+    /// `None` stands for a scheme which was satisfied. If every scheme is missing, so is the requirement;
+    /// if only some are, the credentials are insufficient.
+    pub fn requirement<const N: usize>(schemes: [Option<Self>; N]) -> Self {
+        let mut satisfied = false;
+        let mut missing = None;
+        let mut invalid = None;
+        for scheme in schemes {
+            match scheme {
+                None => satisfied = true,
+                Some(rejection @ Self::Missing { .. }) => missing = missing.or(Some(rejection)),
+                Some(rejection) => invalid = invalid.or(Some(rejection)),
+            }
+        }
+        match (invalid, missing) {
+            (Some(rejection), _) => rejection,
+            (None, Some(rejection)) if !satisfied => rejection,
+            _ => Self::Insufficient,
+        }
+    }
+
+    /// Combine the rejections of two alternatives, keeping the more informative of the two.
+    pub fn or(self, other: Self) -> Self {
+        match (&self, &other) {
+            (Self::Invalid { .. }, _) => self,
+            (_, Self::Invalid { .. }) => other,
+            (Self::Insufficient, _) => self,
+            (_, Self::Insufficient) => other,
+            _ => self,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Extraction of credentials from the head of a `http` request.
+//!
+//! Credentials which are absent are reported as [`SecurityRejection::Missing`], so that another security
+//! requirement may still be satisfied; credentials which are present but malformed are reported as
+//! [`SecurityRejection::Invalid`].
+//!
+//! API keys are not presented via an HTTP authentication scheme; their rejections name the `ApiKey` scheme.
+
+use headers::{
+    authorization::{self, Authorization},
+    Header,
+};
+use http::{
+    header::{AUTHORIZATION, COOKIE},
+    request::Parts,
+    HeaderValue,
+};
+
+use crate::security::{Basic, Bearer, SecurityRejection};
+
+/// Authentication scheme named by the rejections of API keys.
+const API_KEY: &str = "ApiKey";
+
+fn missing(scheme: &'static str, auth_scheme: &'static str) -> SecurityRejection {
+    SecurityRejection::Missing {
+        scheme,
+        auth_scheme,
+    }
+}
+
+fn invalid(scheme: &'static str, auth_scheme: &'static str) -> SecurityRejection {
+    SecurityRejection::Invalid {
+        scheme,
+        auth_scheme,
+    }
+}
+
+/// Extract an API key from a request header.
+pub fn api_key_header(
+    parts: &Parts,
+    scheme: &'static str,
+    name: &'static str,
+) -> Result<String, SecurityRejection> {
+    let value = parts
+        .headers
+        .get(name)
+        .ok_or_else(|| missing(scheme, API_KEY))?;
+    value
+        .to_str()
+        .map(ToOwned::to_owned)
+        .map_err(|_| invalid(scheme, API_KEY))
+}
+
+/// Extract an API key from the query string.
+pub fn api_key_query(
+    parts: &Parts,
+    scheme: &'static str,
+    name: &'static str,
+) -> Result<String, SecurityRejection> {
+    let query = parts.uri.query().unwrap_or_default();
+    form_urlencoded::parse(query.as_bytes())
+        .find(|(key, _value)| key == name)
+        .map(|(_key, value)| value.into_owned())
+        .ok_or_else(|| missing(scheme, API_KEY))
+}
+
+/// Extract an API key from a cookie.
+pub fn api_key_cookie(
+    parts: &Parts,
+    scheme: &'static str,
+    name: &'static str,
+) -> Result<String, SecurityRejection> {
+    for value in parts.headers.get_all(COOKIE) {
+        let value = value.to_str().map_err(|_| invalid(scheme, API_KEY))?;
+        let cookie = value
+            .split(';')
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(key, _value)| *key == name);
+        if let Some((_key, value)) = cookie {
+            return Ok(value.to_owned());
+        }
+    }
+    Err(missing(scheme, API_KEY))
+}
+
+/// Get the `Authorization` header, if it uses the given authentication scheme.
+///
+/// A header which uses a different authentication scheme is treated as absent, as it might satisfy some other
+/// security scheme.
+fn authorization<'a>(
+    parts: &'a Parts,
+    scheme: &'static str,
+    auth_scheme: &'static str,
+) -> Result<&'a HeaderValue, SecurityRejection> {
+    let value = parts
+        .headers
+        .get(AUTHORIZATION)
+        .ok_or_else(|| missing(scheme, auth_scheme))?;
+    let uses_scheme = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.split_once(' '))
+        .is_some_and(|(prefix, _credentials)| prefix.eq_ignore_ascii_case(auth_scheme));
+    if !uses_scheme {
+        return Err(missing(scheme, auth_scheme));
+    }
+    Ok(value)
+}
+
+/// Extract credentials of the HTTP `Basic` authentication scheme.
+pub fn basic(parts: &Parts, scheme: &'static str) -> Result<Basic, SecurityRejection> {
+    let value = authorization(parts, scheme, "Basic")?;
+    let basic = Authorization::<authorization::Basic>::decode(&mut std::iter::once(value))
+        .map_err(|_| invalid(scheme, "Basic"))?;
+    Ok(Basic {
+        username: basic.username().to_owned(),
+        password: basic.password().to_owned(),
+    })
+}
+
+/// Extract credentials of the HTTP `Bearer` authentication scheme.
+pub fn bearer(parts: &Parts, scheme: &'static str) -> Result<Bearer, SecurityRejection> {
+    let value = authorization(parts, scheme, "Bearer")?;
+    let bearer = Authorization::<authorization::Bearer>::decode(&mut std::iter::once(value))
+        .map_err(|_| invalid(scheme, "Bearer"))?;
+    Ok(Bearer {
+        token: bearer.token().to_owned(),
+    })
+}
+
+/// Extract the credentials of any other HTTP authentication scheme, without interpreting them.
+pub fn credentials(
+    parts: &Parts,
+    scheme: &'static str,
+    auth_scheme: &'static str,
+) -> Result<String, SecurityRejection> {
+    let value = authorization(parts, scheme, auth_scheme)?;
+    let (_prefix, credentials) = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.split_once(' '))
+        .ok_or_else(|| invalid(scheme, auth_scheme))?;
+    Ok(credentials.trim().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use super::*;
+
+    fn parts(authorization: Option<&'static str>) -> Parts {
+        let mut request = http::Request::builder();
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+        request.body(()).unwrap().into_parts().0
+    }
+
+    #[test]
+    fn absent_credentials_are_missing() {
+        for authorization in [None, Some("Basic dXNlcjpwYXNz")] {
+            let rejection = bearer(&parts(authorization), "bearerAuth").unwrap_err();
+            assert!(matches!(rejection, SecurityRejection::Missing { .. }));
+        }
+    }
+
+    #[test]
+    fn malformed_credentials_are_unauthorized_with_a_challenge() {
+        let rejection = basic(&parts(Some("Basic not-base64!")), "basicAuth").unwrap_err();
+        assert!(matches!(rejection, SecurityRejection::Invalid { .. }));
+        assert_eq!(rejection.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            rejection.challenge().as_deref(),
+            Some("Basic realm=\"basicAuth\"")
+        );
+    }
+
+    #[test]
+    fn partial_requirements_are_forbidden() {
+        let rejection =
+            SecurityRejection::requirement([None, bearer(&parts(None), "bearerAuth").err()]);
+        assert_eq!(rejection.status(), StatusCode::FORBIDDEN);
+        assert_eq!(rejection.challenge(), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! Responses whose status code is determined at runtime.
+//!
+//! Most responses are declared for a single status code, which is known when the code is generated.
+//! Responses declared for a status code range such as `5XX`, and `default` responses, instead carry
+//! their status code alongside their body in a [`WithStatus`].
+
+use http::StatusCode;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Types which know the status code with which they should be sent.
+pub trait AsStatusCode {
+    /// The status code with which this value should be sent.
+    fn as_status_code(&self) -> StatusCode;
+}
+
+impl AsStatusCode for StatusCode {
+    fn as_status_code(&self) -> StatusCode {
+        *self
+    }
+}
+
+/// A response body, together with the status code with which it is sent.
+///
+/// `CLASS` is the class of status code which this response may be sent with: `5` permits any status in
+/// `500..=599`. A `CLASS` of `0` permits any status code at all; this is used for `default` responses.
+///
+/// Construction validates the status code against its class, so a `WithStatus` always holds a permissible status.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithStatus<T, const CLASS: u16 = 0> {
+    status: StatusCode,
+    body: T,
+}
+
+impl<T, const CLASS: u16> WithStatus<T, CLASS> {
+    /// Create a new response with the given status code.
+    ///
+    /// Fails if the status code is not within the class of this response.
+    pub fn new(status: StatusCode, body: T) -> Result<Self, StatusOutOfRange> {
+        if Self::permits(status) {
+            Ok(Self { status, body })
+        } else {
+            Err(StatusOutOfRange {
+                status,
+                class: CLASS,
+            })
+        }
+    }
+
+    /// `true` when the status code is within the class of this response.
+    pub fn permits(status: StatusCode) -> bool {
+        CLASS == 0 || status.as_u16() / 100 == CLASS
+    }
+
+    /// The status code of this response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The body of this response.
+    pub fn body(&self) -> &T {
+        &self.body
+    }
+
+    /// Discard the status code of this response, returning its body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    /// Decompose this response into its status code and body.
+    pub fn into_parts(self) -> (StatusCode, T) {
+        (self.status, self.body)
+    }
+}
+
+impl<T, const CLASS: u16> AsStatusCode for WithStatus<T, CLASS> {
+    fn as_status_code(&self) -> StatusCode {
+        self.status
+    }
+}
+
+#[cfg(feature = "axum-support")]
+impl<T, const CLASS: u16> axum::response::IntoResponse for WithStatus<T, CLASS>
+where
+    T: axum::response::IntoResponse,
+{
+    fn into_response(self) -> axum::response::Response {
+        (self.status, self.body).into_response()
+    }
+}
+
+#[derive(Serialize)]
+struct WithStatusRef<'a, T> {
+    status_code: u16,
+    body: &'a T,
+}
+
+#[derive(Deserialize)]
+struct WithStatusRepr<T> {
+    status_code: u16,
+    body: T,
+}
+
+impl<T, const CLASS: u16> Serialize for WithStatus<T, CLASS>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        WithStatusRef {
+            status_code: self.status.as_u16(),
+            body: &self.body,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T, const CLASS: u16> Deserialize<'de> for WithStatus<T, CLASS>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let WithStatusRepr { status_code, body } = WithStatusRepr::deserialize(deserializer)?;
+        let status = StatusCode::from_u16(status_code).map_err(D::Error::custom)?;
+        Self::new(status, body).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("status code {status} is not within the permitted range {class}XX")]
+pub struct StatusOutOfRange {
+    pub status: StatusCode,
+    pub class: u16,
+}
@@ -0,0 +1,160 @@
+//! Encoding and decoding of `text/*` bodies through [`CanonicalForm`].
+//!
+//! Text bodies with scalar schemas are the text of their canonical form, encoded in the charset named by
+//! the `charset` parameter of their content type. UTF-8 is assumed when no charset is named.
+
+use std::borrow::{Borrow, Cow};
+
+use serde_json::Number;
+
+use crate::{CanonicalForm, CanonicalizeError, Reason, ValidationError};
+
+/// Types from which [`CanonicalForm::validate`] can parse, which can themselves be parsed from text.
+pub trait FromText {
+    /// An owned instance of this type.
+    type Owned: Borrow<Self>;
+
+    /// Parse an instance of this type from text.
+    fn from_text(text: &str) -> Result<Self::Owned, Reason>;
+}
+
+impl FromText for str {
+    type Owned = String;
+
+    fn from_text(text: &str) -> Result<Self::Owned, Reason> {
+        Ok(text.to_owned())
+    }
+}
+
+impl FromText for Number {
+    type Owned = Number;
+
+    fn from_text(text: &str) -> Result<Self::Owned, Reason> {
+        text.trim().parse().map_err(Reason::from_err)
+    }
+}
+
+impl FromText for bool {
+    type Owned = bool;
+
+    fn from_text(text: &str) -> Result<Self::Owned, Reason> {
+        text.trim().parse().map_err(Reason::from_err)
+    }
+}
+
+/// The value of the `charset` parameter of a content type, if any.
+fn charset_parameter(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Character sets supported for text bodies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Charset {
+    #[default]
+    Utf8,
+    UsAscii,
+    Latin1,
+}
+
+impl Charset {
+    /// Determine the charset named by the `charset` parameter of a content type.
+    pub fn of(content_type: Option<&str>) -> Result<Self, TextError> {
+        let Some(charset) = content_type.and_then(charset_parameter) else {
+            return Ok(Self::default());
+        };
+
+        match charset.to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Ok(Self::Utf8),
+            "us-ascii" | "ascii" => Ok(Self::UsAscii),
+            "iso-8859-1" | "latin1" | "l1" => Ok(Self::Latin1),
+            _ => Err(TextError::UnsupportedCharset(charset.to_owned())),
+        }
+    }
+
+    /// The name of this charset, as it appears in a content type.
+    pub fn name(self) -> &'static str {
+        match self {
+            Charset::Utf8 => "utf-8",
+            Charset::UsAscii => "us-ascii",
+            Charset::Latin1 => "iso-8859-1",
+        }
+    }
+
+    /// Decode text in this charset.
+    pub fn decode(self, data: &[u8]) -> Result<Cow<'_, str>, TextError> {
+        match self {
+            Charset::Utf8 => std::str::from_utf8(data)
+                .map(Cow::Borrowed)
+                .map_err(|_| TextError::Decode(self)),
+            Charset::UsAscii => data
+                .is_ascii()
+                .then(|| String::from_utf8_lossy(data))
+                .ok_or(TextError::Decode(self)),
+            Charset::Latin1 => Ok(Cow::Owned(data.iter().map(|&byte| byte as char).collect())),
+        }
+    }
+
+    /// Encode text in this charset.
+    pub fn encode(self, text: &str) -> Result<Vec<u8>, TextError> {
+        match self {
+            Charset::Utf8 => Ok(text.as_bytes().to_owned()),
+            Charset::UsAscii => text
+                .is_ascii()
+                .then(|| text.as_bytes().to_owned())
+                .ok_or(TextError::Encode(self)),
+            Charset::Latin1 => text
+                .chars()
+                .map(|character| u8::try_from(character).map_err(|_| TextError::Encode(self)))
+                .collect(),
+        }
+    }
+}
+
+/// Decode a text body of the given content type via its canonical form.
+pub fn decode<T>(content_type: Option<&str>, data: &[u8]) -> Result<T, TextError>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    let text = Charset::of(content_type)?.decode(data)?;
+    let parseable = <T::ParseableFrom as FromText>::from_text(&text)
+        .map_err(|reason| TextError::Validate(ValidationError::reason::<T>(reason)))?;
+    T::validate(Borrow::<T::ParseableFrom>::borrow(&parseable)).map_err(TextError::Validate)
+}
+
+/// Encode a value as a text body of the given content type via its canonical form.
+///
+/// Returns the content type to send, which always names its charset, and the encoded body.
+pub fn encode<T>(content_type: &str, value: &T) -> Result<(String, Vec<u8>), TextError>
+where
+    T: CanonicalForm,
+{
+    let charset = Charset::of(Some(content_type))?;
+    let text = value.canonicalize()?.to_string();
+    let data = charset.encode(&text)?;
+    let content_type = if charset_parameter(content_type).is_some() {
+        content_type.to_owned()
+    } else {
+        format!("{content_type}; charset={}", charset.name())
+    };
+    Ok((content_type, data))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TextError {
+    #[error("unsupported charset \"{0}\"")]
+    UnsupportedCharset(String),
+    #[error("text is not valid {}", .0.name())]
+    Decode(Charset),
+    #[error("text cannot be encoded as {}", .0.name())]
+    Encode(Charset),
+    #[error(transparent)]
+    Validate(ValidationError),
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+}
@@ -0,0 +1,639 @@
+//! Framework-agnostic `tower::Service` boilerplate generator.
+//!
+//! When the `tower` option is set, generated code includes:
+//!
+//! - `impl IntoHttpResponse` for all response types
+//! - `impl Credentials` for all credential types
+//! - `struct ApiService`, a [`tower_service::Service`] which serves an implementation of the API
+//!
+//! The service accepts any `http::Request` whose body implements [`http_body::Body`], so it can be served by
+//! hyper directly, or mounted into any framework built on `tower`. Requests are routed by method and path
+//! template, and extracted in the same way as by the router of the axum integration; responses carry the same
+//! status codes, headers, and bodies. Request bodies are buffered up to a limit, [`DEFAULT_BODY_LIMIT`] unless the
+//! service is given another. Operations with a `multipart/form-data` request body are not served; the
+//! documentation of the service lists them. The `context` option is not supported.
+//!
+//! The remaining functions in this module are used by the generated code, and are not generally useful on
+//! their own.
+
+use std::{convert::Infallible, future::Future, pin::Pin, str::FromStr};
+
+use bytes::Bytes;
+use http::{
+    header::{ALLOW, CONTENT_TYPE, WWW_AUTHENTICATE},
+    request::Parts,
+    response::Builder,
+    HeaderValue, Method, StatusCode,
+};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    codegen::{
+        endpoint::dispatch::{impl_credentials_credentials, impl_scheme_credentials},
+        Reference, UnknownReference,
+    },
+    form::{FormEncoding, FormError},
+    security::SecurityRejection,
+    text::{FromText, TextError},
+    tower_compat::{response::impl_into_http_response, service::service_items},
+    ApiModel, CanonicalForm, CanonicalizeError, ValidationError,
+};
+
+mod response;
+mod service;
+
+pub mod security;
+
+pub(crate) fn tower_items<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    if !model.options.tower {
+        return Ok(TokenStream::default());
+    }
+    if model.options.context {
+        return Err(Error::new(
+            "the `context` option is not supported by the tower integration",
+        ));
+    }
+
+    let security = quote!(openapi_gen::tower_compat::security);
+    let request = quote!(openapi_gen::reexport::http::request::Parts);
+    let scheme_impls = model
+        .security_schemes
+        .values()
+        .map(|scheme| impl_scheme_credentials(&security, &request, scheme));
+    let credentials_impls = model
+        .endpoints
+        .iter()
+        .filter_map(|endpoint| impl_credentials_credentials(model, &security, &request, endpoint));
+
+    let mut response_impls = Vec::with_capacity(model.endpoints.len());
+    for endpoint in model.endpoints.iter() {
+        response_impls.push(
+            impl_into_http_response(model, endpoint.response)
+                .map_err(Error::context("implementing `IntoHttpResponse`"))?,
+        );
+    }
+
+    let services = service_items(model, name_resolver)?;
+
+    Ok(quote! {
+        #( #scheme_impls )*
+        #( #credentials_impls )*
+        #( #response_impls )*
+        #services
+    })
+}
+
+/// The response type of generated services.
+pub type Response = http::Response<Full<Bytes>>;
+
+/// The future returned by generated services.
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+/// Encode a value into a complete response.
+///
+/// This is implemented for every response type of the API.
+pub trait IntoHttpResponse {
+    fn into_http_response(self) -> Response;
+}
+
+/// Fallible API methods respond with their error when they fail.
+impl<T, E> IntoHttpResponse for Result<T, E>
+where
+    T: IntoHttpResponse,
+    E: IntoHttpResponse,
+{
+    fn into_http_response(self) -> Response {
+        match self {
+            Ok(response) => response.into_http_response(),
+            Err(err) => err.into_http_response(),
+        }
+    }
+}
+
+impl IntoHttpResponse for Infallible {
+    fn into_http_response(self) -> Response {
+        match self {}
+    }
+}
+
+/// A value which can be the body of a response.
+pub trait IntoBody {
+    fn into_body(self) -> Full<Bytes>;
+}
+
+impl IntoBody for () {
+    fn into_body(self) -> Full<Bytes> {
+        Full::default()
+    }
+}
+
+impl IntoBody for Bytes {
+    fn into_body(self) -> Full<Bytes> {
+        Full::new(self)
+    }
+}
+
+impl IntoBody for Vec<u8> {
+    fn into_body(self) -> Full<Bytes> {
+        Full::new(self.into())
+    }
+}
+
+impl IntoBody for String {
+    fn into_body(self) -> Full<Bytes> {
+        Full::new(self.into())
+    }
+}
+
+/// The default limit on the size of request bodies, in bytes; the same as the default of axum.
+pub const DEFAULT_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Serve a request: collect its body, up to `body_limit` bytes, then handle it.
+///
+/// Requests which cannot be routed or extracted are answered by their [`Rejection`].
+pub fn serve<B, H, F>(request: http::Request<B>, body_limit: usize, handle: H) -> ResponseFuture
+where
+    B: 'static + http_body::Body + Send,
+    B::Data: Send,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    H: 'static + Send + FnOnce(Parts, Bytes) -> F,
+    F: 'static + Future<Output = Result<Response, Rejection>> + Send,
+{
+    Box::pin(async move {
+        let (parts, body) = request.into_parts();
+        let response = match Limited::new(body, body_limit).collect().await {
+            Ok(body) => handle(parts, body.to_bytes()).await,
+            Err(err) if err.is::<LengthLimitError>() => Err(Rejection::BodyTooLarge(body_limit)),
+            Err(err) => Err(Rejection::Body(err)),
+        };
+        Ok(response.unwrap_or_else(Rejection::into_response))
+    })
+}
+
+/// Answer a `HEAD` request with the response to the equivalent `GET` request, without its body.
+///
+/// The status and headers of the response are kept.
+pub fn head(response: Result<Response, Rejection>) -> Result<Response, Rejection> {
+    response.map(|response| {
+        let (parts, _body) = response.into_parts();
+        Response::from_parts(parts, Full::default())
+    })
+}
+
+/// Match a request path against a path template of the specification.
+///
+/// Returns the percent-decoded values of the template parameters, or `None` if the path does not match.
+/// Parameters may make up a whole segment, or be surrounded by literal text within a segment.
+pub fn match_path(template: &str, path: &str) -> Option<Vec<(String, String)>> {
+    let mut template_segments = template.split('/');
+    let mut path_segments = path.split('/');
+    let mut parameters = Vec::new();
+
+    loop {
+        let (template_segment, path_segment) =
+            match (template_segments.next(), path_segments.next()) {
+                (None, None) => return Some(parameters),
+                (Some(template_segment), Some(path_segment)) => (template_segment, path_segment),
+                _ => return None,
+            };
+
+        let parameter = template_segment.split_once('{').and_then(|(prefix, rest)| {
+            let (name, suffix) = rest.split_once('}')?;
+            Some((prefix, name, suffix))
+        });
+        let Some((prefix, name, suffix)) = parameter else {
+            if template_segment != path_segment {
+                return None;
+            }
+            continue;
+        };
+
+        let value = path_segment
+            .strip_prefix(prefix)?
+            .strip_suffix(suffix)
+            .filter(|value| !value.is_empty())?;
+        let value = percent_encoding::percent_decode_str(value)
+            .decode_utf8()
+            .ok()?;
+        parameters.push((name.to_owned(), value.into_owned()));
+    }
+}
+
+/// Decode the path parameters produced by [`match_path`].
+pub fn path_parameters<T>(parameters: Vec<(String, String)>) -> Result<T, Rejection>
+where
+    T: DeserializeOwned,
+{
+    // round-trip through a form so that parameters are parsed in the same way as query parameters
+    let form = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(parameters)
+        .finish();
+    serde_html_form::from_str(&form).map_err(Rejection::Path)
+}
+
+/// Decode the query parameters of a request.
+pub fn query<T>(parts: &Parts) -> Result<T, Rejection>
+where
+    T: DeserializeOwned,
+{
+    let query = parts.uri.query().unwrap_or_default();
+    serde_html_form::from_str(query).map_err(Rejection::Query)
+}
+
+/// Extract a request header, via its canonical form.
+pub fn header<T>(parts: &Parts, name: &'static str) -> Result<Option<T>, Rejection>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    parts
+        .headers
+        .get(name)
+        .map(|value| crate::text::decode(None, value.as_bytes()))
+        .transpose()
+        .map_err(|source| Rejection::Header { name, source })
+}
+
+/// Extract a request header, via its `FromStr` implementation.
+pub fn header_from_str<T>(parts: &Parts, name: &'static str) -> Result<Option<T>, Rejection>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Some(value) = parts.headers.get(name) else {
+        return Ok(None);
+    };
+    let invalid = |reason: String| Rejection::Header {
+        name,
+        source: TextError::Validate(ValidationError::reason::<T>(reason.as_str())),
+    };
+    let value = value.to_str().map_err(|err| invalid(err.to_string()))?;
+    value
+        .parse()
+        .map(Some)
+        .map_err(|err: T::Err| invalid(err.to_string()))
+}
+
+/// Require that a request header is present.
+pub fn required<T>(name: &'static str, value: Option<T>) -> Result<T, Rejection> {
+    value.ok_or(Rejection::MissingHeader(name))
+}
+
+/// Decode a JSON request body.
+pub fn json_body<T>(body: &[u8]) -> Result<T, Rejection>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice(body).map_err(Rejection::Json)
+}
+
+/// Decode an `application/x-www-form-urlencoded` request body, respecting the encodings of its array properties.
+pub fn form_body<T>(body: &[u8]) -> Result<T, Rejection>
+where
+    T: DeserializeOwned + FormEncoding,
+{
+    crate::form::from_bytes(body).map_err(Into::into)
+}
+
+/// Decode an `application/x-www-form-urlencoded` request body which is not an object of the specification.
+pub fn plain_form_body<T>(body: &[u8]) -> Result<T, Rejection>
+where
+    T: DeserializeOwned,
+{
+    serde_html_form::from_bytes(body).map_err(|err| FormError::from(err).into())
+}
+
+/// Decode a `text/*` request body, via its canonical form.
+pub fn text_body<T>(parts: &Parts, body: &[u8]) -> Result<T, Rejection>
+where
+    T: CanonicalForm,
+    T::ParseableFrom: FromText,
+{
+    let content_type = parts
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+    crate::text::decode(content_type, body).map_err(Into::into)
+}
+
+/// Begin a response with the given status.
+pub fn response(status: StatusCode) -> Builder {
+    http::Response::builder().status(status)
+}
+
+/// Produce a response, or `500 Internal Server Error` if it cannot be encoded.
+pub fn respond(response: impl FnOnce() -> Result<Response, EncodeError>) -> Response {
+    response().unwrap_or_else(|err| internal_server_error(&err))
+}
+
+fn internal_server_error(err: &dyn std::error::Error) -> Response {
+    let mut response = http::Response::new(err.to_string().into_body());
+    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+    response
+}
+
+/// Add a header to a response, via its canonical form.
+pub fn insert_header<T>(
+    response: &mut Builder,
+    name: &'static str,
+    value: &T,
+) -> Result<(), EncodeError>
+where
+    T: CanonicalForm,
+{
+    let value = HeaderValue::try_from(value.canonicalize()?.to_string())?;
+    *response = std::mem::take(response).header(name, value);
+    Ok(())
+}
+
+/// Complete a response with a JSON body.
+pub fn json<T>(
+    response: Builder,
+    content_type: &'static str,
+    body: &T,
+) -> Result<Response, EncodeError>
+where
+    T: Serialize,
+{
+    let body = serde_json::to_vec(body)?;
+    Ok(response
+        .header(CONTENT_TYPE, content_type)
+        .body(body.into_body())?)
+}
+
+/// Complete a response with a `text/*` body, via its canonical form.
+pub fn text<T>(
+    response: Builder,
+    content_type: &'static str,
+    body: &T,
+) -> Result<Response, EncodeError>
+where
+    T: CanonicalForm,
+{
+    let (content_type, body) = crate::text::encode(content_type, body)?;
+    Ok(response
+        .header(CONTENT_TYPE, content_type)
+        .body(body.into_body())?)
+}
+
+/// Complete a response with any other body.
+pub fn raw<B>(mut response: Builder, content_type: Option<&'static str>, body: B) -> Response
+where
+    B: IntoBody,
+{
+    if let Some(content_type) = content_type {
+        response = response.header(CONTENT_TYPE, content_type);
+    }
+    response
+        .body(body.into_body())
+        .unwrap_or_else(|err| internal_server_error(&err))
+}
+
+/// A response could not be encoded.
+#[derive(Debug, thiserror::Error)]
+pub enum EncodeError {
+    #[error("failed to encode json")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Text(#[from] TextError),
+    #[error(transparent)]
+    Canonicalize(#[from] CanonicalizeError),
+    #[error("invalid header value")]
+    HeaderValue(#[from] http::header::InvalidHeaderValue),
+    #[error("failed to build response")]
+    Http(#[from] http::Error),
+}
+
+/// A request could not be routed or extracted.
+#[derive(Debug, thiserror::Error)]
+pub enum Rejection {
+    #[error("no operation at this path")]
+    NotFound,
+    #[error("method not allowed at this path")]
+    MethodNotAllowed(Vec<Method>),
+    #[error("failed to read request body")]
+    Body(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("request body is larger than the limit of {0} bytes")]
+    BodyTooLarge(usize),
+    #[error(transparent)]
+    Security(#[from] SecurityRejection),
+    #[error("invalid path parameters")]
+    Path(#[source] serde_html_form::de::Error),
+    #[error("invalid query parameters")]
+    Query(#[source] serde_html_form::de::Error),
+    #[error("invalid header \"{name}\"")]
+    Header {
+        name: &'static str,
+        #[source]
+        source: TextError,
+    },
+    #[error("missing required header \"{0}\"")]
+    MissingHeader(&'static str),
+    #[error("invalid json body")]
+    Json(#[source] serde_json::Error),
+    #[error(transparent)]
+    Form(#[from] FormError),
+    #[error(transparent)]
+    Text(#[from] TextError),
+}
+
+impl Rejection {
+    /// The rejection for a path which matched no operation with the method of the request.
+    ///
+    /// `allow` lists the methods of the operations whose path matched.
+    pub fn not_routed(allow: Vec<Method>) -> Self {
+        if allow.is_empty() {
+            Self::NotFound
+        } else {
+            Self::MethodNotAllowed(allow)
+        }
+    }
+
+    /// The status code which best describes this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Rejection::NotFound => StatusCode::NOT_FOUND,
+            Rejection::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
+            Rejection::Security(rejection) => rejection.status(),
+            Rejection::BodyTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            Rejection::Body(_)
+            | Rejection::Path(_)
+            | Rejection::Query(_)
+            | Rejection::Header { .. }
+            | Rejection::MissingHeader(_) => StatusCode::BAD_REQUEST,
+            Rejection::Json(err) if err.is_data() => StatusCode::UNPROCESSABLE_ENTITY,
+            Rejection::Json(_) => StatusCode::BAD_REQUEST,
+            Rejection::Form(_) | Rejection::Text(TextError::Validate(_)) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Rejection::Text(TextError::UnsupportedCharset(_)) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Rejection::Text(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// Respond with this error.
+    ///
+    /// With feature `api-problem`, the response is a problem whose title is the error, and whose detail is its
+    /// chain of sources. Otherwise, it is the error as plain text. Rejected credentials are answered with a
+    /// `WWW-Authenticate` challenge.
+    pub fn into_response(self) -> Response {
+        let status = self.status();
+        let mut builder = response(status);
+        if let Rejection::MethodNotAllowed(allow) = &self {
+            let allow = allow
+                .iter()
+                .map(Method::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            builder = builder.header(ALLOW, allow);
+        }
+        if let Rejection::Security(rejection) = &self {
+            if let Some(challenge) = rejection.challenge() {
+                builder = builder.header(WWW_AUTHENTICATE, challenge);
+            }
+        }
+
+        #[cfg(feature = "api-problem")]
+        let response = {
+            let mut err: &dyn std::error::Error = &self;
+            let mut detail = String::new();
+            while let Some(predecessor) = err.source() {
+                detail.extend(format!("{predecessor}; ").chars());
+                err = predecessor;
+            }
+
+            let problem = http_api_problem::HttpApiProblem::new(status)
+                .title(self.to_string())
+                .detail(detail);
+            respond(|| json(builder, "application/problem+json", &problem))
+        };
+
+        #[cfg(not(feature = "api-problem"))]
+        let response = raw(builder, Some("text/plain; charset=utf-8"), self.to_string());
+
+        response
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{msg}")]
+pub struct Error {
+    msg: String,
+    #[source]
+    inner: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl Error {
+    fn new(msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        let inner = None;
+        Self { msg, inner }
+    }
+
+    fn context<C, E>(context: C) -> impl FnOnce(E) -> Self
+    where
+        C: Into<String>,
+        Box<dyn 'static + std::error::Error + Send + Sync>: From<E>,
+    {
+        move |err| {
+            let msg = context.into();
+            let inner = Some(err.into());
+            Self { msg, inner }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_body::Body as _;
+
+    use super::*;
+
+    fn parameters(pairs: &[(&str, &str)]) -> Option<Vec<(String, String)>> {
+        Some(
+            pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn literal_segments_match_exactly() {
+        assert_eq!(match_path("/pets/mine", "/pets/mine"), parameters(&[]));
+        assert_eq!(match_path("/pets/mine", "/pets/yours"), None);
+        assert_eq!(match_path("/pets/mine", "/pets"), None);
+        assert_eq!(match_path("/pets", "/pets/mine"), None);
+    }
+
+    #[test]
+    fn parameter_segments_capture_their_value() {
+        assert_eq!(
+            match_path("/pets/{id}", "/pets/7"),
+            parameters(&[("id", "7")])
+        );
+        assert_eq!(
+            match_path("/pets/{id}.json", "/pets/7.json"),
+            parameters(&[("id", "7")])
+        );
+        assert_eq!(
+            match_path("/owners/{owner}/pets/{id}", "/owners/alice/pets/7"),
+            parameters(&[("owner", "alice"), ("id", "7")])
+        );
+        assert_eq!(match_path("/pets/{id}.json", "/pets/7.xml"), None);
+        assert_eq!(match_path("/pets/{id}", "/pets/"), None);
+    }
+
+    #[test]
+    fn trailing_slashes_are_significant() {
+        assert_eq!(match_path("/pets", "/pets/"), None);
+        assert_eq!(match_path("/pets/", "/pets"), None);
+        assert_eq!(match_path("/pets/", "/pets/"), parameters(&[]));
+        assert_eq!(match_path("/pets/{id}", "/pets/7/"), None);
+    }
+
+    #[test]
+    fn parameters_are_percent_decoded() {
+        assert_eq!(
+            match_path("/pets/{name}", "/pets/Mr%20Whiskers%2FJr"),
+            parameters(&[("name", "Mr Whiskers/Jr")])
+        );
+        assert_eq!(
+            match_path("/pets/{name}", "/pets/%C3%A9clair"),
+            parameters(&[("name", "éclair")])
+        );
+        // not valid utf-8
+        assert_eq!(match_path("/pets/{name}", "/pets/%FF"), None);
+    }
+
+    #[test]
+    fn unrouted_requests_are_not_found_or_not_allowed() {
+        let response = Rejection::not_routed(Vec::new()).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(response.headers().get(ALLOW).is_none());
+
+        let response = Rejection::not_routed(vec![Method::GET, Method::HEAD]).into_response();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()[ALLOW], "GET, HEAD");
+    }
+
+    #[test]
+    fn head_responses_keep_everything_but_the_body() {
+        let get = http::Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "text/plain")
+            .body(Full::new(Bytes::from_static(b"hello")))
+            .unwrap();
+
+        let response = head(Ok(get)).unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/plain");
+        assert_eq!(response.body().size_hint().exact(), Some(0));
+    }
+}
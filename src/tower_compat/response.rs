@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{endpoint::dispatch::encode_response, make_ident, Reference},
+    tower_compat::Error,
+    ApiModel,
+};
+
+/// Implement `IntoHttpResponse` for a response type.
+///
+/// Failure to encode a header or body responds with `500 Internal Server Error`.
+pub(crate) fn impl_into_http_response(
+    model: &ApiModel,
+    response_enum: Reference,
+) -> Result<TokenStream, Error> {
+    let item = model
+        .resolve(response_enum)
+        .map_err(Error::context("getting response item"))?;
+    let response_ident = make_ident(&item.rust_name);
+
+    let respond = encode_response(model, &quote!(openapi_gen::tower_compat), response_enum)
+        .map_err(Error::context("encoding response"))?;
+
+    Ok(quote! {
+        impl openapi_gen::tower_compat::IntoHttpResponse for #response_ident {
+            fn into_http_response(self) -> openapi_gen::tower_compat::Response {
+                #respond
+            }
+        }
+    })
+}
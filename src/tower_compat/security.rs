@@ -0,0 +1,35 @@
+//! Runtime support for extracting credentials.
+//!
+//! Generated credential types implement [`Credentials`] by calling the functions in this module. Rejections
+//! are the same as those of the `axum` integration: credentials which are absent or malformed produce
+//! `401 Unauthorized` with a `WWW-Authenticate` challenge; credentials which satisfy only part of a security
+//! requirement produce `403 Forbidden`.
+
+use http::request::Parts;
+
+pub use crate::security::{
+    parts::{api_key_cookie, api_key_header, api_key_query, basic, bearer, credentials},
+    SecurityRejection,
+};
+
+/// Credentials which can be extracted from the head of a request.
+pub trait Credentials: Sized {
+    /// Extract these credentials from the head of a request.
+    fn extract(parts: &Parts) -> Result<Self, SecurityRejection>;
+}
+
+/// Operations which permit anonymous access receive credentials only when they are present.
+///
+/// Credentials which are present but invalid are still rejected.
+impl<T> Credentials for Option<T>
+where
+    T: Credentials,
+{
+    fn extract(parts: &Parts) -> Result<Self, SecurityRejection> {
+        match T::extract(parts) {
+            Ok(credentials) => Ok(Some(credentials)),
+            Err(SecurityRejection::Missing { .. }) => Ok(None),
+            Err(rejection) => Err(rejection),
+        }
+    }
+}
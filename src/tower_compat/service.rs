@@ -0,0 +1,476 @@
+use heck::ToSnakeCase;
+use indexmap::IndexMap;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    codegen::{
+        endpoint::{
+            request_body::{body_object, resolve_concrete},
+            verb::Verb,
+        },
+        make_ident, Endpoint, Reference, Scalar, UnknownReference, Value,
+    },
+    ApiModel,
+};
+
+use super::Error;
+
+/// Name of the private function which handles requests for an endpoint.
+fn handler_name(endpoint: &Endpoint) -> syn::Ident {
+    make_ident(&format!("handle_{}", endpoint.function_name(None)))
+}
+
+/// Unpack a decoded parameter object into one variable per parameter.
+fn unpack_parameter_object<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    ref_: Reference,
+    object: &crate::codegen::Object,
+    decode: TokenStream,
+    source: TokenStream,
+) -> Result<(TokenStream, Vec<syn::Ident>), Error> {
+    let type_ident = model
+        .definition(ref_, &name_resolver)
+        .map_err(Error::context("getting type ident of parameter object"))?;
+    let field_names = object
+        .members
+        .keys()
+        .map(|name| make_ident(&name.to_snake_case()))
+        .collect::<Vec<_>>();
+
+    let extract = quote! {
+        let #type_ident { #( #field_names ),* } = #decode::<#type_ident>(#source)?;
+    };
+    Ok((extract, field_names))
+}
+
+/// Create the function which decodes a request for `endpoint`, and passes it to an implementation of
+/// `api_trait`.
+///
+/// Returns `None` for endpoints which cannot be served, which have a `multipart/form-data` request body.
+fn handler<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+    api_trait: &str,
+    endpoint: &Endpoint,
+) -> Result<Option<TokenStream>, Error> {
+    let prefix = quote!(openapi_gen::tower_compat);
+
+    // statements which extract the arguments from the request
+    let mut extract = Vec::new();
+    let mut parameter_idents = Vec::new();
+
+    // arguments are extracted in the same order as by the axum router:
+    //   0. credentials
+    //   1. path parameters
+    //   2. query parameters
+    //   3. header parameters
+    //   4. body
+
+    if let Some(type_) = endpoint.credentials_type() {
+        extract.push(quote! {
+            let credentials = <#type_ as #prefix::security::Credentials>::extract(parts)?;
+        });
+        parameter_idents.push(make_ident("credentials"));
+    }
+
+    if let Some((ref_, _item, object)) =
+        endpoint
+            .path_parameter_object(model)
+            .map_err(Error::context(
+                "attempting to extract path parameter object",
+            ))?
+    {
+        let (unpack, field_names) = unpack_parameter_object(
+            model,
+            &name_resolver,
+            ref_,
+            object,
+            quote!(#prefix::path_parameters),
+            quote!(path_parameters),
+        )?;
+        extract.push(unpack);
+        parameter_idents.extend(field_names);
+    }
+
+    if let Some((ref_, _item, object)) =
+        endpoint
+            .query_parameter_object(model)
+            .map_err(Error::context(
+                "attempting to extract query parameter object",
+            ))?
+    {
+        let (unpack, field_names) = unpack_parameter_object(
+            model,
+            &name_resolver,
+            ref_,
+            object,
+            quote!(#prefix::query),
+            quote!(parts),
+        )?;
+        extract.push(unpack);
+        parameter_idents.extend(field_names);
+    }
+
+    for (key, param) in endpoint.headers.iter() {
+        let item = model
+            .resolve(param.item_ref)
+            .map_err(Error::context(format!("getting item for param \"{key}\"")))?;
+
+        let type_ident =
+            model
+                .definition(param.item_ref, &name_resolver)
+                .map_err(Error::context(format!(
+                    "getting type ident for {}",
+                    &item.rust_name
+                )))?;
+        let variable_ident = make_ident(&item.rust_name.to_snake_case());
+        let header_name = param.spec_name.to_lowercase();
+
+        // the accept header has no canonical form, but it can be parsed
+        let is_accept = matches!(
+            resolve_concrete(model, param.item_ref)
+                .map_err(Error::context(format!("resolving header \"{key}\"")))?
+                .value,
+            Value::Scalar(Scalar::AcceptHeader)
+        );
+        let helper = if is_accept {
+            quote!(#prefix::header_from_str)
+        } else {
+            quote!(#prefix::header)
+        };
+
+        let mut value = quote!(#helper::<#type_ident>(parts, #header_name)?);
+        if param.required {
+            value = quote!(#prefix::required(#header_name, #value)?);
+        }
+        extract.push(quote!(let #variable_ident = #value;));
+        parameter_idents.push(variable_ident);
+    }
+
+    if let Some(ref_) = endpoint.request_body {
+        // add body parameter last
+        let item = model
+            .resolve(ref_)
+            .map_err(Error::context("getting item for request body"))?;
+
+        let type_ = model
+            .definition(ref_, &name_resolver)
+            .map_err(Error::context("getting request body type"))?;
+        let variable_ident = make_ident("request_body");
+
+        // form bodies decode objects according to their encoding
+        let form_object = if item.is_form_urlencoded() {
+            body_object(model, ref_)
+                .map_err(Error::context("resolving form request body"))?
+                .map(|(_ref, object_name, _object)| object_name)
+        } else {
+            None
+        };
+
+        if item.is_json() {
+            extract.push(quote! {
+                let #variable_ident = #prefix::json_body::<#type_>(&body)?;
+            });
+        } else if let Some(object_name) = form_object {
+            let object_ident = make_ident(object_name);
+            let decode = quote!(#prefix::form_body::<#object_ident>(&body));
+            extract.push(if item.nullable {
+                quote! {
+                    let #variable_ident = (!body.is_empty()).then(|| #decode).transpose()?;
+                }
+            } else {
+                quote!(let #variable_ident = #decode?;)
+            });
+        } else if item.is_form_urlencoded() {
+            extract.push(quote! {
+                let #variable_ident = #prefix::plain_form_body::<#type_>(&body)?;
+            });
+        } else if item.is_multipart_form_data() {
+            // the service does not decode multipart bodies, so this operation is not routed
+            return Ok(None);
+        } else if item.is_canonical_text(model) {
+            extract.push(quote! {
+                let #variable_ident = #prefix::text_body::<#type_>(parts, &body)?;
+            });
+        } else if matches!(
+            resolve_concrete(model, ref_)
+                .map_err(Error::context("resolving request body"))?
+                .value,
+            Value::Scalar(Scalar::OctetStream)
+        ) {
+            extract.push(quote!(let #variable_ident = body;));
+        } else {
+            extract.push(quote!(let #variable_ident = body.to_vec();));
+        }
+
+        parameter_idents.push(variable_ident);
+    }
+
+    let handler_ident = handler_name(endpoint);
+    let method_name = make_ident(&endpoint.function_name(None));
+    let api_trait = make_ident(api_trait);
+    let error_bound = model
+        .options
+        .is_fallible()
+        .then(|| quote!(<Instance as #api_trait>::Error: #prefix::IntoHttpResponse,));
+
+    Ok(Some(quote! {
+        #[allow(unused_variables)]
+        async fn #handler_ident<Instance>(
+            instance: &Instance,
+            parts: &openapi_gen::reexport::http::request::Parts,
+            body: openapi_gen::reexport::bytes::Bytes,
+            path_parameters: Vec<(String, String)>,
+        ) -> Result<#prefix::Response, #prefix::Rejection>
+        where
+            Instance: #api_trait + Sync,
+            #error_bound
+        {
+            #( #extract )*
+            Ok(#prefix::IntoHttpResponse::into_http_response(
+                instance.#method_name(#( #parameter_idents ),*).await,
+            ))
+        }
+    }))
+}
+
+/// Create a service which serves `endpoints` from an implementation of some API traits.
+///
+/// Routes whose path contains no parameters are tried first, so that they take precedence over templates
+/// which would also match them.
+///
+/// `unrouted` lists the endpoints which cannot be served, and so are left out of `endpoints`.
+fn service(
+    model: &ApiModel,
+    name: &str,
+    subject: &str,
+    bounds: &[&str],
+    endpoints: &[&Endpoint],
+    unrouted: &[&Endpoint],
+) -> TokenStream {
+    let mut routes = IndexMap::<&str, Vec<&Endpoint>>::new();
+    for endpoint in endpoints {
+        routes
+            .entry(endpoint.path.as_str())
+            .or_default()
+            .push(endpoint);
+    }
+    routes.sort_by(|path_a, _, path_b, _| path_a.contains('{').cmp(&path_b.contains('{')));
+
+    // nothing is used to route requests when no operation is routed
+    let allow_unused = routes
+        .is_empty()
+        .then(|| quote!(#[allow(unused_variables, unused_mut)]));
+
+    let routes = routes.into_iter().map(|(path, endpoints)| {
+        let mut methods = endpoints
+            .iter()
+            .map(|endpoint| {
+                let method = make_ident(&endpoint.verb.to_string());
+                quote!(openapi_gen::reexport::http::Method::#method)
+            })
+            .collect::<Vec<_>>();
+        let handlers = endpoints
+            .iter()
+            .map(|endpoint| handler_name(endpoint));
+
+        // `HEAD` requests are answered by the `GET` handler, unless the path has a `HEAD` operation of its own
+        let head = endpoints
+            .iter()
+            .find(|endpoint| endpoint.verb == Verb::Get)
+            .filter(|_| !endpoints.iter().any(|endpoint| endpoint.verb == Verb::Head))
+            .map(|endpoint| {
+                let handler = handler_name(endpoint);
+                quote! {
+                    openapi_gen::reexport::http::Method::HEAD => {
+                        return openapi_gen::tower_compat::head(
+                            #handler(&*instance, &parts, body, path_parameters).await,
+                        );
+                    }
+                }
+            });
+        let routed_methods = methods.clone();
+        if head.is_some() {
+            methods.push(quote!(openapi_gen::reexport::http::Method::HEAD));
+        }
+
+        quote! {
+            if let Some(path_parameters) = openapi_gen::tower_compat::match_path(#path, parts.uri.path()) {
+                match parts.method {
+                    #(
+                        #routed_methods => {
+                            return #handlers(&*instance, &parts, body, path_parameters).await;
+                        }
+                    )*
+                    #head
+                    _ => allow.extend([ #( #methods ),* ]),
+                }
+            }
+        }
+    });
+
+    let ident = make_ident(name);
+    let bounds = bounds
+        .iter()
+        .map(|bound| make_ident(bound))
+        .collect::<Vec<_>>();
+    let error_bounds = model.options.is_fallible().then(|| {
+        quote! {
+            #( <Instance as #bounds>::Error: openapi_gen::tower_compat::IntoHttpResponse, )*
+        }
+    });
+    let docs = format!(" A `tower::Service` which serves {subject}.");
+    let base_path_docs = (!model.servers.is_empty()).then(|| {
+        quote! {
+            ///
+            /// Paths are matched without [`BASE_PATH`]; strip it before calling the service to serve them there.
+        }
+    });
+
+    let unrouted_docs = (!unrouted.is_empty()).then(|| {
+        let docs = format!(
+            " Operations with a `multipart/form-data` request body are not served: {}.",
+            unrouted
+                .iter()
+                .map(|endpoint| format!("`{} {}`", endpoint.verb, endpoint.path))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        quote! {
+            ///
+            #[doc = #docs]
+        }
+    });
+
+    quote! {
+        #[doc = #docs]
+        ///
+        /// Requests are routed by method and path, and their parameters and bodies decoded, before being passed
+        /// to the instance. Requests which cannot be routed or decoded are rejected with an appropriate status.
+        #base_path_docs
+        #unrouted_docs
+        pub struct #ident<Instance> {
+            instance: ::std::sync::Arc<Instance>,
+            body_limit: usize,
+        }
+
+        impl<Instance> #ident<Instance> {
+            pub fn new(instance: Instance) -> Self {
+                let instance = ::std::sync::Arc::new(instance);
+                let body_limit = openapi_gen::tower_compat::DEFAULT_BODY_LIMIT;
+                Self { instance, body_limit }
+            }
+
+            /// Reject request bodies larger than `body_limit` bytes with `413 Payload Too Large`.
+            ///
+            /// The default is [`DEFAULT_BODY_LIMIT`][openapi_gen::tower_compat::DEFAULT_BODY_LIMIT].
+            pub fn with_body_limit(mut self, body_limit: usize) -> Self {
+                self.body_limit = body_limit;
+                self
+            }
+        }
+
+        impl<Instance> Clone for #ident<Instance> {
+            fn clone(&self) -> Self {
+                let instance = self.instance.clone();
+                let body_limit = self.body_limit;
+                Self { instance, body_limit }
+            }
+        }
+
+        impl<Instance, Body> openapi_gen::reexport::tower_service::Service<
+            openapi_gen::reexport::http::Request<Body>,
+        > for #ident<Instance>
+        where
+            Instance: 'static + #( #bounds + )* Send + Sync,
+            #error_bounds
+            Body: 'static + openapi_gen::reexport::http_body::Body + Send,
+            Body::Data: Send,
+            Body::Error: Into<Box<dyn ::std::error::Error + Send + Sync>>,
+        {
+            type Response = openapi_gen::tower_compat::Response;
+            type Error = ::std::convert::Infallible;
+            type Future = openapi_gen::tower_compat::ResponseFuture;
+
+            fn poll_ready(
+                &mut self,
+                _cx: &mut ::std::task::Context<'_>,
+            ) -> ::std::task::Poll<Result<(), Self::Error>> {
+                ::std::task::Poll::Ready(Ok(()))
+            }
+
+            #allow_unused
+            fn call(&mut self, request: openapi_gen::reexport::http::Request<Body>) -> Self::Future {
+                let instance = self.instance.clone();
+                openapi_gen::tower_compat::serve(request, self.body_limit, move |parts, body| async move {
+                    let mut allow = Vec::new();
+                    #( #routes )*
+                    Err(openapi_gen::tower_compat::Rejection::not_routed(allow))
+                })
+            }
+        }
+    }
+}
+
+/// Create the request handlers, and one `tower::Service` per API trait.
+///
+/// When emitting one trait per tag, this also creates `struct ApiService`, which requires an implementation of
+/// every trait.
+pub(crate) fn service_items<'a>(
+    model: &ApiModel,
+    name_resolver: impl Fn(Reference) -> Result<&'a str, UnknownReference>,
+) -> Result<TokenStream, Error> {
+    let api_traits = model.api_traits();
+    let mut handlers = Vec::with_capacity(model.endpoints.len());
+    let mut services = Vec::with_capacity(api_traits.len() + 1);
+    let mut all_routed = Vec::with_capacity(model.endpoints.len());
+    let mut all_unrouted = Vec::new();
+
+    for api_trait in &api_traits {
+        let mut routed = Vec::with_capacity(api_trait.endpoints.len());
+        let mut unrouted = Vec::new();
+        for endpoint in &api_trait.endpoints {
+            match handler(model, &name_resolver, api_trait.name, endpoint)? {
+                Some(handler) => {
+                    handlers.push(handler);
+                    routed.push(*endpoint);
+                }
+                None => unrouted.push(*endpoint),
+            }
+        }
+
+        let subject = format!("an instance of [`trait {0}`][{0}]", api_trait.name);
+        services.push(service(
+            model,
+            api_trait.service,
+            &subject,
+            &[api_trait.name],
+            &routed,
+            &unrouted,
+        ));
+        all_routed.extend(routed);
+        all_unrouted.extend(unrouted);
+    }
+
+    if model.options.trait_per_tag {
+        let bounds = api_traits
+            .iter()
+            .map(|api_trait| api_trait.name)
+            .collect::<Vec<_>>();
+        services.push(service(
+            model,
+            "ApiService",
+            "an implementation of every API trait",
+            &bounds,
+            &all_routed,
+            &all_unrouted,
+        ));
+    }
+
+    Ok(quote! {
+        #( #handlers )*
+        #( #services )*
+    })
+}
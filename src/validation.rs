@@ -0,0 +1,463 @@
+//! Validation of requests against the constraints of their schemas.
+//!
+//! Schema constraints such as `minLength`, `pattern`, `maximum`, or `maxItems` are not encoded in the
+//! generated Rust types, so any value which deserializes is accepted. With the `validate` option, generated
+//! code implements [`Validate`] for its types, and the generated router checks every parameter and body before
+//! calling the API. Requests with violations are rejected with a [`ValidationRejection`] which lists all of
+//! them.
+
+use std::fmt;
+
+use http::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::CanonicalForm;
+
+/// The part of a request in which a violation was found.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Path,
+    Query,
+    Header,
+    #[default]
+    Body,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match self {
+            Source::Path => "path",
+            Source::Query => "query",
+            Source::Header => "header",
+            Source::Body => "body",
+        };
+        f.write_str(source)
+    }
+}
+
+/// A value of a request which violates a constraint of its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    /// Where the value was found.
+    #[serde(rename = "in")]
+    pub source: Source,
+    /// The name of the parameter which contains the value, unless it is in the body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// JSON pointer to the value within its parameter or the body.
+    pub pointer: String,
+    /// The constraint which the value violates.
+    pub reason: String,
+    /// What was expected instead of the value, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)?;
+        if let Some(name) = &self.name {
+            write!(f, " parameter `{name}`")?;
+        }
+        if !self.pointer.is_empty() {
+            write!(f, " at `{}`", self.pointer)?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+/// Collects the violations of a request.
+///
+/// It tracks the location of the value being validated, so that each violation can say where it was found.
+#[derive(Debug, Default)]
+pub struct Violations {
+    source: Source,
+    name: Option<String>,
+    pointer: String,
+    violations: Vec<Violation>,
+}
+
+impl Violations {
+    /// Create an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate a parameter named `name`.
+    pub fn parameter(&mut self, source: Source, name: &str, validate: impl FnOnce(&mut Self)) {
+        self.source = source;
+        self.name = Some(name.to_owned());
+        self.pointer.clear();
+        validate(self);
+    }
+
+    /// Validate the body of the request.
+    pub fn body(&mut self, validate: impl FnOnce(&mut Self)) {
+        self.source = Source::Body;
+        self.name = None;
+        self.pointer.clear();
+        validate(self);
+    }
+
+    /// Validate a member or element of the current value.
+    pub fn at(&mut self, segment: impl fmt::Display, validate: impl FnOnce(&mut Self)) {
+        let len = self.pointer.len();
+        push_pointer_segment(&mut self.pointer, segment);
+        validate(self);
+        self.pointer.truncate(len);
+    }
+
+    /// Record a violation by the current value.
+    pub fn push(&mut self, reason: impl fmt::Display) {
+        self.violations.push(Violation {
+            source: self.source,
+            name: self.name.clone(),
+            pointer: self.pointer.clone(),
+            reason: reason.to_string(),
+            expected: None,
+        });
+    }
+
+    /// `true` when no violation has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Succeed if no violation has been recorded, or reject the request.
+    pub fn into_result(self) -> Result<(), ValidationRejection> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationRejection {
+                violations: self.violations,
+            })
+        }
+    }
+}
+
+/// Append a segment to a JSON pointer, escaping it according to RFC 6901.
+pub(crate) fn push_pointer_segment(pointer: &mut String, segment: impl fmt::Display) {
+    pointer.push('/');
+    for c in segment.to_string().chars() {
+        match c {
+            '~' => pointer.push_str("~0"),
+            '/' => pointer.push_str("~1"),
+            c => pointer.push(c),
+        }
+    }
+}
+
+/// A request violated the constraints of its schema.
+#[derive(Debug, thiserror::Error)]
+#[error("request failed validation with {} violation(s)", .violations.len())]
+pub struct ValidationRejection {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationRejection {
+    /// `422 Unprocessable Entity` if only the body is invalid, otherwise `400 Bad Request`.
+    pub fn status(&self) -> StatusCode {
+        if self
+            .violations
+            .iter()
+            .all(|violation| violation.source == Source::Body)
+        {
+            StatusCode::UNPROCESSABLE_ENTITY
+        } else {
+            StatusCode::BAD_REQUEST
+        }
+    }
+}
+
+/// Without feature `api-problem`, the violations are listed in a plain text body.
+#[cfg(feature = "axum-support")]
+impl axum::response::IntoResponse for ValidationRejection {
+    fn into_response(self) -> axum::response::Response {
+        #[cfg(feature = "api-problem")]
+        let response = http_api_problem::HttpApiProblem::new(self.status())
+            .title(self.to_string())
+            .value("invalid-params", &self.violations)
+            .into_response();
+        #[cfg(not(feature = "api-problem"))]
+        let response = {
+            let body = self
+                .violations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+            (self.status(), body).into_response()
+        };
+        response
+    }
+}
+
+/// A type which can check that its values uphold the constraints of their schema.
+///
+/// Generated code implements this for structs, enums, and newtypes when the `validate` option is set.
+pub trait Validate {
+    /// Record every violation of a constraint by this value, or by any value it contains.
+    fn validate(&self, violations: &mut Violations);
+}
+
+/// Deserialize a member which is required, but nullable.
+///
+/// Serde treats a missing member of type `Option` as `None`. Members deserialized with this function must be
+/// present, though their value may be `null`.
+pub fn required<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer)
+}
+
+/// Check the constraints which a scalar upholds through its [`CanonicalForm`].
+pub fn check_constraints<T>(value: &T, violations: &mut Violations)
+where
+    T: CanonicalForm,
+{
+    if let Err(violation) = value.check_constraints() {
+        violations.push(violation);
+    }
+}
+
+/// Check `minLength` and `maxLength`, which count characters.
+pub fn length(value: &str, min: Option<usize>, max: Option<usize>, violations: &mut Violations) {
+    let len = value.chars().count();
+    if let Some(min) = min.filter(|min| len < *min) {
+        violations.push(format_args!("must be at least {min} characters long"));
+    }
+    if let Some(max) = max.filter(|max| len > *max) {
+        violations.push(format_args!("must be at most {max} characters long"));
+    }
+}
+
+/// A `pattern` of the specification, an ECMA-262 regular expression.
+///
+/// Generated code declares each pattern as a `static`, so that it is compiled only once, when it is first checked.
+/// Patterns are compiled when the code is generated too, so that invalid patterns are reported as errors in the
+/// specification.
+#[cfg(feature = "string-pattern")]
+#[derive(Debug)]
+pub struct Pattern {
+    source: &'static str,
+    regex: std::sync::OnceLock<regress::Regex>,
+}
+
+#[cfg(feature = "string-pattern")]
+impl Pattern {
+    /// Declare the pattern `source`, without compiling it yet.
+    pub const fn new(source: &'static str) -> Self {
+        Self {
+            source,
+            regex: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// The source of the pattern, as written in the specification.
+    pub fn as_str(&self) -> &'static str {
+        self.source
+    }
+
+    /// The compiled pattern.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is not a valid regular expression, which code generation has already ruled out.
+    fn regex(&self) -> &regress::Regex {
+        self.regex.get_or_init(|| {
+            regress::Regex::new(self.source).expect("patterns are checked when code is generated")
+        })
+    }
+}
+
+/// Check `pattern`, which need not match the entire value.
+#[cfg(feature = "string-pattern")]
+pub fn pattern(value: &str, pattern: &Pattern, violations: &mut Violations) {
+    if pattern.regex().find(value).is_none() {
+        violations.push(format_args!("must match the pattern `{}`", pattern.source));
+    }
+}
+
+/// String formats which [`format`] can check.
+///
+/// Strings with other unknown formats are accepted as they are.
+pub const FORMATS: &[&str] = &["email", "hostname", "uri"];
+
+/// Check `format`, for the formats listed in [`FORMATS`].
+///
+/// The checks are deliberately shallow: they reject values which are clearly not of the format, but accept some
+/// which a strict parser would not.
+pub fn format(value: &str, format: &str, violations: &mut Violations) {
+    let valid = match format {
+        "email" => value
+            .rsplit_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && is_hostname(domain)),
+        "hostname" => is_hostname(value),
+        "uri" => value.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }),
+        _ => true,
+    };
+    if !valid {
+        violations.push(format_args!("must be a valid {format}"));
+    }
+}
+
+/// `true` when `value` is a hostname according to RFC 1123.
+fn is_hostname(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// Check `enum`, for values whose type does not restrict them to its members.
+pub fn one_of<T>(value: &T, allowed: &[T], violations: &mut Violations)
+where
+    T: PartialEq + fmt::Display,
+{
+    if !allowed.contains(value) {
+        let allowed = allowed
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        violations.push(format_args!("must be one of {allowed}"));
+    }
+}
+
+/// Check `minimum`, and `exclusiveMinimum` with it.
+pub fn minimum(value: f64, minimum: f64, exclusive: bool, violations: &mut Violations) {
+    if exclusive && value <= minimum {
+        violations.push(format_args!("must be greater than {minimum}"));
+    } else if value < minimum {
+        violations.push(format_args!("must be at least {minimum}"));
+    }
+}
+
+/// Check `maximum`, and `exclusiveMaximum` with it.
+pub fn maximum(value: f64, maximum: f64, exclusive: bool, violations: &mut Violations) {
+    if exclusive && value >= maximum {
+        violations.push(format_args!("must be less than {maximum}"));
+    } else if value > maximum {
+        violations.push(format_args!("must be at most {maximum}"));
+    }
+}
+
+/// Check `multipleOf`.
+pub fn multiple_of(value: f64, divisor: f64, violations: &mut Violations) {
+    let quotient = value / divisor;
+    if (quotient - quotient.round()).abs() > 1e-9 {
+        violations.push(format_args!("must be a multiple of {divisor}"));
+    }
+}
+
+/// Check `minItems` and `maxItems`.
+pub fn items(len: usize, min: Option<usize>, max: Option<usize>, violations: &mut Violations) {
+    if let Some(min) = min.filter(|min| len < *min) {
+        violations.push(format_args!("must have at least {min} items"));
+    }
+    if let Some(max) = max.filter(|max| len > *max) {
+        violations.push(format_args!("must have at most {max} items"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn violations_carry_their_location() {
+        let mut violations = Violations::new();
+        violations.parameter(Source::Query, "tags", |violations| {
+            violations.at(1, |violations| length("", Some(1), None, violations));
+        });
+        violations.body(|violations| {
+            violations.at("a/b", |violations| {
+                maximum(5.0, 5.0, true, violations);
+            });
+            items(3, None, Some(2), violations);
+        });
+
+        let rejection = violations.into_result().unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+        let rendered = rejection
+            .violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            [
+                "query parameter `tags` at `/1`: must be at least 1 characters long",
+                "body at `/a~1b`: must be less than 5",
+                "body: must have at most 2 items",
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_and_enums() {
+        let mut valid = Violations::new();
+        format("alice@example.com", "email", &mut valid);
+        format("example.com", "hostname", &mut valid);
+        format("https://example.com", "uri", &mut valid);
+        one_of(&2, &[1, 2], &mut valid);
+        assert!(valid.is_empty());
+
+        let mut violations = Violations::new();
+        format("alice@", "email", &mut violations);
+        format("-example.com", "hostname", &mut violations);
+        format("//example.com", "uri", &mut violations);
+        one_of(&3, &[1, 2], &mut violations);
+
+        let reasons = violations
+            .into_result()
+            .unwrap_err()
+            .violations
+            .into_iter()
+            .map(|violation| violation.reason)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            [
+                "must be a valid email",
+                "must be a valid hostname",
+                "must be a valid uri",
+                "must be one of 1, 2",
+            ]
+        );
+    }
+
+    #[cfg(feature = "string-pattern")]
+    #[test]
+    fn patterns_need_not_match_the_entire_value() {
+        static CAPITALIZED: Pattern = Pattern::new("^[A-Z]");
+
+        let mut violations = Violations::new();
+        pattern("Rex", &CAPITALIZED, &mut violations);
+        assert!(violations.is_empty());
+        pattern("rex", &CAPITALIZED, &mut violations);
+        pattern("Fido", &CAPITALIZED, &mut violations);
+
+        let reasons = violations
+            .into_result()
+            .unwrap_err()
+            .violations
+            .into_iter()
+            .map(|violation| violation.reason)
+            .collect::<Vec<_>>();
+        assert_eq!(reasons, ["must match the pattern `^[A-Z]`"]);
+    }
+}
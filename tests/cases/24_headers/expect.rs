@@ -134,7 +134,7 @@ pub struct CreateNaturalPersonIdentificationResponseCreated {
 #[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
 pub enum CreateNaturalPersonIdentificationResponse {
     Created(CreateNaturalPersonIdentificationResponseCreated),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -285,7 +285,9 @@ for CreateNaturalPersonIdentificationResponse {
                     .into_response()
             }
             CreateNaturalPersonIdentificationResponse::Default(default) => {
-                default.into_response()
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
             }
         }
     }
@@ -294,42 +296,62 @@ for CreateNaturalPersonIdentificationResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/natural-persons",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move |
-                    x_flow_id: Option<
-                        openapi_gen::reexport::axum_extra::TypedHeader<XFlowId>,
-                    >,
-                    x_request_id: Option<
-                        openapi_gen::reexport::axum_extra::TypedHeader<XRequestId>,
-                    >,
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<
-                            CreateNaturalPersonIdentificationRequest,
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("createNaturalPersonIdentification"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/natural-persons",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        x_flow_id: Option<
+                            openapi_gen::reexport::axum_extra::TypedHeader<XFlowId>,
                         >,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move {
-                    let x_flow_id = x_flow_id.map(|x_flow_id| x_flow_id.0);
-                    let x_request_id = x_request_id.map(|x_request_id| x_request_id.0);
-                    instance
-                        .create_natural_person_identification(
-                            x_flow_id,
-                            x_request_id,
+                        x_request_id: Option<
+                            openapi_gen::reexport::axum_extra::TypedHeader<XRequestId>,
+                        >,
+                        openapi_gen::axum_compat::json::Json(
                             request_body,
-                        )
-                        .await
-                }
-            }),
+                        ): openapi_gen::axum_compat::json::Json<
+                            CreateNaturalPersonIdentificationRequest,
+                        >|
+                    async move {
+                        let x_flow_id = x_flow_id.map(|x_flow_id| x_flow_id.0);
+                        let x_request_id = x_request_id
+                            .map(|x_request_id| x_request_id.0);
+                        instance
+                            .create_natural_person_identification(
+                                x_flow_id,
+                                x_request_id,
+                                request_body,
+                            )
+                            .await
+                    }
+                }),
+            ),
         )
 }
 
@@ -92,7 +92,7 @@ pub enum GetNpIdentityDocumentDataResponse {
     Ok(Vec<u8>),
     #[serde(rename = "Not Acceptable")]
     NotAcceptable(openapi_gen::reexport::http_api_problem::HttpApiProblem),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -154,12 +154,14 @@ for GetNpIdentityDocumentDataResponse {
             GetNpIdentityDocumentDataResponse::NotAcceptable(not_acceptable) => {
                 (
                     openapi_gen::reexport::http::status::StatusCode::NOT_ACCEPTABLE,
-                    openapi_gen::reexport::axum::Json(not_acceptable),
+                    not_acceptable,
                 )
                     .into_response()
             }
             GetNpIdentityDocumentDataResponse::Default(default) => {
-                default.into_response()
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
             }
         }
     }
@@ -168,39 +170,68 @@ for GetNpIdentityDocumentDataResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/natural-persons/:identification-id/documents/:document-id/data",
-            openapi_gen::reexport::axum::routing::get({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum::extract::Path(
-                        GetNpIdentityDocumentDataPathParameters {
-                            identification_id,
-                            document_id,
-                        },
-                    ): openapi_gen::reexport::axum::extract::Path<
-                        GetNpIdentityDocumentDataPathParameters,
-                    >,
-                    accept: Option<
-                        openapi_gen::reexport::axum_extra::TypedHeader<
-                            openapi_gen::reexport::accept_header::Accept,
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getNpIdentityDocumentData"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/natural-persons/{identification-id}/documents/{document-id}/data",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::reexport::axum_extra::extract::WithRejection(
+                            openapi_gen::reexport::axum::extract::Path(
+                                GetNpIdentityDocumentDataPathParameters {
+                                    identification_id,
+                                    document_id,
+                                },
+                            ),
+                            _,
+                        ): openapi_gen::reexport::axum_extra::extract::WithRejection<
+                            openapi_gen::reexport::axum::extract::Path<
+                                GetNpIdentityDocumentDataPathParameters,
+                            >,
+                            openapi_gen::axum_compat::ApiProblemRejection,
                         >,
-                    >|
-                async move {
-                    let accept = accept.map(|accept| accept.0);
-                    instance
-                        .get_np_identity_document_data(
-                            identification_id,
-                            document_id,
-                            accept,
-                        )
-                        .await
-                }
-            }),
+                        accept: Option<
+                            openapi_gen::reexport::axum_extra::TypedHeader<
+                                openapi_gen::reexport::accept_header::Accept,
+                            >,
+                        >|
+                    async move {
+                        let accept = accept.map(|accept| accept.0);
+                        instance
+                            .get_np_identity_document_data(
+                                identification_id,
+                                document_id,
+                                accept,
+                            )
+                            .await
+                    }
+                }),
+            ),
         )
 }
 
@@ -29,8 +29,8 @@ pub struct GetRootQueryParameters {
 #[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
 pub enum GetRootResponse {
     #[serde(rename = "OK")]
-    Ok(Vec<u8>),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Ok(openapi_gen::reexport::bytes::Bytes),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -62,7 +62,11 @@ impl openapi_gen::reexport::axum::response::IntoResponse for GetRootResponse {
                 (openapi_gen::reexport::http::status::StatusCode::OK, header_map, ok)
                     .into_response()
             }
-            GetRootResponse::Default(default) => default.into_response(),
+            GetRootResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -70,22 +74,43 @@ impl openapi_gen::reexport::axum::response::IntoResponse for GetRootResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/",
-            openapi_gen::reexport::axum::routing::get({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum::extract::Query(
-                        GetRootQueryParameters { bar, bat, camel_case_name },
-                    ): openapi_gen::reexport::axum::extract::Query<
-                        GetRootQueryParameters,
-                    >|
-                async move { instance.get_root(bar, bat, camel_case_name).await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getRoot"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::query::Query(
+                            GetRootQueryParameters { bar, bat, camel_case_name },
+                        ): openapi_gen::axum_compat::query::Query<GetRootQueryParameters>|
+                    async move { instance.get_root(bar, bat, camel_case_name).await }
+                }),
+            ),
         )
 }
 
@@ -51,6 +51,20 @@ pub trait Api {}
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
@@ -0,0 +1,165 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum SearchResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum SessionResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+///Credentials of security scheme `queryKey`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryKey(pub String);
+///Credentials of security scheme `sessionCookie`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionCookie(pub String);
+
+/// Security requirements of `search`.
+/// 
+/// Each requirement lists security schemes, and the OAuth2 scopes which each must grant.
+pub const SEARCH_SECURITY: &[openapi_gen::security::Requirement] = &[
+    &[("queryKey", &[])],
+];
+
+/// Security requirements of `session`.
+/// 
+/// Each requirement lists security schemes, and the OAuth2 scopes which each must grant.
+pub const SESSION_SECURITY: &[openapi_gen::security::Requirement] = &[
+    &[("sessionCookie", &[])],
+];
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `GET /search`
+    /// 
+    /// Operation ID: `search`
+    async fn search(&self, credentials: QueryKey) -> SearchResponse;
+
+    /// `GET /session`
+    /// 
+    /// Operation ID: `session`
+    async fn session(&self, credentials: SessionCookie) -> SessionResponse;
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for QueryKey
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        openapi_gen::axum_compat::security::api_key_query(parts, "queryKey", "apiKey").map(Self)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for SessionCookie
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        openapi_gen::axum_compat::security::api_key_cookie(parts, "sessionCookie", "sessionId").map(Self)
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for SearchResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            SearchResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for SessionResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            SessionResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/search",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("search"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/search",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |credentials: QueryKey| async move {
+                        instance.search(credentials).await
+                    }
+                }),
+            ),
+        )
+        .route(
+            "/session",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("session"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/session",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |credentials: SessionCookie| async move {
+                        instance.session(credentials).await
+                    }
+                }),
+            ),
+        )
+}
@@ -0,0 +1,313 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Subscription {
+    pub callback_url: String,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Event {
+    pub id: i64,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Upload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub file: Vec<u8>,
+}
+pub type SubscribeRequest = Subscription;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum SubscribeResponse {
+    Created(()),
+}
+pub type OnEventRequest = Event;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum OnEventResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+    Default(
+        openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>,
+    ),
+}
+pub type PublishEventRequest = Event;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum PublishEventResponse {
+    #[serde(rename = "OK")]
+    Ok(()),
+}
+pub type UploadFileRequest = Upload;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum UploadFileResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `POST /subscriptions`
+    /// 
+    /// Operation ID: `subscribe`
+    async fn subscribe(&self, request_body: SubscribeRequest) -> SubscribeResponse;
+}
+/// URL expression of callback `onEvent`.
+/// 
+/// Resolve it against the registering request with [`openapi_gen::callback::resolve_url`].
+pub const ON_EVENT_URL: &str = "{$request.body#/callback_url}";
+/// Requests which this API sends to its consumers.
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Callbacks {
+    /// The error produced when a callback cannot be delivered.
+    type Error;
+
+    /// `POST {$request.body#/callback_url}`
+    /// 
+    /// Operation ID: `onEvent`
+    /// 
+    /// Callback `onEvent`, registered by `subscribe`.
+    async fn on_event(
+        &self,
+        url: &str,
+        request_body: OnEventRequest,
+    ) -> Result<OnEventResponse, Self::Error>;
+
+    /// `POST eventPublished`
+    /// 
+    /// Operation ID: `publishEvent`
+    /// 
+    /// Webhook `eventPublished`.
+    async fn publish_event(
+        &self,
+        url: &str,
+        request_body: PublishEventRequest,
+    ) -> Result<PublishEventResponse, Self::Error>;
+
+    /// `POST fileUploaded`
+    /// 
+    /// Operation ID: `uploadFile`
+    /// 
+    /// Webhook `fileUploaded`.
+    async fn upload_file(
+        &self,
+        url: &str,
+        request_body: UploadFileRequest,
+    ) -> Result<UploadFileResponse, Self::Error>;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for SubscribeResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            SubscribeResponse::Created(created) => {
+                (openapi_gen::reexport::http::status::StatusCode::CREATED, created)
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/subscriptions",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("subscribe"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/subscriptions",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<SubscribeRequest>|
+                    async move { instance.subscribe(request_body).await }
+                }),
+            ),
+        )
+}
+impl openapi_gen::reqwest_compat::IntoMultipart for Upload {
+    fn into_form(
+        self,
+    ) -> Result<
+        openapi_gen::reexport::reqwest::multipart::Form,
+        openapi_gen::reqwest_compat::ClientError,
+    > {
+        #[allow(unused_mut)]
+        let mut form = openapi_gen::reexport::reqwest::multipart::Form::new();
+        if let Some(value) = &self.description {
+            form = openapi_gen::reqwest_compat::multipart_part(
+                form,
+                "description",
+                None,
+                value,
+            )?;
+        }
+        for value in &self.tags {
+            form = openapi_gen::reqwest_compat::multipart_part(form, "tags", None, value)?;
+        }
+        form = openapi_gen::reqwest_compat::multipart_bytes(
+            form,
+            "file",
+            Some("image/png"),
+            self.file,
+        )?;
+        Ok(form)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl Callbacks for openapi_gen::reqwest_compat::CallbackClient {
+    type Error = openapi_gen::reqwest_compat::ClientError;
+    async fn on_event(
+        &self,
+        url: &str,
+        request_body: OnEventRequest,
+    ) -> Result<OnEventResponse, Self::Error> {
+        let url = url.to_owned();
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::POST, url);
+        let request = openapi_gen::reqwest_compat::json_body(
+            request,
+            "application/json",
+            &request_body,
+        )?;
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(OnEventResponse::NoContent(())),
+            _ => {
+                Ok(
+                    OnEventResponse::Default(
+                        openapi_gen::WithStatus::new(
+                            status,
+                            openapi_gen::reqwest_compat::json::<
+                                openapi_gen::reexport::http_api_problem::HttpApiProblem,
+                            >(response)
+                                .await?,
+                        )?,
+                    ),
+                )
+            }
+        }
+    }
+    async fn publish_event(
+        &self,
+        url: &str,
+        request_body: PublishEventRequest,
+    ) -> Result<PublishEventResponse, Self::Error> {
+        let url = url.to_owned();
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::POST, url);
+        let request = openapi_gen::reqwest_compat::json_body(
+            request,
+            "application/json",
+            &request_body,
+        )?;
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            200 => Ok(PublishEventResponse::Ok(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+    async fn upload_file(
+        &self,
+        url: &str,
+        request_body: UploadFileRequest,
+    ) -> Result<UploadFileResponse, Self::Error> {
+        let url = url.to_owned();
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::POST, url);
+        let request = openapi_gen::reqwest_compat::multipart_body(request, request_body)?;
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(UploadFileResponse::NoContent(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+}
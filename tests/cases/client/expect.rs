@@ -0,0 +1,695 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum ListThingsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum CreateThingResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum DeleteThingsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum CountThingsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum HealthResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+///Credentials of security scheme `api_key`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKey(pub String);
+///Credentials of security scheme `basicAuth`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicAuth(pub openapi_gen::security::Basic);
+
+/// Credentials of security scheme `bearerAuth`.
+/// 
+/// A JSON Web Token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BearerAuth(pub openapi_gen::security::Bearer);
+///Credentials of security scheme `petstore_auth`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PetstoreAuth(pub openapi_gen::security::Bearer);
+///Credentials which satisfy a security requirement of `listThings`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListThingsCredentials {
+    PetstoreAuth(PetstoreAuth),
+    ApiKey(ApiKey),
+}
+
+/// Security requirements of `listThings`.
+/// 
+/// Each requirement lists security schemes, and the OAuth2 scopes which each must grant.
+pub const LIST_THINGS_SECURITY: &[openapi_gen::security::Requirement] = &[
+    &[("petstore_auth", &["read:things"])],
+    &[("api_key", &[])],
+];
+///Credentials which satisfy a security requirement of `createThing`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateThingCredentials {
+    BasicAuthAndApiKey(BasicAuth, ApiKey),
+}
+
+/// Security requirements of `createThing`.
+/// 
+/// Each requirement lists security schemes, and the OAuth2 scopes which each must grant.
+pub const CREATE_THING_SECURITY: &[openapi_gen::security::Requirement] = &[
+    &[("basicAuth", &[]), ("api_key", &[])],
+];
+
+/// Security requirements of `deleteThings`.
+/// 
+/// Each requirement lists security schemes, and the OAuth2 scopes which each must grant.
+pub const DELETE_THINGS_SECURITY: &[openapi_gen::security::Requirement] = &[
+    &[("bearerAuth", &[])],
+];
+
+/// Security requirements of `countThings`.
+/// 
+/// Each requirement lists security schemes, and the OAuth2 scopes which each must grant.
+pub const COUNT_THINGS_SECURITY: &[openapi_gen::security::Requirement] = &[
+    &[("api_key", &[])],
+];
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+    /// The error with which methods fail.
+    ///
+    /// The generated router responds with the error in place of a response, so it must implement the
+    /// response trait of the framework: `IntoResponse` for axum, `IntoHttpResponse` for tower, or
+    /// `Into<actix_web::Error>` for actix-web.
+    type Error: Send + 'static;
+
+    /// `GET /things`
+    /// 
+    /// Operation ID: `listThings`
+    async fn list_things(
+        &self,
+        credentials: ListThingsCredentials,
+    ) -> Result<ListThingsResponse, Self::Error>;
+
+    /// `POST /things`
+    /// 
+    /// Operation ID: `createThing`
+    async fn create_thing(
+        &self,
+        credentials: CreateThingCredentials,
+    ) -> Result<CreateThingResponse, Self::Error>;
+
+    /// `DELETE /things`
+    /// 
+    /// Operation ID: `deleteThings`
+    async fn delete_things(
+        &self,
+        credentials: Option<BearerAuth>,
+    ) -> Result<DeleteThingsResponse, Self::Error>;
+
+    /// `GET /things/count`
+    /// 
+    /// Operation ID: `countThings`
+    async fn count_things(&self, credentials: ApiKey) -> Result<CountThingsResponse, Self::Error>;
+
+    /// `GET /health`
+    /// 
+    /// Operation ID: `health`
+    async fn health(&self) -> Result<HealthResponse, Self::Error>;
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for ApiKey
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        openapi_gen::axum_compat::security::api_key_header(parts, "api_key", "x-api-key")
+            .map(Self)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for BasicAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        openapi_gen::axum_compat::security::basic(parts, "basicAuth").map(Self)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for BearerAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        openapi_gen::axum_compat::security::bearer(parts, "bearerAuth").map(Self)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S> for PetstoreAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        openapi_gen::axum_compat::security::bearer(parts, "petstore_auth").map(Self)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S>
+for ListThingsCredentials
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let rejection = match <PetstoreAuth as openapi_gen::reexport::axum::extract::FromRequestParts<
+            S,
+        >>::from_request_parts(parts, state)
+            .await
+        {
+            Ok(petstore_auth) => return Ok(Self::PetstoreAuth(petstore_auth)),
+            Err(err) => err,
+        };
+        let rejection = match <ApiKey as openapi_gen::reexport::axum::extract::FromRequestParts<
+            S,
+        >>::from_request_parts(parts, state)
+            .await
+        {
+            Ok(api_key) => return Ok(Self::ApiKey(api_key)),
+            Err(err) => rejection.or(err),
+        };
+        Err(rejection)
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<S> openapi_gen::reexport::axum::extract::FromRequestParts<S>
+for CreateThingCredentials
+where
+    S: Send + Sync,
+{
+    type Rejection = openapi_gen::axum_compat::security::SecurityRejection;
+    async fn from_request_parts(
+        parts: &mut openapi_gen::reexport::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let rejection = match (
+            <BasicAuth as openapi_gen::reexport::axum::extract::FromRequestParts<
+                S,
+            >>::from_request_parts(parts, state)
+                .await,
+            <ApiKey as openapi_gen::reexport::axum::extract::FromRequestParts<
+                S,
+            >>::from_request_parts(parts, state)
+                .await,
+        ) {
+            (Ok(basic_auth), Ok(api_key)) => {
+                return Ok(Self::BasicAuthAndApiKey(basic_auth, api_key));
+            }
+            (basic_auth, api_key) => {
+                openapi_gen::axum_compat::security::SecurityRejection::requirement([
+                    basic_auth.err(),
+                    api_key.err(),
+                ])
+            }
+        };
+        Err(rejection)
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for ListThingsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            ListThingsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for CreateThingResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            CreateThingResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for DeleteThingsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            DeleteThingsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for CountThingsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            CountThingsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for HealthResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            HealthResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    <Instance as Api>::Error: openapi_gen::reexport::axum::response::IntoResponse,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+    <Instance as Api>::Error: openapi_gen::reexport::axum::response::IntoResponse,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/things",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listThings"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/things",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |credentials: ListThingsCredentials| async move {
+                        instance.list_things(credentials).await
+                    }
+                }),
+            ),
+        )
+        .route(
+            "/things",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("createThing"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/things",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |credentials: CreateThingCredentials| async move {
+                        instance.create_thing(credentials).await
+                    }
+                }),
+            ),
+        )
+        .route(
+            "/things",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("deleteThings"),
+                    method: openapi_gen::reexport::http::Method::DELETE,
+                    path: "/things",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::delete({
+                    let instance = instance.clone();
+                    move |openapi_gen::axum_compat::security::Optional(credentials): openapi_gen::axum_compat::security::Optional<BearerAuth>| async move {
+                        instance.delete_things(credentials).await
+                    }
+                }),
+            ),
+        )
+        .route(
+            "/things/count",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("countThings"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/things/count",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |credentials: ApiKey| async move {
+                        instance.count_things(credentials).await
+                    }
+                }),
+            ),
+        )
+        .route(
+            "/health",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("health"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/health",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.health().await }
+                }),
+            ),
+        )
+}
+impl openapi_gen::reqwest_compat::security::Authenticate for ApiKey {
+    fn authenticate(
+        &self,
+        request: openapi_gen::reexport::reqwest::RequestBuilder,
+    ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+        openapi_gen::reqwest_compat::security::api_key_header(
+            request,
+            "X-API-Key",
+            &self.0,
+        )
+    }
+}
+impl openapi_gen::reqwest_compat::security::Authenticate for BasicAuth {
+    fn authenticate(
+        &self,
+        request: openapi_gen::reexport::reqwest::RequestBuilder,
+    ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+        openapi_gen::reqwest_compat::security::basic(request, &self.0)
+    }
+}
+impl openapi_gen::reqwest_compat::security::Authenticate for BearerAuth {
+    fn authenticate(
+        &self,
+        request: openapi_gen::reexport::reqwest::RequestBuilder,
+    ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+        openapi_gen::reqwest_compat::security::bearer(request, &self.0)
+    }
+}
+impl openapi_gen::reqwest_compat::security::Authenticate for PetstoreAuth {
+    fn authenticate(
+        &self,
+        request: openapi_gen::reexport::reqwest::RequestBuilder,
+    ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+        openapi_gen::reqwest_compat::security::bearer(request, &self.0)
+    }
+}
+impl openapi_gen::reqwest_compat::security::Authenticate for ListThingsCredentials {
+    fn authenticate(
+        &self,
+        request: openapi_gen::reexport::reqwest::RequestBuilder,
+    ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+        match self {
+            Self::PetstoreAuth(petstore_auth) => {
+                let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+                    petstore_auth,
+                    request,
+                );
+                request
+            }
+            Self::ApiKey(api_key) => {
+                let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+                    api_key,
+                    request,
+                );
+                request
+            }
+        }
+    }
+}
+impl openapi_gen::reqwest_compat::security::Authenticate for CreateThingCredentials {
+    fn authenticate(
+        &self,
+        request: openapi_gen::reexport::reqwest::RequestBuilder,
+    ) -> openapi_gen::reexport::reqwest::RequestBuilder {
+        match self {
+            Self::BasicAuthAndApiKey(basic_auth, api_key) => {
+                let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+                    basic_auth,
+                    request,
+                );
+                let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+                    api_key,
+                    request,
+                );
+                request
+            }
+        }
+    }
+}
+/// A client for this API, which sends its requests with `reqwest`.
+///
+/// Each operation is available as a method which reports failure as an error. It also implements the API
+/// traits, whose methods fail with the same error.
+#[derive(Debug, Clone)]
+pub struct Client {
+    client: openapi_gen::reexport::reqwest::Client,
+    base_url: String,
+}
+impl Client {
+    /// Create a client for the API at `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(Default::default(), base_url)
+    }
+    /// Create a client for the API at `base_url`, which sends its requests with the given client.
+    pub fn with_client(
+        client: openapi_gen::reexport::reqwest::Client,
+        base_url: impl Into<String>,
+    ) -> Self {
+        let base_url = base_url.into();
+        Self { client, base_url }
+    }
+    /// The client with which requests are sent.
+    pub fn client(&self) -> &openapi_gen::reexport::reqwest::Client {
+        &self.client
+    }
+    /// The base URL of the API, to which the path of each endpoint is appended.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Send a request to `GET /things`.
+    /// 
+    /// Failure to send the request or to decode its response is reported as an error.
+    pub async fn try_list_things(
+        &self,
+        credentials: ListThingsCredentials,
+    ) -> Result<ListThingsResponse, openapi_gen::reqwest_compat::ClientError> {
+        let url = openapi_gen::reqwest_compat::join_url(&self.base_url, "/things");
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::GET, url);
+        let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+            &credentials,
+            request,
+        );
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(ListThingsResponse::NoContent(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+
+    /// Send a request to `POST /things`.
+    /// 
+    /// Failure to send the request or to decode its response is reported as an error.
+    pub async fn try_create_thing(
+        &self,
+        credentials: CreateThingCredentials,
+    ) -> Result<CreateThingResponse, openapi_gen::reqwest_compat::ClientError> {
+        let url = openapi_gen::reqwest_compat::join_url(&self.base_url, "/things");
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::POST, url);
+        let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+            &credentials,
+            request,
+        );
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(CreateThingResponse::NoContent(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+
+    /// Send a request to `DELETE /things`.
+    /// 
+    /// Failure to send the request or to decode its response is reported as an error.
+    pub async fn try_delete_things(
+        &self,
+        credentials: Option<BearerAuth>,
+    ) -> Result<DeleteThingsResponse, openapi_gen::reqwest_compat::ClientError> {
+        let url = openapi_gen::reqwest_compat::join_url(&self.base_url, "/things");
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::DELETE, url);
+        let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+            &credentials,
+            request,
+        );
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(DeleteThingsResponse::NoContent(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+
+    /// Send a request to `GET /things/count`.
+    /// 
+    /// Failure to send the request or to decode its response is reported as an error.
+    pub async fn try_count_things(
+        &self,
+        credentials: ApiKey,
+    ) -> Result<CountThingsResponse, openapi_gen::reqwest_compat::ClientError> {
+        let url = openapi_gen::reqwest_compat::join_url(&self.base_url, "/things/count");
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::GET, url);
+        let request = openapi_gen::reqwest_compat::security::Authenticate::authenticate(
+            &credentials,
+            request,
+        );
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(CountThingsResponse::NoContent(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+
+    /// Send a request to `GET /health`.
+    /// 
+    /// Failure to send the request or to decode its response is reported as an error.
+    pub async fn try_health(
+        &self,
+    ) -> Result<HealthResponse, openapi_gen::reqwest_compat::ClientError> {
+        let url = openapi_gen::reqwest_compat::join_url(&self.base_url, "/health");
+        let request = self
+            .client()
+            .request(openapi_gen::reexport::http::Method::GET, url);
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            204 => Ok(HealthResponse::NoContent(())),
+            _ => Err(openapi_gen::reqwest_compat::ClientError::UnexpectedStatus(status)),
+        }
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl Api for Client {
+    type Error = openapi_gen::reqwest_compat::ClientError;
+    async fn list_things(
+        &self,
+        credentials: ListThingsCredentials,
+    ) -> Result<ListThingsResponse, Self::Error> {
+        self.try_list_things(credentials).await
+    }
+    async fn create_thing(
+        &self,
+        credentials: CreateThingCredentials,
+    ) -> Result<CreateThingResponse, Self::Error> {
+        self.try_create_thing(credentials).await
+    }
+    async fn delete_things(
+        &self,
+        credentials: Option<BearerAuth>,
+    ) -> Result<DeleteThingsResponse, Self::Error> {
+        self.try_delete_things(credentials).await
+    }
+    async fn count_things(
+        &self,
+        credentials: ApiKey,
+    ) -> Result<CountThingsResponse, Self::Error> {
+        self.try_count_things(credentials).await
+    }
+    async fn health(&self) -> Result<HealthResponse, Self::Error> {
+        self.try_health().await
+    }
+}
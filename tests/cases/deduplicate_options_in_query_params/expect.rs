@@ -107,7 +107,7 @@ type Ok_ = Vec<Item>;
 pub enum GetListResponse {
     #[serde(rename = "OK")]
     Ok(Ok_),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -181,7 +181,11 @@ impl openapi_gen::reexport::axum::response::IntoResponse for GetListResponse {
                 )
                     .into_response()
             }
-            GetListResponse::Default(default) => default.into_response(),
+            GetListResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -189,28 +193,50 @@ impl openapi_gen::reexport::axum::response::IntoResponse for GetListResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/list",
-            openapi_gen::reexport::axum::routing::get({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum::extract::Query(
-                        GetListQueryParameters { status, id },
-                    ): openapi_gen::reexport::axum::extract::Query<
-                        GetListQueryParameters,
-                    >,
-                    x_request_id: Option<
-                        openapi_gen::reexport::axum_extra::TypedHeader<XRequestId>,
-                    >|
-                async move {
-                    let x_request_id = x_request_id.map(|x_request_id| x_request_id.0);
-                    instance.get_list(status, id, x_request_id).await
-                }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getList"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/list",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::query::Query(
+                            GetListQueryParameters { status, id },
+                        ): openapi_gen::axum_compat::query::Query<GetListQueryParameters>,
+                        x_request_id: Option<
+                            openapi_gen::reexport::axum_extra::TypedHeader<XRequestId>,
+                        >|
+                    async move {
+                        let x_request_id = x_request_id
+                            .map(|x_request_id| x_request_id.0);
+                        instance.get_list(status, id, x_request_id).await
+                    }
+                }),
+            ),
         )
 }
 
@@ -0,0 +1,159 @@
+#![allow(non_camel_case_types)]
+pub type PostKudo = openapi_gen::reexport::serde_json::Value;
+///request body for a freeform render request
+pub type PostKudosRequest = PostKudo;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum PostKudosResponse {
+    Created(()),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+    /// The error with which methods fail.
+    ///
+    /// The generated router responds with the error in place of a response, so it must implement the
+    /// response trait of the framework: `IntoResponse` for axum, `IntoHttpResponse` for tower, or
+    /// `Into<actix_web::Error>` for actix-web.
+    type Error: Send + 'static;
+
+    /// `POST /post-kudos`
+    /// 
+    /// Operation ID: `postKudos`
+    async fn post_kudos(
+        &self,
+        request_body: PostKudosRequest,
+    ) -> Result<PostKudosResponse, Self::Error>;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for PostKudosResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            PostKudosResponse::Created(created) => {
+                (openapi_gen::reexport::http::status::StatusCode::CREATED, created)
+                    .into_response()
+            }
+            PostKudosResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    <Instance as Api>::Error: openapi_gen::reexport::axum::response::IntoResponse,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+    <Instance as Api>::Error: openapi_gen::reexport::axum::response::IntoResponse,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/post-kudos",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("postKudos"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/post-kudos",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<PostKudosRequest>|
+                    async move { instance.post_kudos(request_body).await }
+                }),
+            ),
+        )
+}
+/// A programmable mock implementation of [`trait Api`][Api].
+///
+/// Set up the calls which each operation expects with its `expect_` method. Each call is answered by the
+/// first expectation which matches it, and recorded; calls which match no expectation panic. Expected call
+/// counts are verified by [`MockApi::checkpoint`], and when the mock is dropped.
+///
+/// Expectations produce the whole result of their operation, so they can also fail with `E`.
+#[derive(Debug)]
+pub struct MockApi<E = ::std::convert::Infallible> {
+    post_kudos: openapi_gen::mock::Mock<(PostKudosRequest,), Result<PostKudosResponse, E>>,
+}
+impl<E> Default for MockApi<E> {
+    fn default() -> Self {
+        Self {
+            post_kudos: openapi_gen::mock::Mock::new("post_kudos"),
+        }
+    }
+}
+impl MockApi {
+    /// Create a mock with no expectations, which fails with [`Infallible`][::std::convert::Infallible].
+    ///
+    /// To stub failures, create the mock with another error instead, for example with
+    /// `MockApi::<MyError>::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl<E> MockApi<E> {
+    /// Expect a call to [`Api::post_kudos`].
+    pub fn expect_post_kudos(
+        &mut self,
+    ) -> &mut openapi_gen::mock::Expectation<
+        (PostKudosRequest,),
+        Result<PostKudosResponse, E>,
+    > {
+        self.post_kudos.expect()
+    }
+    /// The calls to [`Api::post_kudos`] so far, in order.
+    pub fn post_kudos_calls(
+        &self,
+    ) -> openapi_gen::mock::Calls<'_, (PostKudosRequest,), Result<PostKudosResponse, E>> {
+        self.post_kudos.calls()
+    }
+    /// Verify that every expectation received its expected number of calls, then remove all expectations.
+    ///
+    /// # Panics
+    ///
+    /// If any expectation is not satisfied.
+    pub fn checkpoint(&mut self) {
+        self.post_kudos.checkpoint();
+    }
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+impl<E> Api for MockApi<E>
+where
+    E: Send + 'static,
+{
+    type Error = E;
+    async fn post_kudos(
+        &self,
+        request_body: PostKudosRequest,
+    ) -> Result<PostKudosResponse, Self::Error> {
+        self.post_kudos.call((request_body,))
+    }
+}
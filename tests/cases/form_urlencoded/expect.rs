@@ -0,0 +1,108 @@
+#![allow(non_camel_case_types)]
+pub type Scopes = Vec<String>;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Credentials {
+    pub grant_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Scopes>,
+}
+pub type TokenRequest = Credentials;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum TokenResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `POST /token`
+    /// 
+    /// Operation ID: `token`
+    async fn token(&self, request_body: TokenRequest) -> TokenResponse;
+}
+impl openapi_gen::form::FormEncoding for Credentials {
+    const PROPERTIES: &'static [openapi_gen::form::PropertyStyle] = &[
+        openapi_gen::form::PropertyStyle {
+            name: "scope",
+            style: openapi_gen::form::Style::SpaceDelimited,
+            explode: false,
+        },
+    ];
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for TokenResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            TokenResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+            TokenResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/token",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("token"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/token",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::form::UrlEncodedForm(
+                            request_body,
+                        ): openapi_gen::axum_compat::form::UrlEncodedForm<Credentials>|
+                    async move { instance.token(request_body).await }
+                }),
+            ),
+        )
+}
@@ -128,42 +128,82 @@ impl openapi_gen::reexport::axum::response::IntoResponse for PutThingResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/thing/:id",
-            openapi_gen::reexport::axum::routing::get({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum::extract::Path(
-                        GetThingPathParameters { id },
-                    ): openapi_gen::reexport::axum::extract::Path<
-                        GetThingPathParameters,
-                    >|
-                async move { instance.get_thing(id).await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getThing"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/thing/{id}",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::reexport::axum_extra::extract::WithRejection(
+                            openapi_gen::reexport::axum::extract::Path(
+                                GetThingPathParameters { id },
+                            ),
+                            _,
+                        ): openapi_gen::reexport::axum_extra::extract::WithRejection<
+                            openapi_gen::reexport::axum::extract::Path<
+                                GetThingPathParameters,
+                            >,
+                            openapi_gen::axum_compat::ApiProblemRejection,
+                        >|
+                    async move { instance.get_thing(id).await }
+                }),
+            ),
         )
         .route(
             "/thing/:id",
-            openapi_gen::reexport::axum::routing::put({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum::extract::Path(
-                        PutThingPathParameters { id },
-                    ): openapi_gen::reexport::axum::extract::Path<
-                        PutThingPathParameters,
-                    >,
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<PutThingRequest>,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move { instance.put_thing(id, request_body).await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("putThing"),
+                    method: openapi_gen::reexport::http::Method::PUT,
+                    path: "/thing/{id}",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::put({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::reexport::axum_extra::extract::WithRejection(
+                            openapi_gen::reexport::axum::extract::Path(
+                                PutThingPathParameters { id },
+                            ),
+                            _,
+                        ): openapi_gen::reexport::axum_extra::extract::WithRejection<
+                            openapi_gen::reexport::axum::extract::Path<
+                                PutThingPathParameters,
+                            >,
+                            openapi_gen::axum_compat::ApiProblemRejection,
+                        >,
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<PutThingRequest>|
+                    async move { instance.put_thing(id, request_body).await }
+                }),
+            ),
         )
 }
 
@@ -0,0 +1,243 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Id(pub openapi_gen::reexport::uuid::Uuid);
+openapi_gen::newtype_derive_canonical_form!(Id, openapi_gen::reexport::uuid::Uuid);
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Thing {
+    pub id: Id,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foo: Option<f64>,
+}
+pub type CreateThingRequest = Thing;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum CreateThingResponse {
+    Created(Thing),
+    #[serde(rename = "OK")]
+    Ok(Thing),
+    Default(
+        openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>,
+    ),
+}
+///Combination item for path parameters of `getThing`
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct GetThingPathParameters {
+    pub id: Id,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum GetThingResponse {
+    #[serde(rename = "OK")]
+    Ok(Thing),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `POST /things`
+    /// 
+    /// Operation ID: `createThing`
+    async fn create_thing(
+        &self,
+        request_body: CreateThingRequest,
+    ) -> CreateThingResponse;
+
+    /// `GET /thing/{id}`
+    /// 
+    /// Operation ID: `getThing`
+    async fn get_thing(&self, id: Id) -> GetThingResponse;
+}
+
+/// Parameters of `getThing`, computed from link `GetThingById` of [`CreateThingResponse`].
+/// 
+/// Fetch the thing which was just created.
+#[derive(Debug, Clone)]
+pub struct CreateThingResponseGetThingByIdLink {
+    pub id: Id,
+}
+impl CreateThingResponseGetThingByIdLink {
+    ///Compute this link from the `Created` variant of [`CreateThingResponse`].
+    pub fn from_created(response: &Thing) -> Result<Self, openapi_gen::link::LinkError> {
+        Ok(Self {
+            id: openapi_gen::link::pointer(response, "/id")?,
+        })
+    }
+    ///Compute this link from the `Ok` variant of [`CreateThingResponse`].
+    pub fn from_ok(response: &Thing) -> Result<Self, openapi_gen::link::LinkError> {
+        Ok(Self {
+            id: openapi_gen::link::pointer(response, "/id")?,
+        })
+    }
+}
+impl CreateThingResponse {
+
+    /// Follow link `GetThingById` to `getThing`.
+    /// 
+    /// Returns `None` if this response does not provide the link.
+    pub fn get_thing_by_id_link(
+        &self,
+    ) -> Option<
+        Result<CreateThingResponseGetThingByIdLink, openapi_gen::link::LinkError>,
+    > {
+        match self {
+            CreateThingResponse::Created(created) => {
+                Some(CreateThingResponseGetThingByIdLink::from_created(created))
+            }
+            CreateThingResponse::Ok(ok) => {
+                Some(CreateThingResponseGetThingByIdLink::from_ok(ok))
+            }
+            _ => None,
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for CreateThingResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            CreateThingResponse::Created(created) => {
+                (
+                    openapi_gen::reexport::http::status::StatusCode::CREATED,
+                    openapi_gen::reexport::axum::Json(created),
+                )
+                    .into_response()
+            }
+            CreateThingResponse::Ok(ok) => {
+                (
+                    openapi_gen::reexport::http::status::StatusCode::OK,
+                    openapi_gen::reexport::axum::Json(ok),
+                )
+                    .into_response()
+            }
+            CreateThingResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for GetThingResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            GetThingResponse::Ok(ok) => {
+                (
+                    openapi_gen::reexport::http::status::StatusCode::OK,
+                    openapi_gen::reexport::axum::Json(ok),
+                )
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/things",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("createThing"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/things",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<CreateThingRequest>|
+                    async move { instance.create_thing(request_body).await }
+                }),
+            ),
+        )
+        .route(
+            "/thing/:id",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getThing"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/thing/{id}",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::reexport::axum_extra::extract::WithRejection(
+                            openapi_gen::reexport::axum::extract::Path(
+                                GetThingPathParameters { id },
+                            ),
+                            _,
+                        ): openapi_gen::reexport::axum_extra::extract::WithRejection<
+                            openapi_gen::reexport::axum::extract::Path<
+                                GetThingPathParameters,
+                            >,
+                            openapi_gen::axum_compat::ApiProblemRejection,
+                        >|
+                    async move { instance.get_thing(id).await }
+                }),
+            ),
+        )
+}
@@ -0,0 +1,281 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Upload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub file: Vec<u8>,
+}
+pub type UploadRequest = Upload;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum UploadResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `POST /upload`
+    /// 
+    /// Operation ID: `upload`
+    async fn upload(&self, request_body: UploadRequest) -> UploadResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for UploadResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            UploadResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+            UploadResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::axum_compat::multipart::FromMultipart for Upload {
+    fn from_parts(
+        #[allow(unused_variables)]
+        parts: &mut openapi_gen::axum_compat::multipart::Parts,
+    ) -> Result<Self, openapi_gen::axum_compat::multipart::MultipartError> {
+        let description = parts
+            .take(
+                "description",
+                &openapi_gen::axum_compat::multipart::PartEncoding {
+                    content_type: None,
+                    headers: &[],
+                },
+            )?;
+        let file = parts
+            .take(
+                "file",
+                &openapi_gen::axum_compat::multipart::PartEncoding {
+                    content_type: Some("image/png, image/jpeg"),
+                    headers: &[],
+                },
+            )?;
+        Ok(Self {
+            description: description
+                .as_ref()
+                .map(openapi_gen::axum_compat::multipart::Part::deserialize)
+                .transpose()?,
+            file: openapi_gen::axum_compat::multipart::Part::required("file", file)?
+                .into_vec(),
+        })
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/upload",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("upload"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/upload",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::multipart::MultipartForm(
+                            request_body,
+                        ): openapi_gen::axum_compat::multipart::MultipartForm<Upload>|
+                    async move { instance.upload(request_body).await }
+                }),
+            ),
+        )
+}
+impl openapi_gen::reexport::actix_web::Responder for UploadResponse {
+    type Body = openapi_gen::reexport::actix_web::body::BoxBody;
+    fn respond_to(
+        self,
+        _request: &openapi_gen::reexport::actix_web::HttpRequest,
+    ) -> openapi_gen::reexport::actix_web::HttpResponse {
+        openapi_gen::actix_compat::respond(move || {
+            Ok(
+                match self {
+                    UploadResponse::NoContent(no_content) => {
+                        let response = openapi_gen::actix_compat::response(
+                            openapi_gen::reexport::http::status::StatusCode::NO_CONTENT,
+                        );
+                        openapi_gen::actix_compat::raw(response, None, no_content)
+                    }
+                    UploadResponse::Default(default) => {
+                        let default_status = openapi_gen::AsStatusCode::as_status_code(
+                            &default,
+                        );
+                        let default = default.into_body();
+                        let response = openapi_gen::actix_compat::response(
+                            default_status,
+                        );
+                        openapi_gen::actix_compat::json(
+                            response,
+                            "application/problem+json",
+                            &default,
+                        )?
+                    }
+                },
+            )
+        })
+    }
+}
+/// Configure an actix-web service to serve an instance of [`trait Api`][Api].
+///
+/// The returned function can be passed to `App::configure` or `Scope::configure`.
+///
+/// Operations with a `multipart/form-data` request body are not served: `POST /upload`.
+pub fn configure_service<Instance>(
+    instance: Instance,
+) -> impl Clone + Send + FnOnce(
+    &mut openapi_gen::reexport::actix_web::web::ServiceConfig,
+)
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    move |
+        #[allow(unused_variables)]
+        config: &mut openapi_gen::reexport::actix_web::web::ServiceConfig|
+    {}
+}
+impl openapi_gen::tower_compat::IntoHttpResponse for UploadResponse {
+    fn into_http_response(self) -> openapi_gen::tower_compat::Response {
+        openapi_gen::tower_compat::respond(move || {
+            Ok(
+                match self {
+                    UploadResponse::NoContent(no_content) => {
+                        let response = openapi_gen::tower_compat::response(
+                            openapi_gen::reexport::http::status::StatusCode::NO_CONTENT,
+                        );
+                        openapi_gen::tower_compat::raw(response, None, no_content)
+                    }
+                    UploadResponse::Default(default) => {
+                        let default_status = openapi_gen::AsStatusCode::as_status_code(
+                            &default,
+                        );
+                        let default = default.into_body();
+                        let response = openapi_gen::tower_compat::response(
+                            default_status,
+                        );
+                        openapi_gen::tower_compat::json(
+                            response,
+                            "application/problem+json",
+                            &default,
+                        )?
+                    }
+                },
+            )
+        })
+    }
+}
+/// A `tower::Service` which serves an instance of [`trait Api`][Api].
+///
+/// Requests are routed by method and path, and their parameters and bodies decoded, before being passed
+/// to the instance. Requests which cannot be routed or decoded are rejected with an appropriate status.
+///
+/// Operations with a `multipart/form-data` request body are not served: `POST /upload`.
+pub struct ApiService<Instance> {
+    instance: ::std::sync::Arc<Instance>,
+    body_limit: usize,
+}
+impl<Instance> ApiService<Instance> {
+    pub fn new(instance: Instance) -> Self {
+        let instance = ::std::sync::Arc::new(instance);
+        let body_limit = openapi_gen::tower_compat::DEFAULT_BODY_LIMIT;
+        Self { instance, body_limit }
+    }
+    /// Reject request bodies larger than `body_limit` bytes with `413 Payload Too Large`.
+    ///
+    /// The default is [`DEFAULT_BODY_LIMIT`][openapi_gen::tower_compat::DEFAULT_BODY_LIMIT].
+    pub fn with_body_limit(mut self, body_limit: usize) -> Self {
+        self.body_limit = body_limit;
+        self
+    }
+}
+impl<Instance> Clone for ApiService<Instance> {
+    fn clone(&self) -> Self {
+        let instance = self.instance.clone();
+        let body_limit = self.body_limit;
+        Self { instance, body_limit }
+    }
+}
+impl<
+    Instance,
+    Body,
+> openapi_gen::reexport::tower_service::Service<
+    openapi_gen::reexport::http::Request<Body>,
+> for ApiService<Instance>
+where
+    Instance: 'static + Api + Send + Sync,
+    Body: 'static + openapi_gen::reexport::http_body::Body + Send,
+    Body::Data: Send,
+    Body::Error: Into<Box<dyn ::std::error::Error + Send + Sync>>,
+{
+    type Response = openapi_gen::tower_compat::Response;
+    type Error = ::std::convert::Infallible;
+    type Future = openapi_gen::tower_compat::ResponseFuture;
+    fn poll_ready(
+        &mut self,
+        _cx: &mut ::std::task::Context<'_>,
+    ) -> ::std::task::Poll<Result<(), Self::Error>> {
+        ::std::task::Poll::Ready(Ok(()))
+    }
+    #[allow(unused_variables, unused_mut)]
+    fn call(
+        &mut self,
+        request: openapi_gen::reexport::http::Request<Body>,
+    ) -> Self::Future {
+        let instance = self.instance.clone();
+        openapi_gen::tower_compat::serve(
+            request,
+            self.body_limit,
+            move |parts, body| async move {
+                let mut allow = Vec::new();
+                Err(openapi_gen::tower_compat::Rejection::not_routed(allow))
+            },
+        )
+    }
+}
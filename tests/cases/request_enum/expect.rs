@@ -26,7 +26,7 @@ pub enum MultiRequestsRequest {
 )]
 #[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
 pub enum MultiRequestsResponse {
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 ///request body is optional
 pub type OptionalRequestBodyRequest = Option<JsonType>;
@@ -40,7 +40,7 @@ pub type OptionalRequestBodyRequest = Option<JsonType>;
 )]
 #[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
 pub enum OptionalRequestBodyResponse {
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 pub type SameRequestRequest = ReqType;
 #[derive(
@@ -53,7 +53,7 @@ pub type SameRequestRequest = ReqType;
 )]
 #[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
 pub enum SameRequestResponse {
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -85,7 +85,11 @@ pub trait Api {
 impl openapi_gen::reexport::axum::response::IntoResponse for MultiRequestsResponse {
     fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
         match self {
-            MultiRequestsResponse::Default(default) => default.into_response(),
+            MultiRequestsResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -93,14 +97,22 @@ impl openapi_gen::reexport::axum::response::IntoResponse
 for OptionalRequestBodyResponse {
     fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
         match self {
-            OptionalRequestBodyResponse::Default(default) => default.into_response(),
+            OptionalRequestBodyResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
 impl openapi_gen::reexport::axum::response::IntoResponse for SameRequestResponse {
     fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
         match self {
-            SameRequestResponse::Default(default) => default.into_response(),
+            SameRequestResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -108,56 +120,85 @@ impl openapi_gen::reexport::axum::response::IntoResponse for SameRequestResponse
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/multi-requests",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<MultiRequestsRequest>,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move { instance.multi_requests(request_body).await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("multiRequests"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/multi-requests",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<MultiRequestsRequest>|
+                    async move { instance.multi_requests(request_body).await }
+                }),
+            ),
         )
         .route(
             "/optional-request-body",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("optionalRequestBody"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/optional-request-body",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<
                             OptionalRequestBodyRequest,
-                        >,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move { instance.optional_request_body(request_body).await }
-            }),
+                        >|
+                    async move { instance.optional_request_body(request_body).await }
+                }),
+            ),
         )
         .route(
             "/unified-request-body",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<SameRequestRequest>,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move { instance.same_request(request_body).await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("sameRequest"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/unified-request-body",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<SameRequestRequest>|
+                    async move { instance.same_request(request_body).await }
+                }),
+            ),
         )
 }
 
@@ -15,7 +15,7 @@ pub enum RenderPdfResponse {
     BadRequest(RenderError),
     #[serde(rename = "Service Unavailable")]
     ServiceUnavailable(openapi_gen::reexport::http_api_problem::HttpApiProblem),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -52,11 +52,15 @@ impl openapi_gen::reexport::axum::response::IntoResponse for RenderPdfResponse {
             RenderPdfResponse::ServiceUnavailable(service_unavailable) => {
                 (
                     openapi_gen::reexport::http::status::StatusCode::SERVICE_UNAVAILABLE,
-                    openapi_gen::reexport::axum::Json(service_unavailable),
+                    service_unavailable,
                 )
                     .into_response()
             }
-            RenderPdfResponse::Default(default) => default.into_response(),
+            RenderPdfResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -64,16 +68,39 @@ impl openapi_gen::reexport::axum::response::IntoResponse for RenderPdfResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/render",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move || async move { instance.render_pdf().await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("renderPdf"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/render",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move || async move { instance.render_pdf().await }
+                }),
+            ),
         )
 }
 
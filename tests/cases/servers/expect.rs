@@ -0,0 +1,203 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum ListThingsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+///Region of the data center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProductionRegion {
+    UsEast,
+    #[default]
+    EuWest,
+}
+impl ProductionRegion {
+    /// The value which is substituted into the server URL.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UsEast => "us-east",
+            Self::EuWest => "eu-west",
+        }
+    }
+}
+impl ::std::fmt::Display for ProductionRegion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+///Permitted values of server variable `type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StagingType {
+    V1,
+    #[default]
+    V1_,
+}
+impl StagingType {
+    /// The value which is substituted into the server URL.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V1_ => "V1",
+        }
+    }
+}
+impl ::std::fmt::Display for StagingType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+/// Servers which host this API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Server {
+    ///Production
+    Production {
+        ///Region of the data center.
+        region: ProductionRegion,
+        port: String,
+    },
+    ///Local
+    Local,
+    ///Staging
+    Staging { type_: StagingType, url_: String },
+}
+impl Server {
+    ///The server at `https://{region}.example.com:{port}/api/v2`, with the default value of each variable.
+    pub fn production() -> Self {
+        Self::Production {
+            region: ProductionRegion::default(),
+            port: "8443".to_owned(),
+        }
+    }
+    ///The server at `/api/v2`, with the default value of each variable.
+    pub fn local() -> Self {
+        Self::Local
+    }
+    ///The server at `https://{type}.example.com/{url}/{unknown}`, with the default value of each variable.
+    pub fn staging() -> Self {
+        Self::Staging {
+            type_: StagingType::default(),
+            url_: "acme".to_owned(),
+        }
+    }
+    /// Render the base URL of this server.
+    pub fn url(&self) -> String {
+        let mut url = String::new();
+        match self {
+            Self::Production { region, port } => {
+                url.push_str("https://");
+                url.push_str(region.as_str());
+                url.push_str(".example.com:");
+                url.push_str(port);
+                url.push_str("/api/v2");
+            }
+            Self::Local => {
+                url.push_str("/api/v2");
+            }
+            Self::Staging { type_, url_ } => {
+                url.push_str("https://");
+                url.push_str(type_.as_str());
+                url.push_str(".example.com/");
+                url.push_str(url_);
+                url.push_str("/{unknown}");
+            }
+        }
+        url
+    }
+}
+impl Default for Server {
+    fn default() -> Self {
+        Self::production()
+    }
+}
+/// Path at which the API is mounted on the default server.
+pub const BASE_PATH: &str = "/api/v2";
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `GET /things`
+    /// 
+    /// Operation ID: `listThings`
+    async fn list_things(&self) -> ListThingsResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for ListThingsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            ListThingsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router], mounted at [`BASE_PATH`].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_at(instance, BASE_PATH)
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router], mounted at `base_path`.
+pub fn build_router_at<Instance>(
+    instance: Instance,
+    base_path: &str,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_at_with(instance, base_path, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router], mounted at [`BASE_PATH`].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    build_router_at_with(instance, BASE_PATH, customize)
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router], mounted at `base_path`.
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_at_with<Instance, Customization>(
+    instance: Instance,
+    base_path: &str,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    let router = openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/things",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listThings"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/things",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_things().await }
+                }),
+            ),
+        );
+    openapi_gen::axum_compat::base_path::nest(base_path, router)
+}
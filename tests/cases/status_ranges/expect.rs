@@ -0,0 +1,98 @@
+#![allow(non_camel_case_types)]
+pub type Health = String;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum CheckHealthResponse {
+    #[serde(rename = "success range")]
+    SuccessRange(openapi_gen::WithStatus<Health, 2>),
+    #[serde(rename = "server error range")]
+    ServerErrorRange(
+        openapi_gen::WithStatus<
+            openapi_gen::reexport::http_api_problem::HttpApiProblem,
+            5,
+        >,
+    ),
+    Default(
+        openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>,
+    ),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `GET /health`
+    /// 
+    /// Operation ID: `checkHealth`
+    async fn check_health(&self) -> CheckHealthResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for CheckHealthResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            CheckHealthResponse::SuccessRange(success_range) => {
+                let success_range_status = openapi_gen::AsStatusCode::as_status_code(
+                    &success_range,
+                );
+                let success_range = success_range.into_body();
+                (success_range_status, openapi_gen::reexport::axum::Json(success_range))
+                    .into_response()
+            }
+            CheckHealthResponse::ServerErrorRange(server_error_range) => {
+                let server_error_range_status = openapi_gen::AsStatusCode::as_status_code(
+                    &server_error_range,
+                );
+                let server_error_range = server_error_range.into_body();
+                (server_error_range_status, server_error_range).into_response()
+            }
+            CheckHealthResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/health",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("checkHealth"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/health",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.check_health().await }
+                }),
+            ),
+        )
+}
@@ -0,0 +1,380 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum ListPetsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum ListMorePetsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum GetDefaultsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum GetYearResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+///Pets, capitalized.
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait PetsApi {
+
+    /// `GET /pets`
+    /// 
+    /// Operation ID: `listPets`
+    async fn list_pets(&self) -> ListPetsResponse;
+}
+///Pets, in lower case.
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Pets2Api {
+
+    /// `GET /more-pets`
+    /// 
+    /// Operation ID: `listMorePets`
+    async fn list_more_pets(&self) -> ListMorePetsResponse;
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Default2Api {
+
+    /// `GET /defaults`
+    /// 
+    /// Operation ID: `getDefaults`
+    async fn get_defaults(&self) -> GetDefaultsResponse;
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Tag2024Api {
+
+    /// `GET /years/2024`
+    /// 
+    /// Operation ID: `getYear`
+    async fn get_year(&self) -> GetYearResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for ListPetsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            ListPetsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for ListMorePetsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            ListMorePetsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for GetDefaultsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            GetDefaultsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for GetYearResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            GetYearResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait PetsApi`][PetsApi] into a [`Router`][axum::Router].
+pub fn build_pets_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + Send + Sync,
+{
+    build_pets_router_with(instance, ())
+}
+/// Transform an instance of [`trait PetsApi`][PetsApi] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_pets_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listPets"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/pets",
+                    tag: Some("Pets"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_pets().await }
+                }),
+            ),
+        )
+}
+/// Transform an instance of [`trait Pets2Api`][Pets2Api] into a [`Router`][axum::Router].
+pub fn build_pets2_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Pets2Api + Send + Sync,
+{
+    build_pets2_router_with(instance, ())
+}
+/// Transform an instance of [`trait Pets2Api`][Pets2Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_pets2_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Pets2Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/more-pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listMorePets"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/more-pets",
+                    tag: Some("pets"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_more_pets().await }
+                }),
+            ),
+        )
+}
+/// Transform an instance of [`trait Default2Api`][Default2Api] into a [`Router`][axum::Router].
+pub fn build_default2_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Default2Api + Send + Sync,
+{
+    build_default2_router_with(instance, ())
+}
+/// Transform an instance of [`trait Default2Api`][Default2Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_default2_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Default2Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/defaults",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getDefaults"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/defaults",
+                    tag: Some("default"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.get_defaults().await }
+                }),
+            ),
+        )
+}
+/// Transform an instance of [`trait Tag2024Api`][Tag2024Api] into a [`Router`][axum::Router].
+pub fn build_tag_2024_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Tag2024Api + Send + Sync,
+{
+    build_tag_2024_router_with(instance, ())
+}
+/// Transform an instance of [`trait Tag2024Api`][Tag2024Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_tag_2024_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Tag2024Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/years/2024",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getYear"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/years/2024",
+                    tag: Some("2024"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.get_year().await }
+                }),
+            ),
+        )
+}
+/// Transform an implementation of every API trait into a [`Router`][axum::Router].
+pub fn build_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + Pets2Api + Default2Api + Tag2024Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an implementation of every API trait into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + Pets2Api + Default2Api + Tag2024Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listPets"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/pets",
+                    tag: Some("Pets"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_pets().await }
+                }),
+            ),
+        )
+        .route(
+            "/more-pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listMorePets"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/more-pets",
+                    tag: Some("pets"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_more_pets().await }
+                }),
+            ),
+        )
+        .route(
+            "/defaults",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getDefaults"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/defaults",
+                    tag: Some("default"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.get_defaults().await }
+                }),
+            ),
+        )
+        .route(
+            "/years/2024",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getYear"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/years/2024",
+                    tag: Some("2024"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.get_year().await }
+                }),
+            ),
+        )
+}
@@ -0,0 +1,144 @@
+#![allow(non_camel_case_types)]
+pub type Note = String;
+pub type CreateNoteRequest = Note;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum CreateNoteResponse {
+    #[serde(rename = "OK")]
+    Ok(i64),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum PutBlobResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `POST /notes`
+    /// 
+    /// Operation ID: `createNote`
+    async fn create_note(&self, request_body: CreateNoteRequest) -> CreateNoteResponse;
+
+    /// `PUT /blobs`
+    /// 
+    /// Operation ID: `putBlob`
+    async fn put_blob(
+        &self,
+        request_body: openapi_gen::reexport::bytes::Bytes,
+    ) -> PutBlobResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for CreateNoteResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            CreateNoteResponse::Ok(ok) => {
+                (
+                    openapi_gen::reexport::http::status::StatusCode::OK,
+                    openapi_gen::axum_compat::text::TextResponse {
+                        content_type: "text/plain",
+                        value: ok,
+                    },
+                )
+                    .into_response()
+            }
+            CreateNoteResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for PutBlobResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            PutBlobResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+            PutBlobResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/notes",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("createNote"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/notes",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::text::Text(
+                            request_body,
+                        ): openapi_gen::axum_compat::text::Text<CreateNoteRequest>|
+                    async move { instance.create_note(request_body).await }
+                }),
+            ),
+        )
+        .route(
+            "/blobs",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("putBlob"),
+                    method: openapi_gen::reexport::http::Method::PUT,
+                    path: "/blobs",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::put({
+                    let instance = instance.clone();
+                    move |request_body: openapi_gen::reexport::bytes::Bytes| async move {
+                        instance.put_blob(request_body).await
+                    }
+                }),
+            ),
+        )
+}
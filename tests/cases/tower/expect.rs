@@ -0,0 +1,409 @@
+#![allow(non_camel_case_types)]
+///an identifier for this particular identification process
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct IdentificationId(pub openapi_gen::reexport::uuid::Uuid);
+openapi_gen::newtype_derive_canonical_form!(
+    IdentificationId, openapi_gen::reexport::uuid::Uuid
+);
+
+/// An identifier for a document within the context of the identification service.
+/// 
+/// This is _not_ associated with the documents service in any way.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct DocumentId(pub openapi_gen::reexport::uuid::Uuid);
+openapi_gen::newtype_derive_canonical_form!(
+    DocumentId, openapi_gen::reexport::uuid::Uuid
+);
+///Combination item for path parameters of `getNpIdentityDocumentData`
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Copy,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct GetNpIdentityDocumentDataPathParameters {
+    ///an identifier for this particular identification process
+    #[serde(rename = "identification-id")]
+    pub identification_id: IdentificationId,
+
+    /// An identifier for a document within the context of the identification service.
+    /// 
+    /// This is _not_ associated with the documents service in any way.
+    #[serde(rename = "document-id")]
+    pub document_id: DocumentId,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct OkApplicationJson {
+    ///document data encoded as base64
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<openapi_gen::Bytes>,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum GetNpIdentityDocumentDataResponse {
+    #[serde(rename = "OK application/json")]
+    OkApplicationJson(OkApplicationJson),
+    #[serde(rename = "OK *")]
+    Ok(Vec<u8>),
+    #[serde(rename = "Not Acceptable")]
+    NotAcceptable(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// Get this identity document's raw data.
+    /// 
+    /// If the client accepts only `application/json`, then the data will be base64 encoded and enclosed in a small wrapper object.
+    /// Otherwise, the actual document content type will be returned, and the document data will be unencoded.
+    /// 
+    /// 
+    /// ## Endpoint Data
+    /// 
+    /// `GET /natural-persons/{identification-id}/documents/{document-id}/data`
+    /// 
+    /// Operation ID: `getNpIdentityDocumentData`
+    async fn get_np_identity_document_data(
+        &self,
+        identification_id: IdentificationId,
+        document_id: DocumentId,
+        accept: Option<openapi_gen::reexport::accept_header::Accept>,
+    ) -> GetNpIdentityDocumentDataResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse
+for GetNpIdentityDocumentDataResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            GetNpIdentityDocumentDataResponse::OkApplicationJson(
+                ok_application_json,
+            ) => {
+                let mut header_map = openapi_gen::reexport::http::header::HeaderMap::with_capacity(
+                    1usize,
+                );
+                header_map
+                    .insert(
+                        openapi_gen::reexport::http::header::CONTENT_TYPE,
+                        openapi_gen::reexport::http::HeaderValue::from_static(
+                            "application/json",
+                        ),
+                    );
+                (
+                    openapi_gen::reexport::http::status::StatusCode::OK,
+                    header_map,
+                    openapi_gen::reexport::axum::Json(ok_application_json),
+                )
+                    .into_response()
+            }
+            GetNpIdentityDocumentDataResponse::Ok(ok) => {
+                let mut header_map = openapi_gen::reexport::http::header::HeaderMap::with_capacity(
+                    1usize,
+                );
+                header_map
+                    .insert(
+                        openapi_gen::reexport::http::header::CONTENT_TYPE,
+                        openapi_gen::reexport::http::HeaderValue::from_static("*"),
+                    );
+                (openapi_gen::reexport::http::status::StatusCode::OK, header_map, ok)
+                    .into_response()
+            }
+            GetNpIdentityDocumentDataResponse::NotAcceptable(not_acceptable) => {
+                (
+                    openapi_gen::reexport::http::status::StatusCode::NOT_ACCEPTABLE,
+                    not_acceptable,
+                )
+                    .into_response()
+            }
+            GetNpIdentityDocumentDataResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/natural-persons/:identification-id/documents/:document-id/data",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getNpIdentityDocumentData"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/natural-persons/{identification-id}/documents/{document-id}/data",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::reexport::axum_extra::extract::WithRejection(
+                            openapi_gen::reexport::axum::extract::Path(
+                                GetNpIdentityDocumentDataPathParameters {
+                                    identification_id,
+                                    document_id,
+                                },
+                            ),
+                            _,
+                        ): openapi_gen::reexport::axum_extra::extract::WithRejection<
+                            openapi_gen::reexport::axum::extract::Path<
+                                GetNpIdentityDocumentDataPathParameters,
+                            >,
+                            openapi_gen::axum_compat::ApiProblemRejection,
+                        >,
+                        accept: Option<
+                            openapi_gen::reexport::axum_extra::TypedHeader<
+                                openapi_gen::reexport::accept_header::Accept,
+                            >,
+                        >|
+                    async move {
+                        let accept = accept.map(|accept| accept.0);
+                        instance
+                            .get_np_identity_document_data(
+                                identification_id,
+                                document_id,
+                                accept,
+                            )
+                            .await
+                    }
+                }),
+            ),
+        )
+}
+
+impl openapi_gen::tower_compat::IntoHttpResponse for GetNpIdentityDocumentDataResponse {
+    fn into_http_response(self) -> openapi_gen::tower_compat::Response {
+        openapi_gen::tower_compat::respond(move || {
+            Ok(
+                match self {
+                    GetNpIdentityDocumentDataResponse::OkApplicationJson(
+                        ok_application_json,
+                    ) => {
+                        let response = openapi_gen::tower_compat::response(
+                            openapi_gen::reexport::http::status::StatusCode::OK,
+                        );
+                        openapi_gen::tower_compat::json(
+                            response,
+                            "application/json",
+                            &ok_application_json,
+                        )?
+                    }
+                    GetNpIdentityDocumentDataResponse::Ok(ok) => {
+                        let response = openapi_gen::tower_compat::response(
+                            openapi_gen::reexport::http::status::StatusCode::OK,
+                        );
+                        openapi_gen::tower_compat::raw(response, Some("*"), ok)
+                    }
+                    GetNpIdentityDocumentDataResponse::NotAcceptable(not_acceptable) => {
+                        let response = openapi_gen::tower_compat::response(
+                            openapi_gen::reexport::http::status::StatusCode::NOT_ACCEPTABLE,
+                        );
+                        openapi_gen::tower_compat::json(
+                            response,
+                            "application/problem+json",
+                            &not_acceptable,
+                        )?
+                    }
+                    GetNpIdentityDocumentDataResponse::Default(default) => {
+                        let default_status = openapi_gen::AsStatusCode::as_status_code(
+                            &default,
+                        );
+                        let default = default.into_body();
+                        let response = openapi_gen::tower_compat::response(
+                            default_status,
+                        );
+                        openapi_gen::tower_compat::json(
+                            response,
+                            "application/problem+json",
+                            &default,
+                        )?
+                    }
+                },
+            )
+        })
+    }
+}
+#[allow(unused_variables)]
+async fn handle_get_np_identity_document_data<Instance>(
+    instance: &Instance,
+    parts: &openapi_gen::reexport::http::request::Parts,
+    body: openapi_gen::reexport::bytes::Bytes,
+    path_parameters: Vec<(String, String)>,
+) -> Result<openapi_gen::tower_compat::Response, openapi_gen::tower_compat::Rejection>
+where
+    Instance: Api + Sync,
+{
+    let GetNpIdentityDocumentDataPathParameters { identification_id, document_id } = openapi_gen::tower_compat::path_parameters::<
+        GetNpIdentityDocumentDataPathParameters,
+    >(path_parameters)?;
+    let accept = openapi_gen::tower_compat::header_from_str::<
+        openapi_gen::reexport::accept_header::Accept,
+    >(parts, "accept")?;
+    Ok(
+        openapi_gen::tower_compat::IntoHttpResponse::into_http_response(
+            instance
+                .get_np_identity_document_data(identification_id, document_id, accept)
+                .await,
+        ),
+    )
+}
+/// A `tower::Service` which serves an instance of [`trait Api`][Api].
+///
+/// Requests are routed by method and path, and their parameters and bodies decoded, before being passed
+/// to the instance. Requests which cannot be routed or decoded are rejected with an appropriate status.
+pub struct ApiService<Instance> {
+    instance: ::std::sync::Arc<Instance>,
+    body_limit: usize,
+}
+impl<Instance> ApiService<Instance> {
+    pub fn new(instance: Instance) -> Self {
+        let instance = ::std::sync::Arc::new(instance);
+        let body_limit = openapi_gen::tower_compat::DEFAULT_BODY_LIMIT;
+        Self { instance, body_limit }
+    }
+    /// Reject request bodies larger than `body_limit` bytes with `413 Payload Too Large`.
+    ///
+    /// The default is [`DEFAULT_BODY_LIMIT`][openapi_gen::tower_compat::DEFAULT_BODY_LIMIT].
+    pub fn with_body_limit(mut self, body_limit: usize) -> Self {
+        self.body_limit = body_limit;
+        self
+    }
+}
+impl<Instance> Clone for ApiService<Instance> {
+    fn clone(&self) -> Self {
+        let instance = self.instance.clone();
+        let body_limit = self.body_limit;
+        Self { instance, body_limit }
+    }
+}
+impl<
+    Instance,
+    Body,
+> openapi_gen::reexport::tower_service::Service<
+    openapi_gen::reexport::http::Request<Body>,
+> for ApiService<Instance>
+where
+    Instance: 'static + Api + Send + Sync,
+    Body: 'static + openapi_gen::reexport::http_body::Body + Send,
+    Body::Data: Send,
+    Body::Error: Into<Box<dyn ::std::error::Error + Send + Sync>>,
+{
+    type Response = openapi_gen::tower_compat::Response;
+    type Error = ::std::convert::Infallible;
+    type Future = openapi_gen::tower_compat::ResponseFuture;
+    fn poll_ready(
+        &mut self,
+        _cx: &mut ::std::task::Context<'_>,
+    ) -> ::std::task::Poll<Result<(), Self::Error>> {
+        ::std::task::Poll::Ready(Ok(()))
+    }
+    fn call(
+        &mut self,
+        request: openapi_gen::reexport::http::Request<Body>,
+    ) -> Self::Future {
+        let instance = self.instance.clone();
+        openapi_gen::tower_compat::serve(
+            request,
+            self.body_limit,
+            move |parts, body| async move {
+                let mut allow = Vec::new();
+                if let Some(path_parameters) = openapi_gen::tower_compat::match_path(
+                    "/natural-persons/{identification-id}/documents/{document-id}/data",
+                    parts.uri.path(),
+                ) {
+                    match parts.method {
+                        openapi_gen::reexport::http::Method::GET => {
+                            return handle_get_np_identity_document_data(
+                                    &*instance,
+                                    &parts,
+                                    body,
+                                    path_parameters,
+                                )
+                                .await;
+                        }
+                        openapi_gen::reexport::http::Method::HEAD => {
+                            return openapi_gen::tower_compat::head(
+                                handle_get_np_identity_document_data(
+                                        &*instance,
+                                        &parts,
+                                        body,
+                                        path_parameters,
+                                    )
+                                    .await,
+                            );
+                        }
+                        _ => allow.extend([
+                                openapi_gen::reexport::http::Method::GET,
+                                openapi_gen::reexport::http::Method::HEAD,
+                            ]),
+                    }
+                }
+                Err(openapi_gen::tower_compat::Rejection::not_routed(allow))
+            },
+        )
+    }
+}
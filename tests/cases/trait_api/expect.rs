@@ -13,7 +13,7 @@ pub type PostKudosRequest = PostKudo;
 #[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
 pub enum PostKudosResponse {
     Created(()),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -30,7 +30,11 @@ impl openapi_gen::reexport::axum::response::IntoResponse for PostKudosResponse {
                 (openapi_gen::reexport::http::status::StatusCode::CREATED, created)
                     .into_response()
             }
-            PostKudosResponse::Default(default) => default.into_response(),
+            PostKudosResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -38,24 +42,43 @@ impl openapi_gen::reexport::axum::response::IntoResponse for PostKudosResponse {
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/post-kudos",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<PostKudosRequest>,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move { instance.post_kudos(request_body).await }
-            }),
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("postKudos"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/post-kudos",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<PostKudosRequest>|
+                    async move { instance.post_kudos(request_body).await }
+                }),
+            ),
         )
 }
 
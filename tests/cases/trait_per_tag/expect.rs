@@ -0,0 +1,291 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum ListPetsResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum GetInventoryResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum HealthResponse {
+    #[serde(rename = "No Content")]
+    NoContent(()),
+}
+///Everything about your pets.
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait PetsApi {
+
+    /// `GET /pets`
+    /// 
+    /// Operation ID: `listPets`
+    async fn list_pets(&self) -> ListPetsResponse;
+}
+///Access to the store.
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait StoreApi {
+
+    /// `GET /store/inventory`
+    /// 
+    /// Operation ID: `getInventory`
+    async fn get_inventory(&self) -> GetInventoryResponse;
+}
+///Operations without a tag.
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait DefaultApi {
+
+    /// `GET /health`
+    /// 
+    /// Operation ID: `health`
+    async fn health(&self) -> HealthResponse;
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for ListPetsResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            ListPetsResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for GetInventoryResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            GetInventoryResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for HealthResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            HealthResponse::NoContent(no_content) => {
+                (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
+                    .into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait PetsApi`][PetsApi] into a [`Router`][axum::Router].
+pub fn build_pets_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + Send + Sync,
+{
+    build_pets_router_with(instance, ())
+}
+/// Transform an instance of [`trait PetsApi`][PetsApi] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_pets_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listPets"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/pets",
+                    tag: Some("pets"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_pets().await }
+                }),
+            ),
+        )
+}
+/// Transform an instance of [`trait StoreApi`][StoreApi] into a [`Router`][axum::Router].
+pub fn build_store_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + StoreApi + Send + Sync,
+{
+    build_store_router_with(instance, ())
+}
+/// Transform an instance of [`trait StoreApi`][StoreApi] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_store_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + StoreApi + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/store/inventory",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getInventory"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/store/inventory",
+                    tag: Some("store"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.get_inventory().await }
+                }),
+            ),
+        )
+}
+/// Transform an instance of [`trait DefaultApi`][DefaultApi] into a [`Router`][axum::Router].
+pub fn build_default_router<Instance>(
+    instance: Instance,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + DefaultApi + Send + Sync,
+{
+    build_default_router_with(instance, ())
+}
+/// Transform an instance of [`trait DefaultApi`][DefaultApi] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_default_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + DefaultApi + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/health",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("health"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/health",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.health().await }
+                }),
+            ),
+        )
+}
+/// Transform an implementation of every API trait into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + StoreApi + DefaultApi + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an implementation of every API trait into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + PetsApi + StoreApi + DefaultApi + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("listPets"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/pets",
+                    tag: Some("pets"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.list_pets().await }
+                }),
+            ),
+        )
+        .route(
+            "/store/inventory",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("getInventory"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/store/inventory",
+                    tag: Some("store"),
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.get_inventory().await }
+                }),
+            ),
+        )
+        .route(
+            "/health",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("health"),
+                    method: openapi_gen::reexport::http::Method::GET,
+                    path: "/health",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::get({
+                    let instance = instance.clone();
+                    move || async move { instance.health().await }
+                }),
+            ),
+        )
+}
@@ -0,0 +1,272 @@
+#![allow(non_camel_case_types)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct Pet {
+    pub name: String,
+    pub weight: f64,
+    #[serde(deserialize_with = "openapi_gen::validation::required")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+///Combination item for query parameters of `createPet`
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq,
+    Hash,
+    openapi_gen::reexport::derive_more::Constructor
+)]
+#[serde(crate = "openapi_gen::reexport::serde")]
+pub struct CreatePetQueryParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    pub owner: String,
+}
+pub type CreatePetRequest = Pet;
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    openapi_gen::reexport::serde::Serialize,
+    openapi_gen::reexport::serde::Deserialize,
+    Eq
+)]
+#[serde(crate = "openapi_gen::reexport::serde", tag = "status")]
+pub enum CreatePetResponse {
+    Created(()),
+    Default(
+        openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>,
+    ),
+}
+#[openapi_gen::reexport::async_trait::async_trait]
+pub trait Api {
+
+    /// `POST /pets`
+    /// 
+    /// Operation ID: `createPet`
+    async fn create_pet(
+        &self,
+        limit: Option<i64>,
+        owner: String,
+        request_body: CreatePetRequest,
+    ) -> CreatePetResponse;
+}
+impl openapi_gen::validation::Validate for Pet {
+    fn validate(&self, violations: &mut openapi_gen::validation::Violations) {
+        violations
+            .at(
+                "name",
+                |violations| {
+                    let value = &self.name;
+                    openapi_gen::validation::length(
+                        value,
+                        Some(1),
+                        Some(32),
+                        violations,
+                    );
+                    {
+                        static PATTERN: openapi_gen::validation::Pattern = openapi_gen::validation::Pattern::new(
+                            "^[A-Z]",
+                        );
+                        openapi_gen::validation::pattern(value, &PATTERN, violations);
+                    }
+                    openapi_gen::validation::check_constraints(value, violations);
+                },
+            );
+        violations
+            .at(
+                "weight",
+                |violations| {
+                    let value = &self.weight;
+                    openapi_gen::validation::minimum(*value, 0.0, true, violations);
+                    openapi_gen::validation::check_constraints(value, violations);
+                },
+            );
+        violations
+            .at(
+                "nickname",
+                |violations| {
+                    let value = &self.nickname;
+                    if let Some(value) = value {
+                        openapi_gen::validation::check_constraints(value, violations);
+                    }
+                },
+            );
+        violations
+            .at(
+                "tags",
+                |violations| {
+                    if let Some(value) = &self.tags {
+                        openapi_gen::validation::items(
+                            value.len(),
+                            None,
+                            Some(8),
+                            violations,
+                        );
+                        for (idx, item) in value.iter().enumerate() {
+                            violations
+                                .at(
+                                    idx,
+                                    |violations| {
+                                        openapi_gen::validation::length(
+                                            item,
+                                            Some(1),
+                                            None,
+                                            violations,
+                                        );
+                                        openapi_gen::validation::check_constraints(
+                                            item,
+                                            violations,
+                                        );
+                                    },
+                                );
+                        }
+                    }
+                },
+            );
+    }
+}
+impl openapi_gen::validation::Validate for CreatePetQueryParameters {
+    fn validate(&self, violations: &mut openapi_gen::validation::Violations) {
+        violations
+            .at(
+                "limit",
+                |violations| {
+                    if let Some(value) = &self.limit {
+                        openapi_gen::validation::one_of(value, &[10, 20], violations);
+                        openapi_gen::validation::check_constraints(value, violations);
+                    }
+                },
+            );
+        violations
+            .at(
+                "owner",
+                |violations| {
+                    let value = &self.owner;
+                    openapi_gen::validation::format(value, "email", violations);
+                    openapi_gen::validation::check_constraints(value, violations);
+                },
+            );
+    }
+}
+impl openapi_gen::reexport::axum::response::IntoResponse for CreatePetResponse {
+    fn into_response(self) -> openapi_gen::reexport::axum::response::Response {
+        match self {
+            CreatePetResponse::Created(created) => {
+                (openapi_gen::reexport::http::status::StatusCode::CREATED, created)
+                    .into_response()
+            }
+            CreatePetResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
+        }
+    }
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
+{
+    #[allow(unused_variables)]
+    let instance = ::std::sync::Arc::new(instance);
+    openapi_gen::reexport::axum::Router::new()
+        .route(
+            "/pets",
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: Some("createPet"),
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/pets",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::query::Query(
+                            CreatePetQueryParameters { limit, owner },
+                        ): openapi_gen::axum_compat::query::Query<CreatePetQueryParameters>,
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<CreatePetRequest>|
+                    async move {
+                        let mut violations = openapi_gen::validation::Violations::new();
+                        violations
+                            .parameter(
+                                openapi_gen::validation::Source::Query,
+                                "limit",
+                                |violations| {
+                                    if let Some(value) = &limit {
+                                        openapi_gen::validation::one_of(
+                                            value,
+                                            &[10, 20],
+                                            violations,
+                                        );
+                                        openapi_gen::validation::check_constraints(
+                                            value,
+                                            violations,
+                                        );
+                                    }
+                                },
+                            );
+                        violations
+                            .parameter(
+                                openapi_gen::validation::Source::Query,
+                                "owner",
+                                |violations| {
+                                    let value = &owner;
+                                    openapi_gen::validation::format(value, "email", violations);
+                                    openapi_gen::validation::check_constraints(
+                                        value,
+                                        violations,
+                                    );
+                                },
+                            );
+                        violations
+                            .body(|violations| {
+                                let value = &request_body;
+                                openapi_gen::validation::Validate::validate(
+                                    value,
+                                    violations,
+                                );
+                            });
+                        if let Err(rejection) = violations.into_result() {
+                            return openapi_gen::reexport::axum::response::IntoResponse::into_response(
+                                rejection,
+                            );
+                        }
+                        openapi_gen::reexport::axum::response::IntoResponse::into_response(
+                            instance.create_pet(limit, owner, request_body).await,
+                        )
+                    }
+                }),
+            ),
+        )
+}
@@ -12,7 +12,7 @@ pub type PostWellKnownTypesRequest = openapi_gen::reexport::serde_json::Value;
 pub enum PostWellKnownTypesResponse {
     #[serde(rename = "No Content")]
     NoContent(()),
-    Default(openapi_gen::reexport::http_api_problem::HttpApiProblem),
+    Default(openapi_gen::WithStatus<openapi_gen::reexport::http_api_problem::HttpApiProblem>),
 }
 #[openapi_gen::reexport::async_trait::async_trait]
 pub trait Api {
@@ -30,7 +30,11 @@ impl openapi_gen::reexport::axum::response::IntoResponse for PostWellKnownTypesR
                 (openapi_gen::reexport::http::status::StatusCode::NO_CONTENT, no_content)
                     .into_response()
             }
-            PostWellKnownTypesResponse::Default(default) => default.into_response(),
+            PostWellKnownTypesResponse::Default(default) => {
+                let default_status = openapi_gen::AsStatusCode::as_status_code(&default);
+                let default = default.into_body();
+                (default_status, default).into_response()
+            }
         }
     }
 }
@@ -38,26 +42,45 @@ impl openapi_gen::reexport::axum::response::IntoResponse for PostWellKnownTypesR
 pub fn build_router<Instance>(instance: Instance) -> openapi_gen::reexport::axum::Router
 where
     Instance: 'static + Api + Send + Sync,
+{
+    build_router_with(instance, ())
+}
+/// Transform an instance of [`trait Api`][Api] into a [`Router`][axum::Router].
+///
+/// Before it is added to the router, the route of each operation is passed through `customize`.
+pub fn build_router_with<Instance, Customization>(
+    instance: Instance,
+    #[allow(unused_variables)]
+    customize: Customization,
+) -> openapi_gen::reexport::axum::Router
+where
+    Instance: 'static + Api + Send + Sync,
+    Customization: openapi_gen::axum_compat::customize::Customize,
 {
     #[allow(unused_variables)]
     let instance = ::std::sync::Arc::new(instance);
     openapi_gen::reexport::axum::Router::new()
         .route(
             "/well-known-types",
-            openapi_gen::reexport::axum::routing::post({
-                let instance = instance.clone();
-                move |
-                    openapi_gen::reexport::axum_extra::extract::WithRejection(
-                        openapi_gen::reexport::axum::extract::Json(request_body),
-                        _,
-                    ): openapi_gen::reexport::axum_extra::extract::WithRejection<
-                        openapi_gen::reexport::axum::extract::Json<
+            openapi_gen::axum_compat::customize::Customize::route(
+                &customize,
+                &openapi_gen::axum_compat::customize::Operation {
+                    operation_id: None,
+                    method: openapi_gen::reexport::http::Method::POST,
+                    path: "/well-known-types",
+                    tag: None,
+                },
+                openapi_gen::reexport::axum::routing::post({
+                    let instance = instance.clone();
+                    move |
+                        openapi_gen::axum_compat::json::Json(
+                            request_body,
+                        ): openapi_gen::axum_compat::json::Json<
                             PostWellKnownTypesRequest,
-                        >,
-                        openapi_gen::axum_compat::ApiProblemRejection,
-                    >|
-                async move { instance.post_well_known_types(request_body).await }
-            }),
+                        >|
+                    async move { instance.post_well_known_types(request_body).await }
+                }),
+            ),
         )
 }
 
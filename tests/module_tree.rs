@@ -0,0 +1,162 @@
+//! Emit the module tree of a specification, and build it as a crate.
+//!
+//! The specification is emitted twice: once with a module per operation, and once with a module per tag. Both
+//! trees are written into a crate in a temporary directory, which must build with the same features as this crate.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use openapi_gen::{ApiModel, ModuleFile};
+use openapiv3::OpenAPI;
+
+/// Features of this crate which change the generated code, and so must be enabled for the generated crate.
+const FEATURES: &[(&str, bool)] = &[
+    ("actix-support", cfg!(feature = "actix-support")),
+    ("api-problem", cfg!(feature = "api-problem")),
+    ("axum-support", cfg!(feature = "axum-support")),
+    ("bytes", cfg!(feature = "bytes")),
+    (
+        "integer-restrictions",
+        cfg!(feature = "integer-restrictions"),
+    ),
+    ("reqwest-support", cfg!(feature = "reqwest-support")),
+    ("string-pattern", cfg!(feature = "string-pattern")),
+    ("tower-support", cfg!(feature = "tower-support")),
+    ("uuid", cfg!(feature = "uuid")),
+];
+
+fn emit(trait_per_tag: bool) -> Vec<ModuleFile> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/module_tree/pets.openapi.yaml");
+    let spec = std::fs::read_to_string(&path).expect("the specification should be readable");
+    let spec: OpenAPI = openapi_gen::deserialize_spec(serde_yaml::Deserializer::from_str(&spec))
+        .expect("the specification should be valid");
+    let mut model = ApiModel::new(&spec, Some(&path)).expect("the model should be constructed");
+    model.options_mut().trait_per_tag = trait_per_tag;
+    model
+        .emit_module_tree(false)
+        .expect("the module tree should be emitted")
+}
+
+fn paths(files: &[ModuleFile]) -> BTreeSet<PathBuf> {
+    files.iter().map(|file| file.path.clone()).collect()
+}
+
+fn contents<'a>(files: &'a [ModuleFile], path: &str) -> &'a str {
+    files
+        .iter()
+        .find(|file| file.path == Path::new(path))
+        .map(|file| file.contents.as_str())
+        .unwrap_or_else(|| panic!("{path} should be emitted"))
+}
+
+#[test]
+fn models_and_operations_are_directories() {
+    let files = emit(false);
+    let paths = paths(&files);
+    for path in [
+        "mod.rs",
+        "api.rs",
+        "models/mod.rs",
+        "models/pet.rs",
+        "models/pet_kind.rs",
+        "parameters/mod.rs",
+        "parameters/list_pets.rs",
+        "requests/mod.rs",
+        "requests/create_pet.rs",
+        "responses/mod.rs",
+        "responses/list_pets.rs",
+        "responses/create_pet.rs",
+        "responses/health.rs",
+    ] {
+        assert!(paths.contains(Path::new(path)), "{path} should be emitted");
+    }
+    for path in ["models.rs", "parameters.rs", "requests.rs", "responses.rs"] {
+        assert!(
+            !paths.contains(Path::new(path)),
+            "{path} should not be emitted"
+        );
+    }
+
+    assert!(contents(&files, "models/pet.rs").contains("pub struct Pet "));
+    assert!(contents(&files, "models/mod.rs").contains("pub mod pet_kind;"));
+    assert!(contents(&files, "responses/health.rs").contains("pub enum HealthResponse "));
+}
+
+#[test]
+fn operations_are_grouped_per_tag() {
+    let files = emit(true);
+    let paths = paths(&files);
+    for path in [
+        "models/pet.rs",
+        "parameters/pets.rs",
+        "requests/pets.rs",
+        "responses/pets.rs",
+        "responses/default.rs",
+    ] {
+        assert!(paths.contains(Path::new(path)), "{path} should be emitted");
+    }
+    assert!(!paths.contains(Path::new("responses/list_pets.rs")));
+
+    let pets = contents(&files, "responses/pets.rs");
+    assert!(pets.contains("pub enum ListPetsResponse "));
+    assert!(pets.contains("pub enum CreatePetResponse "));
+}
+
+#[test]
+fn module_trees_build() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("module_tree");
+    let src = root.join("src");
+    if src.exists() {
+        std::fs::remove_dir_all(&src).expect("the previous trees should be removed");
+    }
+
+    for (module, trait_per_tag) in [("per_operation", false), ("per_tag", true)] {
+        for file in emit(trait_per_tag) {
+            let path = src.join(module).join(&file.path);
+            std::fs::create_dir_all(path.parent().unwrap()).expect("directories should be created");
+            std::fs::write(&path, file.contents).expect("files should be written");
+        }
+    }
+    std::fs::write(
+        src.join("lib.rs"),
+        "pub mod per_operation;\npub mod per_tag;\n\
+         pub use per_operation::{Api, ListPetsResponse, Pet, PetKind};\n\
+         pub use per_tag::{DefaultApi, PetsApi};\n",
+    )
+    .expect("the crate root should be written");
+
+    let features = FEATURES
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(feature, _)| format!("{feature:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let manifest = format!(
+        r#"[package]
+name = "module-tree"
+version = "0.1.0"
+edition = "2021"
+publish = false
+
+[workspace]
+
+[dependencies]
+openapi-gen = {{ path = {:?}, features = [{features}] }}
+"#,
+        env!("CARGO_MANIFEST_DIR"),
+    );
+    std::fs::write(root.join("Cargo.toml"), manifest).expect("the manifest should be written");
+
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(root.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", root.join("target"))
+        .status()
+        .expect("cargo should run");
+    assert!(status.success(), "the module trees should build");
+}
@@ -146,7 +146,8 @@ impl Case {
         let definition_path = path.join("definition.yaml");
 
         let definition = std::fs::read_to_string(&definition_path).ok()?;
-        let definition = serde_yaml::from_str(&definition).ok()?;
+        let definition =
+            openapi_gen::deserialize_spec(serde_yaml::Deserializer::from_str(&definition)).ok()?;
 
         let expect = std::fs::read_to_string(path.join("expect.rs")).ok()?;
         let expect = syn::parse_str(&expect).ok()?;
@@ -227,10 +228,15 @@ fn env_is_set(name: impl AsRef<std::ffi::OsStr>) -> bool {
 #[test]
 #[cfg_attr(
     not(all(
+        feature = "actix-support",
         feature = "api-problem",
+        feature = "axum-support",
         feature = "bytes",
         feature = "integer-restrictions",
-        feature = "string-pattern"
+        feature = "reqwest-support",
+        feature = "string-pattern",
+        feature = "tower-support",
+        feature = "uuid"
     )),
     ignore = "required features are not enabled"
 )]